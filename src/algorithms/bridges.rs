@@ -0,0 +1,107 @@
+//! Bridges: chains of components with two ports, each component
+//! connecting to the previous one by a port of the same value.
+//!
+//! The chain starts from a port 0, each component can be used once,
+//! in either orientation. The strength of a bridge is the sum
+//! of the ports of its components.
+//!
+//! Every chain is explored by a depth-first search, tracking
+//! the components already used in a bitmask, so there can be
+//! at most 64 components.
+
+/// Finds the strength of the strongest bridge.
+///
+/// ```
+/// use enontekio::algorithms::bridges::strongest_bridge;
+///
+/// let components = [(0, 2), (2, 2), (2, 3), (3, 4), (3, 5), (0, 1), (10, 1), (9, 10)];
+/// assert_eq!(31, strongest_bridge(&components));
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are more than 64 components.
+pub fn strongest_bridge(components: &[(u32, u32)]) -> u32 {
+    best_bridge(components, |_, strength| strength).unwrap_or(0)
+}
+
+/// Finds the longest bridge, the strongest one among bridges of the same length.
+///
+/// Returns its length, as a number of components, and its strength.
+///
+/// ```
+/// use enontekio::algorithms::bridges::longest_then_strongest_bridge;
+///
+/// let components = [(0, 2), (2, 2), (2, 3), (3, 4), (3, 5), (0, 1), (10, 1), (9, 10)];
+/// assert_eq!((4, 19), longest_then_strongest_bridge(&components));
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are more than 64 components.
+pub fn longest_then_strongest_bridge(components: &[(u32, u32)]) -> (usize, u32) {
+    best_bridge(components, |length, strength| (length, strength)).unwrap_or((0, 0))
+}
+
+/// Finds the best bridge, according to a key computed from its length and strength.
+///
+/// Returns None if no component has a port 0.
+///
+/// # Panics
+///
+/// Panics if there are more than 64 components.
+pub fn best_bridge<K, F>(components: &[(u32, u32)], key: F) -> Option<K>
+where K: Ord, F: Fn(usize, u32) -> K {
+    assert!(components.len() <= 64, "bridges can be built from at most 64 components");
+
+    let mut best = None;
+    explore(components, 0, 0, 0, 0, &key, &mut best);
+
+    best
+}
+
+fn explore<K, F>(components: &[(u32, u32)], used: u64, port: u32, length: usize, strength: u32, key: &F, best: &mut Option<K>)
+where K: Ord, F: Fn(usize, u32) -> K {
+    if length > 0 {
+        let candidate = key(length, strength);
+        if best.as_ref().is_none_or(|best| candidate > *best) {
+            *best = Some(candidate);
+        }
+    }
+
+    for (index, &(a, b)) in components.iter().enumerate() {
+        if used & (1 << index) != 0 {
+            continue;
+        }
+
+        let next_port = if a == port {
+            b
+        } else if b == port {
+            a
+        } else {
+            continue;
+        };
+
+        explore(components, used | (1 << index), next_port, length + 1, strength + a + b, key, best);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_bridge() {
+        let components = [(0, 1), (1, 5), (0, 3), (3, 3), (3, 2)];
+
+        assert_eq!(Some(14), best_bridge(&components, |_, strength| strength));
+        assert_eq!(Some(3), best_bridge(&components, |length, _| length));
+        assert_eq!(None, best_bridge(&[(1, 2)], |_, strength| strength));
+    }
+
+    #[test]
+    fn test_bridges_without_start() {
+        assert_eq!(0, strongest_bridge(&[(1, 2), (2, 3)]));
+        assert_eq!((0, 0), longest_then_strongest_bridge(&[]));
+    }
+}
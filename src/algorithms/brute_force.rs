@@ -0,0 +1,111 @@
+//! Brute force: tests every candidate of a small domain until one fits.
+//!
+//! All functions return the first matching candidate in the domain order,
+//! so the parallel variant gives the same answer as the sequential one.
+
+/// Finds the first candidate of the domain satisfying the test.
+///
+/// The search stops at the first hit.
+///
+/// ```
+/// use enontekio::algorithms::brute_force::brute_force;
+///
+/// let found = brute_force(1.., |n: &u32| n * n > 200);
+/// assert_eq!(Some(15), found);
+/// ```
+pub fn brute_force<I, F>(domain: I, test_fn: F) -> Option<I::Item>
+where I: IntoIterator, F: Fn(&I::Item) -> bool {
+    domain.into_iter().find(|candidate| test_fn(candidate))
+}
+
+/// Finds the first candidate of the domain satisfying the test,
+/// calling the progress callback with the count of tested candidates
+/// every <every> candidates.
+pub fn brute_force_with_progress<I, F, P>(domain: I, test_fn: F, every: usize, mut progress: P) -> Option<I::Item>
+where I: IntoIterator, F: Fn(&I::Item) -> bool, P: FnMut(usize) {
+    let every = every.max(1);
+
+    for (i, candidate) in domain.into_iter().enumerate() {
+        if test_fn(&candidate) {
+            return Some(candidate);
+        }
+
+        let tested = i + 1;
+        if tested.is_multiple_of(every) {
+            progress(tested);
+        }
+    }
+
+    None
+}
+
+/// Finds the first candidate of the domain satisfying the test,
+/// testing the candidates in parallel by chunks of <chunk_size>.
+///
+/// Chunks are tested one after another: once a chunk contains a hit,
+/// the following candidates aren't pulled from the domain.
+/// Within a chunk, the earliest hit is returned, so the result
+/// is the same as the sequential brute_force.
+#[cfg(feature = "rayon")]
+pub fn brute_force_parallel<I, F>(domain: I, test_fn: F, chunk_size: usize) -> Option<I::Item>
+where I: IntoIterator, I::Item: Send + Sync, F: Fn(&I::Item) -> bool + Sync {
+    use rayon::prelude::*;
+
+    let chunk_size = chunk_size.max(1);
+    let mut domain = domain.into_iter();
+
+    loop {
+        let mut chunk: Vec<_> = domain.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            return None;
+        }
+
+        if let Some(position) = chunk.par_iter().position_first(&test_fn) {
+            return Some(chunk.swap_remove(position));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brute_force_returns_first_hit() {
+        assert_eq!(Some(6), brute_force(0..100, |n| n % 6 == 0 && *n > 0));
+        assert_eq!(None, brute_force(0..10, |n| *n > 10));
+    }
+
+    #[test]
+    fn test_brute_force_stops_at_first_hit() {
+        let tested = std::cell::Cell::new(0);
+
+        brute_force(0.., |n| {
+            tested.set(tested.get() + 1);
+            *n == 41
+        });
+
+        assert_eq!(42, tested.get());
+    }
+
+    #[test]
+    fn test_brute_force_with_progress() {
+        let mut reports = Vec::new();
+
+        let found = brute_force_with_progress(0..100, |n| *n == 35, 10, |tested| reports.push(tested));
+
+        assert_eq!(Some(35), found);
+        assert_eq!(vec![10, 20, 30], reports);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_brute_force_parallel_keeps_domain_order() {
+        for chunk_size in [1, 7, 64, 1000] {
+            let found = brute_force_parallel(0..10_000, |n| n % 97 == 0 && *n > 500, chunk_size);
+            assert_eq!(Some(582), found);
+        }
+
+        assert_eq!(None, brute_force_parallel(0..100, |n| *n > 100, 16));
+    }
+}
@@ -0,0 +1,283 @@
+//! Alignment of independent cyclic processes, to find when all of them
+//! are in a goal state at the same time.
+//!
+//! Each process is deterministic and eventually cycles. Its cycle and goal
+//! times are measured separately, then combined with the Chinese remainder
+//! theorem. When each process only reaches a goal at the multiples of its
+//! cycle length, that's the lcm of the cycle lengths, but the assumption
+//! isn't needed.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::math::cycle::{detect_cycle, Cycle};
+use crate::math::euclid::{chinese_remainder, lcm};
+
+/// Error returned when processes can't be aligned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignmentError {
+    /// The process at this index never reaches a goal state within its cycle
+    NoGoalInCycle(usize),
+
+    /// The goal times of the processes never match
+    NoCommonTime,
+}
+
+impl Display for AlignmentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlignmentError::NoGoalInCycle(index) => write!(f, "process {} never reaches a goal within its cycle", index),
+            AlignmentError::NoCommonTime => write!(f, "processes never reach a goal at the same time"),
+        }
+    }
+}
+
+impl Error for AlignmentError {}
+
+/// The cycle of a process and the times it's in a goal state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessCycle {
+    pub cycle: Cycle,
+
+    /// The sorted times in a goal state, before the end of the first cycle
+    pub goal_times: Vec<usize>,
+}
+
+impl ProcessCycle {
+    /// Measures the cycle of a process from its start state.
+    ///
+    /// The process must eventually cycle, or this function won't return.
+    pub fn measure<S, F, G>(start: S, step_fn: F, is_goal: G) -> Self
+    where S: Clone + Eq, F: Fn(&S) -> S, G: Fn(&S) -> bool {
+        let cycle = detect_cycle(start.clone(), &step_fn);
+
+        let mut goal_times = Vec::new();
+        let mut state = start;
+        for time in 0..cycle.start + cycle.length {
+            if is_goal(&state) {
+                goal_times.push(time);
+            }
+            state = step_fn(&state);
+        }
+
+        Self { cycle, goal_times }
+    }
+
+    /// Checks if the process is in a goal state at the specified time.
+    pub fn is_goal_at(&self, time: u64) -> bool {
+        let start = self.cycle.start as u64;
+        let time = if time < start {
+            time
+        } else {
+            start + (time - start) % self.cycle.length as u64
+        };
+
+        self.goal_times.binary_search(&(time as usize)).is_ok()
+    }
+
+    /// Gets the goal times in the cycle, modulo the cycle length.
+    pub fn cycle_residues(&self) -> Vec<usize> {
+        self.goal_times.iter()
+            .filter(|&&time| time >= self.cycle.start)
+            .map(|&time| time % self.cycle.length)
+            .collect()
+    }
+
+    /// Gets the goal residues modulo their smallest period in the cycle, and that period.
+    ///
+    /// A process in a goal state every other step of a cycle of length 1000
+    /// has 500 cycle residues, but only the residue 1 or 0 modulo 2.
+    pub fn reduced_cycle_residues(&self) -> (Vec<usize>, usize) {
+        let length = self.cycle.length;
+        let mut residues = self.cycle_residues();
+        residues.sort_unstable();
+        residues.dedup();
+
+        let period = (1..=length)
+            .filter(|period| length.is_multiple_of(*period))
+            .find(|&period| residues.iter().all(|&residue| residues.binary_search(&((residue + period) % length)).is_ok()))
+            .unwrap_or(length);
+        residues.retain(|&residue| residue < period);
+
+        (residues, period)
+    }
+
+    /// Checks the usual simplifying assumption: the process is in a goal state
+    /// exactly at the multiples of its cycle length.
+    pub fn goals_at_cycle_multiples(&self) -> bool {
+        let residues = self.cycle_residues();
+        let prefix_goals = self.goal_times.iter().filter(|&&time| time < self.cycle.start);
+
+        residues == [0] && prefix_goals.count() == 0
+    }
+}
+
+/// Finds the first time, counting from 0, when every process started from
+/// one of the starts is in a goal state.
+///
+/// The states must include everything the step depends on, like the
+/// position in a repeated instructions list. The processes must eventually
+/// cycle, or this function won't return.
+///
+/// ```
+/// use enontekio::algorithms::cycle_alignment::parallel_cycle_alignment;
+///
+/// // Counters modulo 4 and 6, both starting at 1, the goal being 0
+/// let time = parallel_cycle_alignment(
+///     vec![(1, 4), (1, 6)],
+///     |&(n, modulus)| ((n + 1) % modulus, modulus),
+///     |&(n, _)| n == 0,
+/// );
+/// assert_eq!(Ok(11), time);
+/// ```
+pub fn parallel_cycle_alignment<S, F, G>(starts: Vec<S>, step_fn: F, is_goal: G) -> Result<u64, AlignmentError>
+where S: Clone + Eq, F: Fn(&S) -> S, G: Fn(&S) -> bool {
+    let processes: Vec<ProcessCycle> = starts.into_iter()
+        .map(|start| ProcessCycle::measure(start, &step_fn, &is_goal))
+        .collect();
+
+    align(&processes)
+}
+
+/// Finds the first time when every process is in a goal state.
+pub fn align(processes: &[ProcessCycle]) -> Result<u64, AlignmentError> {
+    // Before every process is in its cycle, goal times are checked one by one
+    let transient = processes.iter().map(|process| process.cycle.start).max().unwrap_or(0) as u64;
+    if let Some(time) = (0..transient).find(|&time| processes.iter().all(|process| process.is_goal_at(time))) {
+        return Ok(time);
+    }
+
+    // Then, the goal residues are combined one process at a time. The solutions
+    // all share the lcm of the periods as modulus, so they're merged as a set
+    // of residues modulo that lcm, which can't grow beyond the lcm, instead of
+    // a system per combination of residues, which grows exponentially.
+    let mut residues: Vec<i128> = vec![0];
+    let mut modulus: i128 = 1;
+    for (index, process) in processes.iter().enumerate() {
+        let (process_residues, period) = process.reduced_cycle_residues();
+        if process_residues.is_empty() {
+            return Err(AlignmentError::NoGoalInCycle(index));
+        }
+
+        let period = period as i128;
+        let mut combined: Vec<i128> = residues.iter()
+            .flat_map(|&residue| process_residues.iter()
+                .filter_map(move |&other| chinese_remainder(&[(residue, modulus), (other as i128, period)])))
+            .map(|(residue, _)| residue)
+            .collect();
+        combined.sort_unstable();
+        combined.dedup();
+
+        if combined.is_empty() {
+            return Err(AlignmentError::NoCommonTime);
+        }
+
+        residues = combined;
+        modulus = lcm(modulus, period);
+    }
+
+    // The first solution after the transient part
+    let transient = transient as i128;
+    let first = residues.iter()
+        .map(|&residue| {
+            let periods = ((transient - residue).max(0) + modulus - 1) / modulus;
+
+            residue + periods * modulus
+        })
+        .min()
+        .expect("there is at least one residue");
+
+    Ok(first as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    /// A network walked following repeated left and right instructions,
+    /// the state being the node and the position in the instructions.
+    fn ghosts() -> u64 {
+        let instructions: Vec<char> = "LR".chars().collect();
+        let network: HashMap<&str, (&str, &str)> = HashMap::from([
+            ("11A", ("11B", "XXX")),
+            ("11B", ("XXX", "11Z")),
+            ("11Z", ("11B", "XXX")),
+            ("22A", ("22B", "XXX")),
+            ("22B", ("22C", "22C")),
+            ("22C", ("22Z", "22Z")),
+            ("22Z", ("22B", "22B")),
+            ("XXX", ("XXX", "XXX")),
+        ]);
+
+        let starts = network.keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|&node| (node, 0))
+            .collect();
+
+        parallel_cycle_alignment(
+            starts,
+            |&(node, index)| {
+                let (left, right) = network[node];
+                let next = if instructions[index] == 'L' { left } else { right };
+
+                (next, (index + 1) % instructions.len())
+            },
+            |&(node, _)| node.ends_with('Z'),
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_parallel_cycle_alignment() {
+        assert_eq!(6, ghosts());
+    }
+
+    #[test]
+    fn test_goals_at_cycle_multiples() {
+        let counter = ProcessCycle::measure(1, |&n| (n + 1) % 5, |&n| n == 1);
+        assert!(counter.goals_at_cycle_multiples());
+
+        let shifted = ProcessCycle::measure(0, |&n| (n + 1) % 5, |&n| n == 1);
+        assert!(!shifted.goals_at_cycle_multiples());
+        assert_eq!(vec![1], shifted.cycle_residues());
+    }
+
+    #[test]
+    fn test_reduced_cycle_residues() {
+        let every_other_step = ProcessCycle::measure(1, |&n| (n + 1) % 1000, |&n| n % 2 == 0);
+        assert_eq!(500, every_other_step.cycle_residues().len());
+        assert_eq!((vec![1], 2), every_other_step.reduced_cycle_residues());
+
+        let counter = ProcessCycle::measure(0, |&n| (n + 1) % 6, |&n| n == 2 || n == 3);
+        assert_eq!((vec![2, 3], 6), counter.reduced_cycle_residues());
+    }
+
+    #[test]
+    fn test_alignment_with_many_residues() {
+        // 500 residues per process would give 500^4 combinations
+        let time = parallel_cycle_alignment(
+            vec![(1, 1000), (1, 998), (1, 996), (1, 994)],
+            |&(n, modulus)| ((n + 1) % modulus, modulus),
+            |&(n, _)| n % 2 == 0,
+        );
+
+        assert_eq!(Ok(1), time);
+    }
+
+    #[test]
+    fn test_alignment_errors() {
+        // 0, 1, 2, 2, 2... reaches 1 only before its cycle
+        let result = parallel_cycle_alignment(vec![0, 0], |&n: &u32| (n + 1).min(2), |&n| n == 1);
+        assert_eq!(Ok(1), result);
+
+        let processes = [
+            ProcessCycle::measure(0, |&n| (n + 1) % 4, |&n| n == 1),
+            ProcessCycle::measure(0, |&n| (n + 1) % 6, |&n| n == 2),
+        ];
+        assert_eq!(Err(AlignmentError::NoCommonTime), align(&processes));
+
+        let processes = [ProcessCycle::measure(0, |&n: &u32| (n + 1).min(2), |&n| n == 5)];
+        assert_eq!(Err(AlignmentError::NoGoalInCycle(0)), align(&processes));
+    }
+}
@@ -0,0 +1,205 @@
+//! Local search: improves a state by moving to one of its neighbors,
+//! for optimization problems too large for an exhaustive search.
+
+/// The way to choose the next state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// Moves to the best neighbor while it improves the score,
+    /// stopping at a local optimum or after max_iterations moves.
+    HillClimbing {
+        max_iterations: usize,
+    },
+
+    /// Moves to a random neighbor, always accepted if it improves the score,
+    /// and accepted with probability exp(delta / temperature) otherwise.
+    ///
+    /// The temperature is multiplied by the cooling rate after each iteration.
+    /// The seed makes the search reproducible.
+    SimulatedAnnealing {
+        initial_temperature: f64,
+        cooling_rate: f64,
+        iterations: usize,
+        seed: u64,
+    },
+}
+
+/// Searches the state with the highest score, starting from the initial state.
+///
+/// The neighbors function gets the states reachable with one modification, like a swap.
+/// To minimize a cost, use its opposite as score.
+///
+/// Returns the best state found and its score.
+///
+/// ```
+/// use enontekio::algorithms::local_search::{local_search, Strategy};
+///
+/// // Maximizes -(x - 7)² over the integers, moving by 1.
+/// let (best, score) = local_search(
+///     0,
+///     |&x: &i32| vec![x - 1, x + 1],
+///     |&x| -((x - 7) as f64).powi(2),
+///     Strategy::HillClimbing { max_iterations: 100 },
+/// );
+///
+/// assert_eq!(7, best);
+/// assert_eq!(0.0, score);
+/// ```
+pub fn local_search<S, FN, FS>(initial: S, neighbors_fn: FN, score_fn: FS, strategy: Strategy) -> (S, f64)
+where S: Clone, FN: Fn(&S) -> Vec<S>, FS: Fn(&S) -> f64 {
+    match strategy {
+        Strategy::HillClimbing { max_iterations } => {
+            hill_climbing(initial, neighbors_fn, score_fn, max_iterations)
+        }
+        Strategy::SimulatedAnnealing { initial_temperature, cooling_rate, iterations, seed } => {
+            simulated_annealing(initial, neighbors_fn, score_fn, initial_temperature, cooling_rate, iterations, seed)
+        }
+    }
+}
+
+fn hill_climbing<S, FN, FS>(initial: S, neighbors_fn: FN, score_fn: FS, max_iterations: usize) -> (S, f64)
+where S: Clone, FN: Fn(&S) -> Vec<S>, FS: Fn(&S) -> f64 {
+    let mut current_score = score_fn(&initial);
+    let mut current = initial;
+
+    for _ in 0..max_iterations {
+        let best_neighbor = neighbors_fn(&current)
+            .into_iter()
+            .map(|neighbor| {
+                let score = score_fn(&neighbor);
+                (neighbor, score)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match best_neighbor {
+            Some((neighbor, score)) if score > current_score => {
+                current = neighbor;
+                current_score = score;
+            }
+            _ => break,
+        }
+    }
+
+    (current, current_score)
+}
+
+fn simulated_annealing<S, FN, FS>(initial: S, neighbors_fn: FN, score_fn: FS, initial_temperature: f64, cooling_rate: f64, iterations: usize, seed: u64) -> (S, f64)
+where S: Clone, FN: Fn(&S) -> Vec<S>, FS: Fn(&S) -> f64 {
+    let mut rng = SplitMix64::new(seed);
+    let mut temperature = initial_temperature;
+
+    let mut current_score = score_fn(&initial);
+    let mut current = initial;
+    let mut best = (current.clone(), current_score);
+
+    for _ in 0..iterations {
+        let mut neighbors = neighbors_fn(&current);
+        if neighbors.is_empty() {
+            break;
+        }
+
+        let index = (rng.next_u64() % neighbors.len() as u64) as usize;
+        let candidate = neighbors.swap_remove(index);
+        let candidate_score = score_fn(&candidate);
+        let delta = candidate_score - current_score;
+
+        if delta >= 0.0 || (temperature > 0.0 && rng.next_f64() < (delta / temperature).exp()) {
+            current = candidate;
+            current_score = candidate_score;
+
+            if current_score > best.1 {
+                best = (current.clone(), current_score);
+            }
+        }
+
+        temperature *= cooling_rate;
+    }
+
+    best
+}
+
+/// A small and fast pseudorandom generator, good enough for randomized searches.
+///
+/// Source: https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Gets a float in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A score with a local optimum at 2 and the global one at 10.
+    fn score(x: &i32) -> f64 {
+        match x {
+            0..=2 => *x as f64,
+            3..=5 => 1.0,
+            6..=10 => *x as f64,
+            _ => 0.0,
+        }
+    }
+
+    fn neighbors(x: &i32) -> Vec<i32> {
+        vec![x - 1, x + 1]
+    }
+
+    #[test]
+    fn test_hill_climbing_stops_at_local_optimum() {
+        let (best, _) = local_search(0, neighbors, score, Strategy::HillClimbing { max_iterations: 100 });
+
+        assert_eq!(2, best);
+    }
+
+    #[test]
+    fn test_hill_climbing_respects_max_iterations() {
+        let (best, _) = local_search(6, neighbors, score, Strategy::HillClimbing { max_iterations: 2 });
+
+        assert_eq!(8, best);
+    }
+
+    #[test]
+    fn test_simulated_annealing_escapes_local_optimum() {
+        let strategy = Strategy::SimulatedAnnealing {
+            initial_temperature: 5.0,
+            cooling_rate: 0.999,
+            iterations: 10_000,
+            seed: 42,
+        };
+
+        let (best, score) = local_search(0, neighbors, score, strategy);
+
+        assert_eq!(10, best);
+        assert_eq!(10.0, score);
+    }
+
+    #[test]
+    fn test_simulated_annealing_is_reproducible() {
+        let strategy = Strategy::SimulatedAnnealing {
+            initial_temperature: 1.0,
+            cooling_rate: 0.9,
+            iterations: 50,
+            seed: 7,
+        };
+
+        assert_eq!(local_search(0, neighbors, score, strategy), local_search(0, neighbors, score, strategy));
+    }
+}
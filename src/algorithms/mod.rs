@@ -0,0 +1,7 @@
+//! Generic problem solving algorithms, like searches over a state space.
+
+pub mod bridges;
+pub mod brute_force;
+pub mod cycle_alignment;
+pub mod local_search;
+pub mod workflows;
@@ -0,0 +1,493 @@
+//! Workflows engine, routing parts through named lists of rules
+//! until they're accepted or rejected.
+//!
+//! A workflow is written `px{a<2006:qkq,m>2090:A,rfg}`: each rule
+//! compares a category of the part to a value, and sends the part to
+//! another workflow, or to A (accepted) or R (rejected). The last rule
+//! has no condition. The evaluation starts at the "in" workflow.
+//!
+//! A part is written `{x=787,m=2655,a=1222,s=2876}`.
+//!
+//! Besides single parts, the engine can propagate ranges of values,
+//! to count how many combinations are accepted.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::parser::ParserError;
+
+/// The name of the workflow where the evaluation starts
+pub const START_WORKFLOW: &str = "in";
+
+/// The default categories of a part
+pub const DEFAULT_CATEGORIES: &str = "xmas";
+
+/// Where a rule sends a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl From<&str> for Target {
+    fn from(name: &str) -> Self {
+        match name {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => Target::Workflow(name.to_string()),
+        }
+    }
+}
+
+/// A comparison between a part category and a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Condition {
+    /// The index of the category in the engine categories
+    pub category: usize,
+
+    /// true for category > value, false for category < value
+    pub greater: bool,
+
+    pub value: u64,
+}
+
+impl Condition {
+    pub fn matches(&self, part: &[u64]) -> bool {
+        let rating = part[self.category];
+
+        if self.greater {
+            rating > self.value
+        } else {
+            rating < self.value
+        }
+    }
+
+    /// Splits a range into the values matching the condition and the other ones.
+    pub fn split(&self, range: &Range<u64>) -> (Range<u64>, Range<u64>) {
+        if self.greater {
+            // No value of a Range<u64> is above u64::MAX - 1, so saturating is exact
+            let threshold = self.value.saturating_add(1).clamp(range.start, range.end);
+            (threshold..range.end, range.start..threshold)
+        } else {
+            let threshold = self.value.clamp(range.start, range.end);
+            (range.start..threshold, threshold..range.end)
+        }
+    }
+}
+
+/// A rule of a workflow: the target, when the condition matches if any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub condition: Option<Condition>,
+    pub target: Target,
+}
+
+/// A set of workflows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Engine {
+    categories: Vec<char>,
+    workflows: HashMap<String, Vec<Rule>>,
+}
+
+impl Engine {
+    /// Parses workflows, one per line, with the x, m, a and s categories.
+    pub fn parse(workflows: &str) -> Result<Self, ParserError> {
+        Self::parse_with_categories(workflows, DEFAULT_CATEGORIES)
+    }
+
+    /// Parses workflows, one per line, with custom categories names.
+    ///
+    /// Every target workflow must be defined, once, and workflows must not loop.
+    pub fn parse_with_categories(workflows: &str, categories: &str) -> Result<Self, ParserError> {
+        let mut engine = Self {
+            categories: categories.chars().collect(),
+            workflows: HashMap::new(),
+        };
+
+        let mut lines = HashMap::new();
+        let mut lines_count = 0;
+
+        for (index, line) in workflows.lines().enumerate() {
+            lines_count = index + 1;
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (name, rules) = engine.parse_workflow(line)
+                .map_err(|message| ParserError::parse(index + 1, line, message))?;
+            if lines.contains_key(&name) {
+                let message = format!("duplicate workflow {}", name);
+                return Err(ParserError::parse(index + 1, line, message));
+            }
+
+            lines.insert(name.clone(), (index + 1, line));
+            engine.workflows.insert(name, rules);
+        }
+
+        if !engine.workflows.contains_key(START_WORKFLOW) {
+            let message = format!("missing {} workflow", START_WORKFLOW);
+            return Err(ParserError::parse(lines_count, "", message));
+        }
+
+        for (name, rules) in &engine.workflows {
+            for rule in rules {
+                if let Target::Workflow(target) = &rule.target {
+                    if !engine.workflows.contains_key(target) {
+                        let (line_number, line) = lines[name];
+                        let message = format!("unknown target workflow {}", target);
+                        return Err(ParserError::parse(line_number, line, message));
+                    }
+                }
+            }
+        }
+
+        let mut names: Vec<&String> = lines.keys().collect();
+        names.sort_by_key(|name| lines[*name].0);
+
+        let mut visited = HashSet::new();
+        for name in names {
+            let mut path = Vec::new();
+            if engine.loops_from(name, &mut path, &mut visited) {
+                let (line_number, line) = lines[path[0]];
+                let message = format!("workflows loop through {}", path.join(" -> "));
+                return Err(ParserError::parse(line_number, line, message));
+            }
+        }
+
+        Ok(engine)
+    }
+
+    /// Checks by a depth-first search if the workflows reachable from this one loop.
+    ///
+    /// The path holds the workflows being explored, so on a loop,
+    /// it ends with the workflows of the loop. Workflows fully explored
+    /// without a loop are added to visited, to not explore them again.
+    fn loops_from<'a>(&'a self, name: &'a str, path: &mut Vec<&'a str>, visited: &mut HashSet<&'a str>) -> bool {
+        if let Some(start) = path.iter().position(|&explored| explored == name) {
+            path.drain(..start);
+            path.push(name);
+            return true;
+        }
+
+        if visited.contains(name) {
+            return false;
+        }
+
+        path.push(name);
+        for rule in &self.workflows[name] {
+            if let Target::Workflow(target) = &rule.target {
+                if self.loops_from(target, path, visited) {
+                    return true;
+                }
+            }
+        }
+        path.pop();
+        visited.insert(name);
+
+        false
+    }
+
+    fn parse_workflow(&self, line: &str) -> Result<(String, Vec<Rule>), String> {
+        let (name, rules) = line
+            .strip_suffix('}')
+            .and_then(|line| line.split_once('{'))
+            .ok_or("expected a workflow like name{rules}")?;
+
+        let rules = rules
+            .split(',')
+            .map(|rule| self.parse_rule(rule))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match rules.last() {
+            Some(Rule { condition: None, .. }) => Ok((name.to_string(), rules)),
+            _ => Err("the last rule must have no condition".to_string()),
+        }
+    }
+
+    fn parse_rule(&self, rule: &str) -> Result<Rule, String> {
+        let (condition, target) = match rule.split_once(':') {
+            None => return Ok(Rule { condition: None, target: rule.into() }),
+            Some(parts) => parts,
+        };
+
+        let mut chars = condition.chars();
+        let category = chars.next()
+            .and_then(|name| self.category_index(name))
+            .ok_or_else(|| format!("unknown category in rule {}", rule))?;
+        let greater = match chars.next() {
+            Some('>') => true,
+            Some('<') => false,
+            _ => return Err(format!("expected < or > in rule {}", rule)),
+        };
+        let value = chars.as_str().parse()
+            .map_err(|error| format!("invalid value in rule {}: {}", rule, error))?;
+
+        Ok(Rule {
+            condition: Some(Condition { category, greater, value }),
+            target: target.into(),
+        })
+    }
+
+    fn category_index(&self, name: char) -> Option<usize> {
+        self.categories.iter().position(|&category| category == name)
+    }
+
+    /// Gets the rules of a workflow.
+    pub fn workflow(&self, name: &str) -> Option<&[Rule]> {
+        self.workflows.get(name).map(|rules| rules.as_slice())
+    }
+
+    /// Parses a part like {x=787,m=2655,a=1222,s=2876}.
+    ///
+    /// Returns the ratings in the order of the engine categories.
+    /// Each category must be rated exactly once.
+    /// An error is reported as a ParserError::Parse on line 1.
+    pub fn parse_part(&self, line: &str) -> Result<Vec<u64>, ParserError> {
+        self.parse_part_ratings(line.trim())
+            .map_err(|message| ParserError::parse(1, line, message))
+    }
+
+    fn parse_part_ratings(&self, line: &str) -> Result<Vec<u64>, String> {
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .ok_or("expected a part like {x=1,m=2}")?;
+
+        let mut part = vec![None; self.categories.len()];
+        for rating in ratings.split(',') {
+            let (name, value) = rating.split_once('=')
+                .ok_or_else(|| format!("expected category=value, got {}", rating))?;
+
+            let index = name.chars().next()
+                .filter(|_| name.len() == 1)
+                .and_then(|name| self.category_index(name))
+                .ok_or_else(|| format!("unknown category: {}", name))?;
+            if part[index].is_some() {
+                return Err(format!("duplicate category: {}", name));
+            }
+            part[index] = Some(value.parse().map_err(|error| format!("invalid rating {}: {}", value, error))?);
+        }
+
+        part.iter()
+            .zip(&self.categories)
+            .map(|(rating, category)| rating.ok_or_else(|| format!("missing category: {}", category)))
+            .collect()
+    }
+
+    /// Checks if a part is accepted by the workflows.
+    ///
+    /// Returns None if the part doesn't have a rating for each category.
+    ///
+    /// ```
+    /// use enontekio::algorithms::workflows::Engine;
+    ///
+    /// let engine = Engine::parse("in{x>10:big,R}\nbig{s<5:A,R}").unwrap();
+    ///
+    /// assert_eq!(Some(true), engine.evaluate(&engine.parse_part("{x=11,m=0,a=0,s=4}").unwrap()));
+    /// assert_eq!(Some(false), engine.evaluate(&engine.parse_part("{x=11,m=0,a=0,s=5}").unwrap()));
+    /// assert_eq!(None, engine.evaluate(&[11, 0]));
+    /// ```
+    pub fn evaluate(&self, part: &[u64]) -> Option<bool> {
+        if part.len() != self.categories.len() {
+            return None;
+        }
+
+        let mut workflow = START_WORKFLOW;
+
+        loop {
+            let rule = self.workflows[workflow]
+                .iter()
+                .find(|rule| match rule.condition {
+                    None => true,
+                    Some(condition) => condition.matches(part),
+                })
+                .expect("the last rule has no condition");
+
+            match &rule.target {
+                Target::Accept => return Some(true),
+                Target::Reject => return Some(false),
+                Target::Workflow(next) => workflow = next,
+            }
+        }
+    }
+
+    /// Gets the disjoint hyperrectangles of parts accepted by the workflows,
+    /// from a range of values for each category.
+    ///
+    /// Returns None if there isn't a range for each category.
+    pub fn accepted_ranges(&self, ranges: Vec<Range<u64>>) -> Option<Vec<Vec<Range<u64>>>> {
+        if ranges.len() != self.categories.len() {
+            return None;
+        }
+
+        let mut accepted = Vec::new();
+        let mut pending = vec![(Target::Workflow(START_WORKFLOW.to_string()), ranges)];
+
+        while let Some((target, mut ranges)) = pending.pop() {
+            let workflow = match target {
+                Target::Accept => {
+                    accepted.push(ranges);
+                    continue;
+                }
+                Target::Reject => continue,
+                Target::Workflow(workflow) => workflow,
+            };
+
+            for rule in &self.workflows[&workflow] {
+                match rule.condition {
+                    None => {
+                        pending.push((rule.target.clone(), ranges));
+                        break;
+                    }
+                    Some(condition) => {
+                        let (matching, other) = condition.split(&ranges[condition.category]);
+
+                        if !matching.is_empty() {
+                            let mut matching_ranges = ranges.clone();
+                            matching_ranges[condition.category] = matching;
+                            pending.push((rule.target.clone(), matching_ranges));
+                        }
+
+                        if other.is_empty() {
+                            break;
+                        }
+                        ranges[condition.category] = other;
+                    }
+                }
+            }
+        }
+
+        Some(accepted)
+    }
+
+    /// Counts the combinations of ratings accepted by the workflows,
+    /// from a range of values for each category.
+    ///
+    /// Returns None if there isn't a range for each category.
+    ///
+    /// ```
+    /// use enontekio::algorithms::workflows::Engine;
+    ///
+    /// let engine = Engine::parse("in{x>10:big,R}\nbig{s<5:A,R}").unwrap();
+    ///
+    /// // x in 11..20 and s in 1..5
+    /// assert_eq!(Some(9 * 4 * 19 * 19), engine.count_accepted(vec![1..20; 4]));
+    /// ```
+    pub fn count_accepted(&self, ranges: Vec<Range<u64>>) -> Option<u64> {
+        let count = self.accepted_ranges(ranges)?
+            .iter()
+            .map(|ranges| ranges.iter().map(|range| range.end - range.start).product::<u64>())
+            .sum();
+
+        Some(count)
+    }
+}
+
+impl FromStr for Engine {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKFLOWS: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}";
+
+    const PARTS: [&str; 5] = [
+        "{x=787,m=2655,a=1222,s=2876}",
+        "{x=1679,m=44,a=2067,s=496}",
+        "{x=2036,m=264,a=79,s=2244}",
+        "{x=2461,m=1339,a=466,s=291}",
+        "{x=2127,m=1623,a=2188,s=1013}",
+    ];
+
+    #[test]
+    fn test_evaluate_parts() {
+        let engine: Engine = WORKFLOWS.parse().unwrap();
+
+        let total: u64 = PARTS.iter()
+            .map(|line| engine.parse_part(line).unwrap())
+            .filter(|part| engine.evaluate(part) == Some(true))
+            .map(|part| part.iter().sum::<u64>())
+            .sum();
+
+        assert_eq!(19114, total);
+    }
+
+    #[test]
+    fn test_count_accepted() {
+        let engine = Engine::parse(WORKFLOWS).unwrap();
+
+        assert_eq!(Some(167409079868000), engine.count_accepted(vec![1..4001; 4]));
+        assert_eq!(None, engine.count_accepted(vec![1..4001; 3]));
+    }
+
+    #[test]
+    fn test_split_at_bounds() {
+        let greater = Condition { category: 0, greater: true, value: u64::MAX };
+        assert_eq!((u64::MAX..u64::MAX, 0..u64::MAX), greater.split(&(0..u64::MAX)));
+
+        let lower = Condition { category: 0, greater: false, value: 0 };
+        assert_eq!((5..5, 5..10), lower.split(&(5..10)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Engine::parse("in{a<5:A,R}\nbad{a=5:A,R}").unwrap_err();
+        assert_eq!(Some(2), error.line_number());
+
+        assert!(Engine::parse("in{a<5:A}").is_err());
+        let error = Engine::parse("px{A}\nin{a<5:nowhere,R}").unwrap_err();
+        assert_eq!(Some(2), error.line_number());
+        assert!(Engine::parse("px{A}").is_err());
+
+        let error = Engine::parse("in{A}\nin{R}").unwrap_err();
+        assert_eq!(Some(2), error.line_number());
+
+        let engine = Engine::parse("in{A}").unwrap();
+        assert!(engine.parse_part("{x=1,q=2}").is_err());
+        assert_eq!(Some(1), engine.parse_part("x=1").unwrap_err().line_number());
+    }
+
+    #[test]
+    fn test_parse_part_requires_each_category_once() {
+        let engine = Engine::parse("in{A}").unwrap();
+
+        assert_eq!(vec![1, 2, 3, 4], engine.parse_part("{s=4,a=3,m=2,x=1}").unwrap());
+
+        let error = engine.parse_part("{x=1,m=2,a=3}").unwrap_err();
+        assert!(error.to_string().contains("missing category: s"));
+
+        let error = engine.parse_part("{x=1,m=2,a=3,s=4,x=5}").unwrap_err();
+        assert!(error.to_string().contains("duplicate category: x"));
+    }
+
+    #[test]
+    fn test_parse_rejects_loops() {
+        assert!(Engine::parse("in{in}").is_err());
+
+        let error = Engine::parse("in{a<5:px,A}\npx{x>2:qs,R}\nqs{px}").unwrap_err();
+        assert_eq!(Some(2), error.line_number());
+        assert!(error.to_string().contains("px -> qs -> px"));
+
+        assert!(Engine::parse("in{a<5:px,qs}\npx{qs}\nqs{A}").is_ok());
+    }
+}
@@ -0,0 +1,107 @@
+//! Bits manipulation, to build grids from hashes or bytes.
+
+use crate::collections::{connected_components_in_2d_grid, TAXICAB_DIRECTION_VECTORS_2D};
+
+/// Gets the bits of bytes, most significant bit first.
+///
+/// ```
+/// use enontekio::bits::bits_from_bytes;
+///
+/// assert_eq!(vec![true, false, false, false, false, false, true, true], bits_from_bytes(&[0x83]));
+/// ```
+pub fn bits_from_bytes(bytes: &[u8]) -> Vec<bool> {
+    bytes.iter()
+        .flat_map(|&byte| (0..8).rev().map(move |bit| byte >> bit & 1 == 1))
+        .collect()
+}
+
+/// Gets the bits of an hexadecimal string, most significant bit first.
+///
+/// ```
+/// use enontekio::bits::bits_from_hex;
+///
+/// assert_eq!(Ok(vec![true, false, true, false, false, false, false, true]), bits_from_hex("a1"));
+/// assert!(bits_from_hex("0g").is_err());
+/// ```
+pub fn bits_from_hex(hex: &str) -> Result<Vec<bool>, String> {
+    let mut bits = Vec::with_capacity(hex.len() * 4);
+
+    for c in hex.chars() {
+        let digit = c.to_digit(16).ok_or_else(|| format!("invalid hexadecimal digit {:?}", c))?;
+        bits.extend((0..4).rev().map(|bit| digit >> bit & 1 == 1));
+    }
+
+    Ok(bits)
+}
+
+/// Builds a grid of bits, a row for each hash, given as bytes.
+///
+/// A hash can be the output of any hash function, like a knot hash.
+pub fn grid_from_hashes<H: AsRef<[u8]>>(hashes: &[H]) -> Vec<Vec<bool>> {
+    hashes.iter()
+        .map(|hash| bits_from_bytes(hash.as_ref()))
+        .collect()
+}
+
+/// Builds a grid of bits, a row for each hash, given as an hexadecimal string.
+pub fn grid_from_hex_hashes<S: AsRef<str>>(hashes: &[S]) -> Result<Vec<Vec<bool>>, String> {
+    hashes.iter()
+        .map(|hash| bits_from_hex(hash.as_ref()))
+        .collect()
+}
+
+/// Counts the set bits of a grid.
+pub fn count_set_bits(grid: &[Vec<bool>]) -> usize {
+    grid.iter().flatten().filter(|&&bit| bit).count()
+}
+
+/// Gets the regions of a grid of bits, ie the groups of set bits
+/// connected horizontally or vertically.
+///
+/// Regions are ordered by their first cell, in row-major order.
+pub fn regions(grid: &[Vec<bool>]) -> Vec<Vec<(usize, usize)>> {
+    connected_components_in_2d_grid(grid, &TAXICAB_DIRECTION_VECTORS_2D, |&a, &b| a && b)
+        .into_iter()
+        .filter(|region| grid[region[0].0][region[0].1])
+        .collect()
+}
+
+/// Counts the regions of a grid of bits, ie the groups of set bits
+/// connected horizontally or vertically.
+///
+/// ```
+/// use enontekio::bits::{count_regions, grid_from_hex_hashes};
+///
+/// // 1100
+/// // 0101
+/// // 1001
+/// let grid = grid_from_hex_hashes(&["c", "5", "9"]).unwrap();
+///
+/// assert_eq!(3, count_regions(&grid));
+/// ```
+pub fn count_regions(grid: &[Vec<bool>]) -> usize {
+    regions(grid).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_from_hashes() {
+        let grid = grid_from_hashes(&[[0xffu8, 0x00], [0x0f, 0xf0]]);
+
+        assert_eq!(2, grid.len());
+        assert_eq!(16, grid[0].len());
+        assert_eq!(16, count_set_bits(&grid));
+        assert_eq!(1, count_regions(&grid));
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = grid_from_hex_hashes(&["d0", "10", "0b"]).unwrap();
+        let regions = regions(&grid);
+
+        assert_eq!(vec![vec![(0, 0), (0, 1)], vec![(0, 3), (1, 3)], vec![(2, 4)], vec![(2, 6), (2, 7)]], regions);
+    }
+}
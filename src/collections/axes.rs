@@ -0,0 +1,134 @@
+/// Iterates over the cells of a column, from top to bottom.
+///
+/// Rows too short to have this column are skipped.
+pub fn iter_column<T>(grid: &[Vec<T>], j: usize) -> impl Iterator<Item = &T> {
+    grid.iter().filter_map(move |row| row.get(j))
+}
+
+/// Gets a copy of the cells of a column, from top to bottom.
+///
+/// ```
+/// use enontekio::collections::column;
+///
+/// let grid = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+/// assert_eq!(vec![2, 4, 6], column(&grid, 1));
+/// ```
+pub fn column<T: Clone>(grid: &[Vec<T>], j: usize) -> Vec<T> {
+    iter_column(grid, j).cloned().collect()
+}
+
+/// Iterates over the columns of a grid, from left to right,
+/// each column going from top to bottom.
+///
+/// This method assumes the grid argument to be a grid,
+/// ie each line is expected to have the same length.
+pub fn iter_columns<T>(grid: &[Vec<T>]) -> impl Iterator<Item = Vec<&T>> {
+    let width = grid.first().map_or(0, |row| row.len());
+
+    (0..width).map(move |j| iter_column(grid, j).collect())
+}
+
+/// Gets the diagonals of a grid, going down and right.
+///
+/// Diagonals are ordered from the bottom left cell to the top right one,
+/// each diagonal going from top to bottom.
+///
+/// ```
+/// use enontekio::collections::diagonals;
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+///
+/// let expected: Vec<Vec<&i32>> = vec![vec![&4], vec![&1, &5], vec![&2, &6], vec![&3]];
+/// assert_eq!(expected, diagonals(&grid));
+/// ```
+pub fn diagonals<T>(grid: &[Vec<T>]) -> Vec<Vec<&T>> {
+    let height = grid.len();
+    let width = grid.first().map_or(0, |row| row.len());
+    if height == 0 || width == 0 {
+        return Vec::new();
+    }
+
+    // A diagonal starts on the first column (from the bottom), then on the first row
+    let starts = (0..height).rev().map(|i| (i, 0))
+        .chain((1..width).map(|j| (0, j)));
+
+    starts
+        .map(|(i, j)| {
+            (0..)
+                .map_while(|k| grid.get(i + k)?.get(j + k))
+                .collect()
+        })
+        .collect()
+}
+
+/// Gets the anti-diagonals of a grid, going down and left.
+///
+/// Anti-diagonals are ordered from the top left cell to the bottom right one,
+/// each anti-diagonal going from top to bottom.
+///
+/// ```
+/// use enontekio::collections::anti_diagonals;
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+///
+/// let expected: Vec<Vec<&i32>> = vec![vec![&1], vec![&2, &4], vec![&3, &5], vec![&6]];
+/// assert_eq!(expected, anti_diagonals(&grid));
+/// ```
+pub fn anti_diagonals<T>(grid: &[Vec<T>]) -> Vec<Vec<&T>> {
+    let height = grid.len();
+    let width = grid.first().map_or(0, |row| row.len());
+    if height == 0 || width == 0 {
+        return Vec::new();
+    }
+
+    // An anti-diagonal starts on the first row, then on the last column
+    let starts = (0..width).map(|j| (0, j))
+        .chain((1..height).map(|i| (i, width - 1)));
+
+    starts
+        .map(|(i, j)| {
+            (0..=j)
+                .map_while(|k| grid.get(i + k)?.get(j - k))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns() {
+        let grid = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+
+        let columns: Vec<String> = iter_columns(&grid)
+            .map(|column| column.into_iter().collect())
+            .collect();
+
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+        assert!(column(&grid, 3).is_empty());
+    }
+
+    #[test]
+    fn test_diagonals_cover_every_cell_once() {
+        let grid: Vec<Vec<usize>> = (0..4).map(|i| (0..3).map(|j| i * 3 + j).collect()).collect();
+
+        for lines in [diagonals(&grid), anti_diagonals(&grid)] {
+            assert_eq!(6, lines.len());
+
+            let mut cells: Vec<usize> = lines.into_iter().flatten().copied().collect();
+            cells.sort();
+            assert_eq!((0..12).collect::<Vec<_>>(), cells);
+        }
+    }
+
+    #[test]
+    fn test_diagonals_of_empty_grid() {
+        let grid: Vec<Vec<u8>> = Vec::new();
+
+        assert!(diagonals(&grid).is_empty());
+        assert!(anti_diagonals(&grid).is_empty());
+        assert_eq!(0, iter_columns(&grid).count());
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+use std::ops::Neg;
+
+use crate::collections::{SparseGrid, ALL_DIRECTION_VECTORS_2D, TAXICAB_DIRECTION_VECTORS_2D};
+
+/// Order of the two coordinates of a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AxisOrder {
+    /// (i, j): the row first, then the column, like the grids of this crate
+    #[default]
+    RowMajor,
+
+    /// (x, y): the horizontal coordinate first, then the vertical one
+    XY,
+}
+
+/// Direction the vertical coordinate grows to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YAxis {
+    /// The vertical coordinate grows downwards, like the lines of a file
+    #[default]
+    Down,
+
+    /// The vertical coordinate grows upwards, like in mathematics
+    Up,
+}
+
+/// A convention to write 2D coordinates, to convert points
+/// and direction vectors to and from the crate convention.
+///
+/// The grids and direction vectors of this crate are row-major,
+/// with rows growing downwards: (i, j) is row i, column j.
+/// Puzzles often describe points as (x, y) instead, with y going down
+/// like on a screen, or going up like on a cartesian plane.
+///
+/// Conversions keep the origin: with an upwards y axis,
+/// the row i is at y = -i.
+///
+/// ```
+/// use enontekio::collections::CoordSystem;
+///
+/// let system = CoordSystem::CARTESIAN;
+///
+/// assert_eq!((0, 1), system.up());
+/// assert_eq!((-7, 3), system.to_row_major((3, 7)));
+/// assert_eq!((3, 7), system.from_row_major((-7, 3)));
+/// assert_eq!((3, -7), system.convert_to((3, 7), &CoordSystem::SCREEN));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CoordSystem {
+    pub axis_order: AxisOrder,
+    pub y_axis: YAxis,
+}
+
+impl CoordSystem {
+    /// (i, j) with rows growing downwards, the convention of this crate
+    pub const ROW_MAJOR: CoordSystem = CoordSystem { axis_order: AxisOrder::RowMajor, y_axis: YAxis::Down };
+
+    /// (x, y) with y growing downwards, like on a screen
+    pub const SCREEN: CoordSystem = CoordSystem { axis_order: AxisOrder::XY, y_axis: YAxis::Down };
+
+    /// (x, y) with y growing upwards, like on a cartesian plane
+    pub const CARTESIAN: CoordSystem = CoordSystem { axis_order: AxisOrder::XY, y_axis: YAxis::Up };
+
+    pub fn new(axis_order: AxisOrder, y_axis: YAxis) -> Self {
+        Self { axis_order, y_axis }
+    }
+
+    /// Converts coordinates written in this system to (i, j) coordinates.
+    ///
+    /// As the conversion is linear, it applies to points and direction vectors alike.
+    pub fn to_row_major<T: Copy + Neg<Output = T>>(&self, (a, b): (T, T)) -> (T, T) {
+        let (vertical, horizontal) = match self.axis_order {
+            AxisOrder::RowMajor => (a, b),
+            AxisOrder::XY => (b, a),
+        };
+
+        match self.y_axis {
+            YAxis::Down => (vertical, horizontal),
+            YAxis::Up => (-vertical, horizontal),
+        }
+    }
+
+    /// Converts (i, j) coordinates to coordinates written in this system.
+    pub fn from_row_major<T: Copy + Neg<Output = T>>(&self, (i, j): (T, T)) -> (T, T) {
+        let vertical = match self.y_axis {
+            YAxis::Down => i,
+            YAxis::Up => -i,
+        };
+
+        match self.axis_order {
+            AxisOrder::RowMajor => (vertical, j),
+            AxisOrder::XY => (j, vertical),
+        }
+    }
+
+    /// Converts coordinates written in this system to another one.
+    pub fn convert_to<T: Copy + Neg<Output = T>>(&self, coords: (T, T), target: &CoordSystem) -> (T, T) {
+        target.from_row_major(self.to_row_major(coords))
+    }
+
+    /// Gets the vector to move one cell up.
+    pub fn up(&self) -> (i32, i32) {
+        self.from_row_major((-1, 0))
+    }
+
+    /// Gets the vector to move one cell down.
+    pub fn down(&self) -> (i32, i32) {
+        self.from_row_major((1, 0))
+    }
+
+    /// Gets the vector to move one cell left.
+    pub fn left(&self) -> (i32, i32) {
+        self.from_row_major((0, -1))
+    }
+
+    /// Gets the vector to move one cell right.
+    pub fn right(&self) -> (i32, i32) {
+        self.from_row_major((0, 1))
+    }
+
+    /// Returns the vectors to move horizontally or vertically, in this system.
+    pub fn taxicab_direction_vectors(&self) -> Vec<(i32, i32)> {
+        self.convert_vectors(&TAXICAB_DIRECTION_VECTORS_2D)
+    }
+
+    /// Returns the vectors to move horizontally, vertically or in diagonal, in this system.
+    pub fn all_direction_vectors(&self) -> Vec<(i32, i32)> {
+        self.convert_vectors(&ALL_DIRECTION_VECTORS_2D)
+    }
+
+    fn convert_vectors(&self, vectors: &[(i32, i32)]) -> Vec<(i32, i32)> {
+        vectors.iter().map(|&vector| self.from_row_major(vector)).collect()
+    }
+
+    /// Converts the points of a parsed map, like the output
+    /// of parser::parse_sparse_map_str, to this system.
+    ///
+    /// ```
+    /// use enontekio::collections::CoordSystem;
+    /// use enontekio::parser;
+    ///
+    /// let points = parser::parse_sparse_map_str("..#\n#..", '#');
+    /// let points = CoordSystem::SCREEN.from_row_major_points(points);
+    ///
+    /// assert!(points.contains(&(2, 0)));
+    /// assert!(points.contains(&(0, 1)));
+    /// ```
+    pub fn from_row_major_points<I>(&self, points: I) -> HashSet<(i64, i64)>
+    where I: IntoIterator<Item = (i64, i64)> {
+        points.into_iter().map(|point| self.from_row_major(point)).collect()
+    }
+
+    /// Converts a grid whose cells are located in this system
+    /// to a grid located by (i, j) coordinates, ready to be rendered.
+    ///
+    /// ```
+    /// use enontekio::collections::{CoordSystem, RenderOptions, SparseGrid};
+    ///
+    /// let mut grid = SparseGrid::new('.');
+    /// grid.set((0, 0), 'o');
+    /// grid.set((2, 1), '#');
+    ///
+    /// let grid = CoordSystem::CARTESIAN.to_row_major_grid(&grid);
+    /// assert_eq!("..#\no..\n", grid.render(&RenderOptions::default(), |&c| c));
+    /// ```
+    pub fn to_row_major_grid<T: Clone>(&self, grid: &SparseGrid<T>) -> SparseGrid<T> {
+        let mut converted = SparseGrid::new(grid.default_value().clone());
+        for (coords, value) in grid.iter() {
+            converted.set(self.to_row_major(coords), value.clone());
+        }
+
+        converted
+    }
+
+    /// Converts a grid located by (i, j) coordinates, like a parsed map,
+    /// to a grid whose cells are located in this system.
+    pub fn from_row_major_grid<T: Clone>(&self, grid: &SparseGrid<T>) -> SparseGrid<T> {
+        let mut converted = SparseGrid::new(grid.default_value().clone());
+        for (coords, value) in grid.iter() {
+            converted.set(self.from_row_major(coords), value.clone());
+        }
+
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYSTEMS: [CoordSystem; 4] = [
+        CoordSystem::ROW_MAJOR,
+        CoordSystem::SCREEN,
+        CoordSystem::CARTESIAN,
+        CoordSystem { axis_order: AxisOrder::RowMajor, y_axis: YAxis::Up },
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for system in SYSTEMS {
+            assert_eq!((4, -9), system.from_row_major(system.to_row_major((4, -9))));
+            assert_eq!((4, -9), system.to_row_major(system.from_row_major((4, -9))));
+        }
+    }
+
+    #[test]
+    fn test_direction_vectors() {
+        assert_eq!((-1, 0), CoordSystem::ROW_MAJOR.up());
+        assert_eq!((0, -1), CoordSystem::SCREEN.up());
+        assert_eq!((1, 0), CoordSystem::SCREEN.right());
+        assert_eq!((0, -1), CoordSystem::CARTESIAN.down());
+        assert_eq!((-1, 0), CoordSystem::CARTESIAN.left());
+
+        for system in SYSTEMS {
+            assert_eq!((-1, 0), system.to_row_major(system.up()));
+            assert_eq!(4, system.taxicab_direction_vectors().len());
+            assert_eq!(8, system.all_direction_vectors().len());
+        }
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let grid = SparseGrid::from_dense(&[vec!['#', '.'], vec!['.', '#']], '.');
+        let converted = CoordSystem::CARTESIAN.from_row_major_grid(&grid);
+
+        assert_eq!(&'#', converted.get((1, -1)));
+        assert_eq!(grid.to_dense(), CoordSystem::CARTESIAN.to_row_major_grid(&converted).to_dense());
+    }
+}
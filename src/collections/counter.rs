@@ -0,0 +1,196 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+use crate::collections::top_k;
+
+/// A multiset, counting how many times each item has been added.
+///
+/// Items never added, or whose count dropped to zero, aren't stored.
+///
+/// ```
+/// use enontekio::collections::Counter;
+///
+/// let letters: Counter<char> = "abracadabra".chars().collect();
+///
+/// assert_eq!(5, letters.get(&'a'));
+/// assert_eq!(0, letters.get(&'z'));
+/// assert_eq!(vec![(&'a', 5), (&'b', 2), (&'r', 2)], letters.most_common(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Eq + Hash> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Default for Counter<T> {
+    fn default() -> Self {
+        Self { counts: HashMap::new() }
+    }
+}
+
+impl<T: Eq + Hash> Counter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item once.
+    pub fn add(&mut self, item: T) {
+        self.add_count(item, 1);
+    }
+
+    /// Adds an item several times.
+    pub fn add_count(&mut self, item: T, count: usize) {
+        if count > 0 {
+            *self.counts.entry(item).or_insert(0) += count;
+        }
+    }
+
+    /// Removes an item several times, down to zero.
+    pub fn remove_count(&mut self, item: &T, count: usize) {
+        if let Some(current) = self.counts.get_mut(item) {
+            if *current > count {
+                *current -= count;
+            } else {
+                self.counts.remove(item);
+            }
+        }
+    }
+
+    /// Gets how many times an item has been added.
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Gets the number of distinct items.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Gets the number of items, counting each repetition.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Iterates over the items and their counts, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+}
+
+impl<T: Eq + Hash + Ord> Counter<T> {
+    /// Gets the n most common items, from the most common.
+    ///
+    /// Items with the same count are sorted in ascending order.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        top_k(self.iter().map(|(item, count)| (count, Reverse(item))), n)
+            .into_iter()
+            .map(|(count, Reverse(item))| (item, count))
+            .collect()
+    }
+
+    /// Gets the most common item, the smallest one in case of a tie.
+    pub fn max_by_count(&self) -> Option<(&T, usize)> {
+        self.iter().max_by_key(|&(item, count)| (count, Reverse(item)))
+    }
+
+    /// Gets the least common item, the smallest one in case of a tie.
+    pub fn min_by_count(&self) -> Option<(&T, usize)> {
+        self.iter().min_by_key(|&(item, count)| (count, item))
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+
+        counter
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+/// Adds the counts of both counters.
+impl<T: Eq + Hash> Add for Counter<T> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (item, count) in other.counts {
+            self.add_count(item, count);
+        }
+
+        self
+    }
+}
+
+/// Subtracts the counts of the other counter, keeping only positive counts.
+///
+/// ```
+/// use enontekio::collections::Counter;
+///
+/// let available: Counter<char> = "aabbc".chars().collect();
+/// let needed: Counter<char> = "abbbd".chars().collect();
+///
+/// let missing = needed - available;
+/// assert_eq!(vec![(&'b', 1), (&'d', 1)], missing.most_common(5));
+/// ```
+impl<T: Eq + Hash> Sub for Counter<T> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        for (item, count) in other.counts {
+            self.remove_count(&item, count);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let mut counter: Counter<&str> = vec!["a", "b", "a"].into_iter().collect();
+        counter.add_count("c", 0);
+        counter.remove_count(&"b", 5);
+
+        assert_eq!(1, counter.len());
+        assert_eq!(2, counter.total());
+        assert_eq!(0, counter.get(&"b"));
+        assert!(Counter::<u8>::new().is_empty());
+    }
+
+    #[test]
+    fn test_min_max_by_count() {
+        let counter: Counter<u8> = vec![3, 1, 3, 2, 2, 4].into_iter().collect();
+
+        assert_eq!(Some((&2, 2)), counter.max_by_count());
+        assert_eq!(Some((&1, 1)), counter.min_by_count());
+        assert_eq!(None, Counter::<u8>::new().max_by_count());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a: Counter<char> = "aab".chars().collect();
+        let b: Counter<char> = "abc".chars().collect();
+
+        let sum = a.clone() + b.clone();
+        assert_eq!(vec![(&'a', 3), (&'b', 2), (&'c', 1)], sum.most_common(10));
+
+        let difference = a - b;
+        assert_eq!(vec![(&'a', 1)], difference.most_common(10));
+    }
+}
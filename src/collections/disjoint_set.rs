@@ -0,0 +1,186 @@
+use crate::collections::get_neighbors_in_2d_grid;
+
+/// A disjoint-set, or union-find, structure over the elements 0 to n - 1.
+///
+/// Uses path compression and union by rank, so operations are
+/// nearly constant time.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    set_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates a disjoint-set where each of the n elements is in its own set.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+            set_count: n,
+        }
+    }
+
+    /// Gets the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Gets the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Finds the representative of the set containing the element.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Path compression: every element on the path now points to the root.
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing a and b.
+    ///
+    /// Returns false if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+
+        if root_a == root_b {
+            return false;
+        }
+
+        match self.ranks[root_a].cmp(&self.ranks[root_b]) {
+            std::cmp::Ordering::Less => self.parents[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parents[root_b] = root_a,
+            std::cmp::Ordering::Equal => {
+                self.parents[root_b] = root_a;
+                self.ranks[root_a] += 1;
+            }
+        }
+
+        self.set_count -= 1;
+        true
+    }
+
+    /// Checks if a and b are in the same set.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Gets the elements of each set.
+    ///
+    /// Sets are ordered by their smallest element, and elements are sorted in each set.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut set_indices = vec![usize::MAX; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+
+            if set_indices[root] == usize::MAX {
+                set_indices[root] = sets.len();
+                sets.push(Vec::new());
+            }
+            sets[set_indices[root]].push(element);
+        }
+
+        sets
+    }
+}
+
+/// Gets the connected components of a 2D grid.
+///
+/// Two neighbor cells, following the direction vectors, belong to the same component
+/// when the predicate returns true for their values, for example `|a, b| a == b`
+/// to group cells by value.
+///
+/// Components are ordered by their first cell, in row-major order.
+///
+/// ```
+/// use enontekio::collections::{connected_components_in_2d_grid, get_taxicab_direction_vectors_2d};
+///
+/// let grid = vec![
+///     vec!['A', 'A', 'B'],
+///     vec!['C', 'A', 'B'],
+/// ];
+///
+/// let components = connected_components_in_2d_grid(&grid, &get_taxicab_direction_vectors_2d(), |a, b| a == b);
+/// assert_eq!(3, components.len());
+/// assert_eq!(vec![(0, 0), (0, 1), (1, 1)], components[0]);
+/// ```
+pub fn connected_components_in_2d_grid<T, D, F>(grid: &[Vec<T>], directions: &[(D, D)], predicate: F) -> Vec<Vec<(usize, usize)>>
+where D: Into<i64> + Copy, F: Fn(&T, &T) -> bool {
+    if grid.is_empty() {
+        return Vec::new();
+    }
+
+    let width = grid[0].len();
+    let mut set = DisjointSet::new(grid.len() * width);
+
+    for i in 0..grid.len() {
+        for j in 0..width {
+            for (ni, nj) in get_neighbors_in_2d_grid(grid, i, j, directions) {
+                if predicate(&grid[i][j], &grid[ni][nj]) {
+                    set.union(i * width + j, ni * width + nj);
+                }
+            }
+        }
+    }
+
+    set.sets()
+        .into_iter()
+        .map(|elements| elements.into_iter().map(|element| (element / width, element % width)).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::get_all_direction_vectors_2d;
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut set = DisjointSet::new(5);
+        assert_eq!(5, set.set_count());
+
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 4));
+        assert!(set.union(1, 4));
+        assert!(!set.union(0, 3));
+
+        assert_eq!(2, set.set_count());
+        assert!(set.same_set(0, 4));
+        assert!(!set.same_set(2, 4));
+        assert_eq!(vec![vec![0, 1, 3, 4], vec![2]], set.sets());
+    }
+
+    #[test]
+    fn test_connected_components_in_2d_grid() {
+        let grid = vec![
+            vec![1, 0, 0],
+            vec![0, 1, 0],
+            vec![0, 0, 1],
+        ];
+
+        let components = connected_components_in_2d_grid(&grid, &get_all_direction_vectors_2d(), |a, b| a == b);
+
+        assert_eq!(2, components.len());
+        assert_eq!(vec![(0, 0), (1, 1), (2, 2)], components[0]);
+        assert_eq!(6, components[1].len());
+    }
+}
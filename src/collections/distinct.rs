@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Removes the duplicates of an iterator, keeping the first occurrence of each
+/// element in its original order.
+///
+/// ```
+/// use enontekio::collections::dedup_preserve_order;
+///
+/// assert_eq!(vec![3, 1, 2], dedup_preserve_order(vec![3, 1, 3, 2, 1]));
+/// ```
+pub fn dedup_preserve_order<I, T>(iter: I) -> Vec<T>
+where I: IntoIterator<Item = T>, T: Hash + Eq + Clone {
+    let mut seen = HashSet::new();
+
+    iter.into_iter()
+        .filter(|item| seen.insert(item.clone()))
+        .collect()
+}
+
+/// Gets the first element appearing a second time in an iterator.
+///
+/// The iterator is consumed only up to that element, so it can be infinite.
+///
+/// ```
+/// use enontekio::collections::first_duplicate;
+///
+/// assert_eq!(Some(3), first_duplicate(vec![1, 3, 2, 3, 1]));
+/// ```
+pub fn first_duplicate<I, T>(iter: I) -> Option<T>
+where I: IntoIterator<Item = T>, T: Hash + Eq + Clone {
+    let mut seen = HashSet::new();
+
+    iter.into_iter().find(|item| !seen.insert(item.clone()))
+}
+
+/// Checks if all the elements of an iterator are distinct.
+///
+/// ```
+/// use enontekio::collections::all_distinct;
+///
+/// assert!(all_distinct("abcd".chars()));
+/// assert!(!all_distinct("abca".chars()));
+/// ```
+pub fn all_distinct<I, T>(iter: I) -> bool
+where I: IntoIterator<Item = T>, T: Hash + Eq {
+    let mut seen = HashSet::new();
+
+    iter.into_iter().all(|item| seen.insert(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_preserve_order() {
+        assert_eq!(vec!["b", "a"], dedup_preserve_order(["b", "a", "b", "b"]));
+        assert!(dedup_preserve_order(Vec::<u32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_first_duplicate() {
+        assert_eq!(None, first_duplicate(vec![1, 2, 3]));
+        assert_eq!(Some(0), first_duplicate((0..5).cycle()));
+    }
+
+    #[test]
+    fn test_all_distinct() {
+        assert!(all_distinct(Vec::<u32>::new()));
+        assert!(all_distinct(["aa", "bb", "cc"]));
+        assert!(!all_distinct(["aa", "bb", "aa"]));
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+/// Counts the cells of a grid enclosed by a loop, by the even-odd rule.
+///
+/// Each row is scanned from left to right: a cell outside the loop is
+/// enclosed if the scan crossed the loop an odd number of times before it.
+///
+/// The crossing rule tells if a loop cell crosses the scan line, ie if
+/// the loop goes through the top side of the cell. Corners are the
+/// tricky part: along a └┘ pair, the loop touches the line and goes back
+/// up, so it's counted twice, while along a └┐ pair, the loop crosses it,
+/// so it's counted once. Counting only the cells connected to the cell
+/// above, like |, L or J pipes, handles both cases.
+///
+/// ```
+/// use enontekio::collections::count_enclosed_cells;
+///
+/// let grid: Vec<Vec<char>> = [
+///     "..........",
+///     ".F------7.",
+///     ".|F----7|.",
+///     ".||....||.",
+///     ".|L-7F-J|.",
+///     ".|..||..|.",
+///     ".L--JL--J.",
+/// ].iter().map(|line| line.chars().collect()).collect();
+///
+/// let loop_cells: Vec<(usize, usize)> = (0..7)
+///     .flat_map(|i| (0..10).map(move |j| (i, j)))
+///     .filter(|&(i, j)| grid[i][j] != '.')
+///     .collect();
+///
+/// let enclosed = count_enclosed_cells(&loop_cells, (7, 10), |(i, j)| matches!(grid[i][j], '|' | 'L' | 'J'));
+/// assert_eq!(4, enclosed);
+/// ```
+pub fn count_enclosed_cells<F>(loop_cells: &[(usize, usize)], (rows, columns): (usize, usize), crossing_rule: F) -> usize
+where F: Fn((usize, usize)) -> bool {
+    let loop_cells: HashSet<_> = loop_cells.iter().copied().collect();
+    let mut count = 0;
+
+    for i in 0..rows {
+        let mut inside = false;
+
+        for j in 0..columns {
+            if loop_cells.contains(&(i, j)) {
+                if crossing_rule((i, j)) {
+                    inside = !inside;
+                }
+            } else if inside {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Gets the cells of a loop connected to the cell above, from the loop
+/// cells given in order, like when following the pipes from the start.
+///
+/// That's a crossing rule for count_enclosed_cells which doesn't need
+/// to know the shape of each tile, including an unknown start tile.
+///
+/// ```
+/// use enontekio::collections::{cells_connected_up, count_enclosed_cells};
+///
+/// // A 3x3 ring around (1, 1), clockwise from the top left corner
+/// let path = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0), (1, 0)];
+/// let connected_up = cells_connected_up(&path);
+///
+/// assert_eq!(1, count_enclosed_cells(&path, (3, 3), |cell| connected_up.contains(&cell)));
+/// ```
+pub fn cells_connected_up(path: &[(usize, usize)]) -> HashSet<(usize, usize)> {
+    let is_above = |(i, j): (usize, usize), (other_i, other_j): (usize, usize)| other_j == j && other_i + 1 == i;
+    let n = path.len();
+
+    (0..n)
+        .filter(|&k| {
+            let cell = path[k];
+            let previous = path[(k + n - 1) % n];
+            let next = path[(k + 1) % n];
+
+            is_above(cell, previous) || is_above(cell, next)
+        })
+        .map(|k| path[k])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A U shape: the notch between the two arms isn't enclosed
+    fn u_shape() -> Vec<(usize, usize)> {
+        vec![
+            (0, 0), (0, 1), (0, 2), (1, 2), (1, 3), (0, 3), (0, 4), (0, 5),
+            (1, 5), (2, 5), (3, 5), (3, 4), (3, 3), (3, 2), (3, 1), (3, 0), (2, 0), (1, 0),
+        ]
+    }
+
+    #[test]
+    fn test_corner_pairs() {
+        let path = u_shape();
+        let connected_up = cells_connected_up(&path);
+
+        // Row 1: (1, 2) and (1, 3) are a └┘ pair, touching the scan line
+        assert!(connected_up.contains(&(1, 2)) && connected_up.contains(&(1, 3)));
+
+        // Enclosed: (1, 1), (1, 4), then (2, 1) to (2, 4)
+        assert_eq!(6, count_enclosed_cells(&path, (4, 6), |cell| connected_up.contains(&cell)));
+    }
+
+    #[test]
+    fn test_crossing_rule_by_cells_connected_down() {
+        let path = u_shape();
+        let below = |(i, j): (usize, usize), (other_i, other_j): (usize, usize)| other_j == j && other_i == i + 1;
+        let n = path.len();
+        let connected_down: HashSet<_> = (0..n)
+            .filter(|&k| below(path[k], path[(k + n - 1) % n]) || below(path[k], path[(k + 1) % n]))
+            .map(|k| path[k])
+            .collect();
+
+        assert_eq!(6, count_enclosed_cells(&path, (4, 6), |cell| connected_down.contains(&cell)));
+    }
+
+    #[test]
+    fn test_no_loop() {
+        assert_eq!(0, count_enclosed_cells(&[], (5, 5), |_| true));
+    }
+}
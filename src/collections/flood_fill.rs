@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use crate::collections::get_neighbors_in_2d_grid;
+
+/// Gets the coordinates of every cell connected to the start cell,
+/// through cells satisfying the predicate.
+///
+/// The connectivity is given by the direction vectors, for example
+/// `get_taxicab_direction_vectors_2d()` for 4-connectivity or
+/// `get_all_direction_vectors_2d()` for 8-connectivity.
+///
+/// If the start cell doesn't satisfy the predicate, the set is empty.
+///
+/// ```
+/// use enontekio::collections::{flood_fill, get_taxicab_direction_vectors_2d};
+///
+/// let grid = vec![
+///     vec!['.', '#', '.'],
+///     vec!['.', '#', '.'],
+///     vec!['.', '.', '#'],
+/// ];
+///
+/// let region = flood_fill(&grid, (0, 0), &get_taxicab_direction_vectors_2d(), |&c| c == '.');
+/// assert_eq!(4, region.len());
+/// ```
+pub fn flood_fill<T, D, F>(grid: &[Vec<T>], start: (usize, usize), directions: &[(D, D)], predicate: F) -> HashSet<(usize, usize)>
+where D: Into<i64> + Copy, F: Fn(&T) -> bool {
+    let mut region = HashSet::new();
+
+    let is_valid_start = grid.get(start.0)
+        .and_then(|row| row.get(start.1))
+        .is_some_and(&predicate);
+
+    if !is_valid_start {
+        return region;
+    }
+
+    let mut to_visit = vec![start];
+    region.insert(start);
+
+    while let Some((i, j)) = to_visit.pop() {
+        for neighbor in get_neighbors_in_2d_grid(grid, i, j, directions) {
+            if !region.contains(&neighbor) && predicate(&grid[neighbor.0][neighbor.1]) {
+                region.insert(neighbor);
+                to_visit.push(neighbor);
+            }
+        }
+    }
+
+    region
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::{get_all_direction_vectors_2d, get_taxicab_direction_vectors_2d};
+
+    fn get_grid() -> Vec<Vec<char>> {
+        vec![
+            vec!['.', '#', '.'],
+            vec!['#', '.', '#'],
+            vec!['.', '#', '.'],
+        ]
+    }
+
+    #[test]
+    fn test_flood_fill_taxicab() {
+        let region = flood_fill(&get_grid(), (0, 0), &get_taxicab_direction_vectors_2d(), |&c| c == '.');
+
+        assert_eq!(HashSet::from([(0, 0)]), region);
+    }
+
+    #[test]
+    fn test_flood_fill_all_directions() {
+        let region = flood_fill(&get_grid(), (0, 0), &get_all_direction_vectors_2d(), |&c| c == '.');
+
+        assert_eq!(5, region.len());
+        assert!(region.contains(&(2, 2)));
+    }
+
+    #[test]
+    fn test_flood_fill_from_invalid_start() {
+        let directions = get_taxicab_direction_vectors_2d();
+
+        assert!(flood_fill(&get_grid(), (0, 1), &directions, |&c| c == '.').is_empty());
+        assert!(flood_fill(&get_grid(), (5, 5), &directions, |&c| c == '.').is_empty());
+    }
+}
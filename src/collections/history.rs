@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+
+/// A recorder of successive states, like the registers of a VM or the nodes
+/// expanded by a search, to inspect them once the run is completed.
+///
+/// Only the last <capacity> recorded states are kept, as a ring buffer.
+/// With a sampling rate n, only one state out of n is recorded.
+///
+/// Each state is stored with its step number, ie the number of states
+/// submitted before it, sampled or not.
+///
+/// ```
+/// use enontekio::collections::History;
+///
+/// let mut history = History::new(3);
+/// for i in 0..10 {
+///     history.record(i * i);
+/// }
+///
+/// assert_eq!(vec![(7, &49), (8, &64), (9, &81)], history.iter().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    entries: VecDeque<(usize, T)>,
+    capacity: usize,
+    sampling_rate: usize,
+    steps: usize,
+}
+
+impl<T> History<T> {
+    /// Creates a history keeping the last <capacity> states.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            sampling_rate: 1,
+            steps: 0,
+        }
+    }
+
+    /// Records only one state out of <sampling_rate>, starting with the first one.
+    pub fn with_sampling_rate(mut self, sampling_rate: usize) -> Self {
+        self.sampling_rate = sampling_rate.max(1);
+        self
+    }
+
+    /// Submits a state, recording it if it's sampled.
+    pub fn record(&mut self, state: T) {
+        let step = self.steps;
+        self.steps += 1;
+
+        if !step.is_multiple_of(self.sampling_rate) || self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((step, state));
+    }
+
+    /// Gets the number of states submitted, recorded or not.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Gets the number of recorded states currently kept.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the kept states, from the oldest, with their step number.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (usize, &T)> {
+        self.entries.iter().map(|(step, state)| (*step, state))
+    }
+
+    /// Gets the oldest kept state.
+    pub fn first(&self) -> Option<(usize, &T)> {
+        self.iter().next()
+    }
+
+    /// Gets the most recent state.
+    pub fn last(&self) -> Option<(usize, &T)> {
+        self.iter().next_back()
+    }
+
+    /// Finds the most recent state satisfying the predicate.
+    pub fn find_last<F>(&self, predicate: F) -> Option<(usize, &T)> where F: Fn(&T) -> bool {
+        self.iter().rev().find(|(_, state)| predicate(state))
+    }
+
+    /// Removes all the kept states and resets the step counter.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.steps = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_ring_buffer() {
+        let mut history = History::new(2);
+        assert!(history.is_empty());
+
+        history.record('a');
+        history.record('b');
+        history.record('c');
+
+        assert_eq!(3, history.steps());
+        assert_eq!(2, history.len());
+        assert_eq!(Some((1, &'b')), history.first());
+        assert_eq!(Some((2, &'c')), history.last());
+    }
+
+    #[test]
+    fn test_history_sampling() {
+        let mut history = History::new(10).with_sampling_rate(3);
+        for i in 0..10 {
+            history.record(i);
+        }
+
+        assert_eq!(vec![0, 3, 6, 9], history.iter().map(|(_, &state)| state).collect::<Vec<_>>());
+        assert_eq!(Some((6, &6)), history.find_last(|&state| state < 8));
+    }
+
+    #[test]
+    fn test_history_without_capacity() {
+        let mut history = History::new(0);
+        history.record(1);
+
+        assert!(history.is_empty());
+        assert_eq!(1, history.steps());
+    }
+}
@@ -1,3 +1,50 @@
+use std::iter;
+use std::ops::Neg;
+
+use num_integer::Integer;
+
+mod axes;
+mod coord_system;
+mod counter;
+mod disjoint_set;
+mod distinct;
+mod enclosed;
+mod flood_fill;
+mod history;
+mod neighbors;
+mod paths;
+mod prefix_sum;
+mod ragged;
+mod reachable;
+mod render;
+mod search;
+mod sparse_grid;
+mod split;
+mod top_k;
+mod transform;
+mod windows;
+
+pub use axes::{anti_diagonals, column, diagonals, iter_column, iter_columns};
+pub use coord_system::{AxisOrder, CoordSystem, YAxis};
+pub use counter::Counter;
+pub use disjoint_set::{connected_components_in_2d_grid, DisjointSet};
+pub use distinct::{all_distinct, dedup_preserve_order, first_duplicate};
+pub use enclosed::{cells_connected_up, count_enclosed_cells};
+pub use flood_fill::flood_fill;
+pub use history::History;
+pub use neighbors::{map_with_neighbors, Neighborhood};
+pub use paths::{count_monotone_paths, count_monotone_paths_without_obstacles};
+pub use prefix_sum::PrefixSum2D;
+pub use ragged::{check_rectangular, is_rectangular, RaggedCoordinates2D, RaggedGridError};
+pub use reachable::{bfs_distances, reachable_after_steps, reachable_after_steps_tiled, reachable_counts_tiled};
+pub use render::{render_grid, RenderOptions};
+pub use search::{find_pattern, pattern_orientations, search_word, PatternMatch, PatternOptions};
+pub use sparse_grid::SparseGrid;
+pub use split::{split_when, SeparatorPolicy};
+pub use top_k::{bottom_k, bottom_k_by_key, top_k, top_k_by_key};
+pub use transform::{flip_horizontally, flip_vertically, for_each_cell_mut, map_grid, rotate_180, rotate_270, rotate_90, transpose};
+pub use windows::{count_increasing_windows, window_map, windows_sum, WindowMap};
+
 pub trait Coordinates2D {
     /// Gets all the coordinates of a 2D data structure, like a vector of vectors.
     /// That allows to iterate directly with a map against (i, j)
@@ -17,6 +64,9 @@ pub trait Coordinates2D {
     /// ```
     ///
     /// This function is mainly useful to navigate in rows and columns of a numbers grid.
+    ///
+    /// Every row is assumed to have the length of the first one: for ragged
+    /// vectors, use RaggedCoordinates2D, or check_rectangular to reject them.
     fn coordinates_2d(&self) -> Vec<(usize, usize)>;
 }
 
@@ -24,118 +74,428 @@ pub trait Coordinates3D {
     fn coordinates_3d(&self) -> Vec<(usize, usize, usize)>;
 }
 
+/// Iterates over the coordinates of a 2D data structure, without allocating them.
+///
+/// That's a separate trait from Coordinates2D, so implementors
+/// of Coordinates2D don't have to provide it.
+pub trait IterCoordinates2D {
+    /// Iterates over all the coordinates of a 2D data structure, row by row.
+    ///
+    /// Every row is assumed to have the length of the first one.
+    ///
+    /// ```
+    /// use enontekio::collections::IterCoordinates2D;
+    ///
+    /// let grid = [[1, 2, 3], [4, 5, 6]];
+    /// let corner = grid.iter_coordinates_2d().last();
+    ///
+    /// assert_eq!(Some((1, 2)), corner);
+    /// ```
+    fn iter_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)>;
+}
+
+/// Iterates over the coordinates of a 3D data structure, without allocating them.
+pub trait IterCoordinates3D {
+    fn iter_coordinates_3d(&self) -> impl Iterator<Item = (usize, usize, usize)>;
+}
+
+/// Iterates over the coordinates of a grid, assuming each row has the same length.
+fn iter_coordinates_2d(height: usize, width: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+}
+
+fn iter_coordinates_3d(height: usize, width: usize, depth: usize) -> impl Iterator<Item = (usize, usize, usize)> {
+    iter_coordinates_2d(height, width).flat_map(move |(i, j)| (0..depth).map(move |k| (i, j, k)))
+}
+
+impl<T> IterCoordinates2D for Vec<Vec<T>> {
+    fn iter_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.as_slice().iter_coordinates_2d()
+    }
+}
+
+impl<T> IterCoordinates2D for [Vec<T>] {
+    fn iter_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)> {
+        iter_coordinates_2d(self.len(), self.first().map_or(0, |row| row.len()))
+    }
+}
+
+impl<T, const N: usize> IterCoordinates2D for [[T; N]] {
+    fn iter_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)> {
+        iter_coordinates_2d(self.len(), N)
+    }
+}
+
+impl<T, const N: usize, const M: usize> IterCoordinates2D for [[T; N]; M] {
+    fn iter_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)> {
+        iter_coordinates_2d(M, N)
+    }
+}
+
+impl<T> IterCoordinates3D for Vec<Vec<Vec<T>>> {
+    fn iter_coordinates_3d(&self) -> impl Iterator<Item = (usize, usize, usize)> {
+        self.as_slice().iter_coordinates_3d()
+    }
+}
+
+impl<T> IterCoordinates3D for [Vec<Vec<T>>] {
+    fn iter_coordinates_3d(&self) -> impl Iterator<Item = (usize, usize, usize)> {
+        let width = self.first().map_or(0, |plane| plane.len());
+        let depth = self.first().and_then(|plane| plane.first()).map_or(0, |row| row.len());
+
+        iter_coordinates_3d(self.len(), width, depth)
+    }
+}
+
+impl<T, const N: usize, const M: usize, const L: usize> IterCoordinates3D for [[[T; N]; M]; L] {
+    fn iter_coordinates_3d(&self) -> impl Iterator<Item = (usize, usize, usize)> {
+        iter_coordinates_3d(L, M, N)
+    }
+}
+
 impl<T> Coordinates2D for Vec<Vec<T>> {
     fn coordinates_2d(&self) -> Vec<(usize, usize)> {
-        (0..self.len())
-            .flat_map(|i| (0..self[0].len()).map(move |j| (i, j)))
-            .collect()
+        self.iter_coordinates_2d().collect()
+    }
+}
+
+impl<T> Coordinates2D for [Vec<T>] {
+    fn coordinates_2d(&self) -> Vec<(usize, usize)> {
+        self.iter_coordinates_2d().collect()
+    }
+}
+
+impl<T, const N: usize> Coordinates2D for [[T; N]] {
+    fn coordinates_2d(&self) -> Vec<(usize, usize)> {
+        self.iter_coordinates_2d().collect()
+    }
+}
+
+impl<T, const N: usize, const M: usize> Coordinates2D for [[T; N]; M] {
+    fn coordinates_2d(&self) -> Vec<(usize, usize)> {
+        self.iter_coordinates_2d().collect()
     }
 }
 
 impl<T> Coordinates3D for Vec<Vec<Vec<T>>> {
     fn coordinates_3d(&self) -> Vec<(usize, usize, usize)> {
-        (0..self.len())
-            .flat_map(|i| (0..self[0].len())
-                .flat_map(move |j| (0..self[0][0].len()).map(move |k| (i, j, k))))
-            .collect()
+        self.iter_coordinates_3d().collect()
     }
 }
 
-/// Returns all the possible vectors to move in a grid.
+impl<T> Coordinates3D for [Vec<Vec<T>>] {
+    fn coordinates_3d(&self) -> Vec<(usize, usize, usize)> {
+        self.iter_coordinates_3d().collect()
+    }
+}
+
+impl<T, const N: usize, const M: usize, const L: usize> Coordinates3D for [[[T; N]; M]; L] {
+    fn coordinates_3d(&self) -> Vec<(usize, usize, usize)> {
+        self.iter_coordinates_3d().collect()
+    }
+}
+
+/// All the possible vectors to move in a grid.
 /// The moves can be horizontal, vertical or in diagonal.
-pub fn get_all_direction_vectors_2d() -> Vec<(i32, i32)> {
-    vec![
-        // Vertically
-        (1, 0),
-        (-1, 0),
+pub const ALL_DIRECTION_VECTORS_2D: [(i32, i32); 8] = [
+    // Vertically
+    (1, 0),
+    (-1, 0),
+
+    // Horizontally
+    (0, 1),
+    (0, -1),
+
+    // Diagonally
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// All the possible vectors to move in a grid in taxicab geometry.
+/// The moves can be horizontal or vertical.
+pub const TAXICAB_DIRECTION_VECTORS_2D: [(i32, i32); 4] = [
+    // Vertically
+    (1, 0),
+    (-1, 0),
+
+    // Horizontally
+    (0, 1),
+    (0, -1),
+];
+
+/// All the possible vectors to move in a grid in diagonal.
+pub const DIAGONAL_DIRECTION_VECTORS_2D: [(i32, i32); 4] = [
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// All the possible vectors to move in a 3D grid in taxicab geometry,
+/// ie the 6 faces neighbors.
+pub const TAXICAB_DIRECTION_VECTORS_3D: [(i32, i32, i32); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// All the possible vectors to move in a 3D grid,
+/// ie the 26 neighbors sharing a face, an edge or a corner.
+pub const ALL_DIRECTION_VECTORS_3D: [(i32, i32, i32); 26] = {
+    let mut vectors = [(0, 0, 0); 26];
+    let mut index = 0;
+
+    let mut n = 0;
+    while n < 27 {
+        let vector = (n / 9 - 1, n / 3 % 3 - 1, n % 3 - 1);
+        if n != 13 {
+            vectors[index] = vector;
+            index += 1;
+        }
+        n += 1;
+    }
+
+    vectors
+};
+
+/// The vectors of the moves of a knight on a chessboard,
+/// two cells in one direction then one cell perpendicularly.
+pub const KNIGHT_MOVE_VECTORS_2D: [(i32, i32); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (1, -2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+];
+
+/// Iterates over all the possible vectors to move in a grid,
+/// horizontally, vertically or in diagonal, without allocation.
+///
+/// ```
+/// use enontekio::collections::iter_all_direction_vectors_2d;
+///
+/// let (i, j) = (4, 7);
+/// let neighbors_sum: i32 = iter_all_direction_vectors_2d()
+///     .map(|(delta_i, delta_j)| (i + delta_i) + (j + delta_j))
+///     .sum();
+///
+/// assert_eq!(88, neighbors_sum);
+/// ```
+pub fn iter_all_direction_vectors_2d() -> impl Iterator<Item = (i32, i32)> {
+    ALL_DIRECTION_VECTORS_2D.into_iter()
+}
+
+/// Iterates over the vectors to move in a grid in taxicab geometry, without allocation.
+pub fn iter_taxicab_direction_vectors_2d() -> impl Iterator<Item = (i32, i32)> {
+    TAXICAB_DIRECTION_VECTORS_2D.into_iter()
+}
 
-        // Horizontally
-        (0, 1),
-        (0, -1),
+/// Iterates over the vectors to move in a grid in diagonal, without allocation.
+pub fn iter_diagonal_direction_vectors_2d() -> impl Iterator<Item = (i32, i32)> {
+    DIAGONAL_DIRECTION_VECTORS_2D.into_iter()
+}
+
+/// Iterates over the vectors to move in a 3D grid in taxicab geometry, without allocation.
+pub fn iter_taxicab_direction_vectors_3d() -> impl Iterator<Item = (i32, i32, i32)> {
+    TAXICAB_DIRECTION_VECTORS_3D.into_iter()
+}
 
-        // Diagonally
-        (1, 1),
-        (1, -1),
-        (-1, 1),
-        (-1, -1),
-    ]
+/// Iterates over all the vectors to move in a 3D grid, without allocation.
+pub fn iter_all_direction_vectors_3d() -> impl Iterator<Item = (i32, i32, i32)> {
+    ALL_DIRECTION_VECTORS_3D.into_iter()
+}
+
+/// Returns all the possible vectors to move in a grid.
+/// The moves can be horizontal, vertical or in diagonal.
+///
+/// Prefer ALL_DIRECTION_VECTORS_2D in a hot loop, to avoid the allocation.
+pub fn get_all_direction_vectors_2d() -> Vec<(i32, i32)> {
+    ALL_DIRECTION_VECTORS_2D.to_vec()
 }
 
 /// Returns all the possible vectors to move in a grid in taxicab geometry.
 /// The moves can be horizontal or vertical.
+///
+/// Prefer TAXICAB_DIRECTION_VECTORS_2D in a hot loop, to avoid the allocation.
 pub fn get_taxicab_direction_vectors_2d() -> Vec<(i32, i32)> {
-    vec![
-        // Vertically
-        (1, 0),
-        (-1, 0),
-
-        // Horizontally
-        (0, 1),
-        (0, -1),
-    ]
+    TAXICAB_DIRECTION_VECTORS_2D.to_vec()
 }
 
 /// Returns all the possible vectors to move in a grid in diagonal.
+///
+/// Prefer DIAGONAL_DIRECTION_VECTORS_2D in a hot loop, to avoid the allocation.
 pub fn get_diagonal_direction_vectors_2d() -> Vec<(i32, i32)> {
-    vec![
-        (1, 1),
-        (1, -1),
-        (-1, 1),
-        (-1, -1),
-    ]
+    DIAGONAL_DIRECTION_VECTORS_2D.to_vec()
+}
+
+/// Returns all the possible vectors to move in a 3D grid in taxicab geometry,
+/// ie the 6 faces neighbors.
+///
+/// Prefer TAXICAB_DIRECTION_VECTORS_3D in a hot loop, to avoid the allocation.
+pub fn get_taxicab_direction_vectors_3d() -> Vec<(i32, i32, i32)> {
+    TAXICAB_DIRECTION_VECTORS_3D.to_vec()
+}
+
+/// Returns all the possible vectors to move in a 3D grid,
+/// ie the 26 neighbors sharing a face, an edge or a corner.
+///
+/// Prefer ALL_DIRECTION_VECTORS_3D in a hot loop, to avoid the allocation.
+pub fn get_all_direction_vectors_3d() -> Vec<(i32, i32, i32)> {
+    ALL_DIRECTION_VECTORS_3D.to_vec()
+}
+
+/// Returns the vectors of the moves of a knight on a chessboard.
+pub fn get_knight_move_vectors_2d() -> Vec<(i32, i32)> {
+    KNIGHT_MOVE_VECTORS_2D.to_vec()
+}
+
+/// Returns the vectors to every cell within a Chebyshev distance,
+/// ie in the square of side 2r + 1 around a cell, except the cell itself.
+///
+/// A radius of 1 gives the same vectors as get_all_direction_vectors_2d,
+/// in another order.
+///
+/// ```
+/// use enontekio::collections::offsets_within_chebyshev_radius;
+///
+/// assert_eq!(24, offsets_within_chebyshev_radius(2).len());
+/// ```
+pub fn offsets_within_chebyshev_radius<T>(radius: T) -> Vec<(T, T)>
+where T: Integer + Neg<Output = T> + Copy {
+    offsets_within(radius, |delta_i, delta_j| abs(delta_i).max(abs(delta_j)))
+}
+
+/// Returns the vectors to every cell within a Manhattan distance,
+/// ie in the diamond around a cell, except the cell itself.
+///
+/// A radius of 1 gives the same vectors as get_taxicab_direction_vectors_2d,
+/// in another order.
+///
+/// ```
+/// use enontekio::collections::offsets_within_manhattan_radius;
+///
+/// assert_eq!(12, offsets_within_manhattan_radius(2).len());
+/// assert!(offsets_within_manhattan_radius(2).contains(&(-1, 1)));
+/// ```
+pub fn offsets_within_manhattan_radius<T>(radius: T) -> Vec<(T, T)>
+where T: Integer + Neg<Output = T> + Copy {
+    offsets_within(radius, |delta_i, delta_j| abs(delta_i) + abs(delta_j))
+}
+
+/// Gets the vectors sorted by row then column, whose distance is at most the radius.
+fn offsets_within<T, F>(radius: T, distance: F) -> Vec<(T, T)>
+where T: Integer + Neg<Output = T> + Copy, F: Fn(T, T) -> T {
+    let deltas = || {
+        let first = Some(-radius).filter(|&delta| delta <= radius);
+        iter::successors(first, move |&delta| (delta < radius).then(|| delta + T::one()))
+    };
+
+    deltas()
+        .flat_map(|delta_i| deltas().map(move |delta_j| (delta_i, delta_j)))
+        .filter(|&(delta_i, delta_j)| !(delta_i.is_zero() && delta_j.is_zero()) && distance(delta_i, delta_j) <= radius)
+        .collect()
+}
+
+fn abs<T: Integer + Neg<Output = T>>(value: T) -> T {
+    if value < T::zero() {
+        -value
+    } else {
+        value
+    }
 }
 
 /// Checks if the specified coordinates are valid for a specified 2D grid.
 /// A coordinate is valid if it doesn't overflow and is positive.
 ///
+/// Coordinates can be of any integer type, like i32 for small offsets
+/// or i64 for large virtual coordinates.
+///
 /// This method assumes the grid argument to be a grid,
 /// ie each line is expected to have the same length.
 ///
 /// This method has been designed to compute coordinates and filter the result.
-pub fn are_valid_coordinates_for_2d_grid<T>(grid: &Vec<Vec<T>>, coords: (i32, i32)) -> bool {
-    if grid.is_empty() {
-        return false;
-    }
+pub fn are_valid_coordinates_for_2d_grid<T, C>(grid: &[Vec<T>], coords: (C, C)) -> bool
+where C: TryInto<usize> {
+    to_coordinates_in_2d_grid(grid, coords).is_some()
+}
 
-    let i = coords.0;
-    let j = coords.1;
+/// Converts coordinates of any integer type to the coordinates
+/// of a cell of a 2D grid, or None if they're outside of the grid.
+///
+/// ```
+/// use enontekio::collections::to_coordinates_in_2d_grid;
+///
+/// let grid = vec![vec![0; 3]; 2];
+///
+/// assert_eq!(Some((1, 2)), to_coordinates_in_2d_grid(&grid, (1i64, 2i64)));
+/// assert_eq!(None, to_coordinates_in_2d_grid(&grid, (-1i64, 2i64)));
+/// assert_eq!(None, to_coordinates_in_2d_grid(&grid, (4_000_000_000i64, 0)));
+/// ```
+pub fn to_coordinates_in_2d_grid<T, C>(grid: &[Vec<T>], (i, j): (C, C)) -> Option<(usize, usize)>
+where C: TryInto<usize> {
+    let width = grid.first()?.len();
+    let i = i.try_into().ok()?;
+    let j = j.try_into().ok()?;
 
-    if i < 0 || j < 0 {
-        return false;
-    }
+    (i < grid.len() && j < width).then_some((i, j))
+}
 
-    let max_i = grid.len() - 1;
-    let max_j = grid[0].len() - 1;
+/// Moves from a cell of a 2D grid following a direction vector,
+/// or None if the cell reached is outside of the grid.
+///
+/// The direction vector can be of any integer type converting to i64.
+///
+/// ```
+/// use enontekio::collections::move_in_2d_grid;
+///
+/// let grid = vec![vec![0; 3]; 2];
+///
+/// assert_eq!(Some((1, 0)), move_in_2d_grid(&grid, (0, 2), (1i64, -2i64)));
+/// assert_eq!(None, move_in_2d_grid(&grid, (0, 2), (0i8, 1i8)));
+/// ```
+pub fn move_in_2d_grid<T, D>(grid: &[Vec<T>], (i, j): (usize, usize), (delta_i, delta_j): (D, D)) -> Option<(usize, usize)>
+where D: Into<i64> {
+    let i = i64::try_from(i).ok()?.checked_add(delta_i.into())?;
+    let j = i64::try_from(j).ok()?.checked_add(delta_j.into())?;
 
-    (i as usize) <= max_i && (j as usize) <= max_j
+    to_coordinates_in_2d_grid(grid, (i, j))
 }
 
-/// Gets the coordinates of each neighbor cell belonging to the grid.
+/// Gets the coordinates of each neighbor cell belonging to the grid,
+/// following the specified direction vectors, of any integer type converting to i64.
 ///
-/// Neighbors are only seeked horizontally or vertically.
 /// Any coordinates will belong to the grid.
-pub fn get_taxicab_neighbors_in_2d_grid<T> (grid: &Vec<Vec<T>>, i: usize, j: usize) -> Vec<(usize, usize)> {
-    get_taxicab_direction_vectors_2d()
+pub fn get_neighbors_in_2d_grid<T, D> (grid: &[Vec<T>], i: usize, j: usize, directions: &[(D, D)]) -> Vec<(usize, usize)>
+where D: Into<i64> + Copy {
+    directions
         .iter()
-        .map(|&(delta_i, delta_j)| (delta_i + i as i32, delta_j + j as i32))
-        .filter(|&coords| are_valid_coordinates_for_2d_grid(&grid, coords))
-        .map(|(i, j)| (i as usize, j as usize))
+        .filter_map(|&direction| move_in_2d_grid(grid, (i, j), direction))
         .collect()
 }
 
 /// Gets the coordinates of each neighbor cell belonging to the grid.
 ///
-/// Neighbors are only seeked horizontally, vertically or diagonally.
+/// Neighbors are only seeked horizontally or vertically.
 /// Any coordinates will belong to the grid.
-pub fn get_all_neighbors_in_2d_grid<T> (grid: &Vec<Vec<T>>, i: usize, j: usize) -> Vec<(usize, usize)> {
-    get_all_direction_vectors_2d()
-        .iter()
-        .map(|&(delta_i, delta_j)| (delta_i + i as i32, delta_j + j as i32))
-        .filter(|&coords| are_valid_coordinates_for_2d_grid(&grid, coords))
-        .map(|(i, j)| (i as usize, j as usize))
-        .collect()
+pub fn get_taxicab_neighbors_in_2d_grid<T> (grid: &[Vec<T>], i: usize, j: usize) -> Vec<(usize, usize)> {
+    get_neighbors_in_2d_grid(grid, i, j, &TAXICAB_DIRECTION_VECTORS_2D)
 }
 
+/// Gets the coordinates of each neighbor cell belonging to the grid.
+///
+/// Neighbors are only seeked horizontally, vertically or diagonally.
+/// Any coordinates will belong to the grid.
+pub fn get_all_neighbors_in_2d_grid<T> (grid: &[Vec<T>], i: usize, j: usize) -> Vec<(usize, usize)> {
+    get_neighbors_in_2d_grid(grid, i, j, &ALL_DIRECTION_VECTORS_2D)
+}
 
 #[cfg(test)]
 mod tests {
@@ -148,7 +508,7 @@ mod tests {
             vec![3, 4],
         ];
 
-        let expected = vec![(0 as usize, 0 as usize), (0, 1), (1, 0), (1, 1)];
+        let expected = vec![(0, 0), (0, 1), (1, 0), (1, 1)];
         assert_eq!(expected, digits.coordinates_2d());
     }
 
@@ -167,7 +527,7 @@ mod tests {
         ];
 
         let expected = vec![
-            (0 as usize, 0 as usize, 0 as usize), (0, 0, 1), (0, 1, 0), (0, 1, 1),
+            (0, 0, 0), (0, 0, 1), (0, 1, 0), (0, 1, 1),
             (1, 0, 0), (1, 0, 1), (1, 1, 0), (1, 1, 1),
         ];
         assert_eq!(expected, digits.coordinates_3d());
@@ -180,7 +540,7 @@ mod tests {
             vec![vec![5, 6], vec![7, 8]],
         ];
 
-        let expected = vec![(0 as usize, 0 as usize), (0, 1), (1, 0), (1, 1)];
+        let expected = vec![(0, 0), (0, 1), (1, 0), (1, 1)];
         assert_eq!(expected, digits.coordinates_2d());
     }
 
@@ -191,12 +551,82 @@ mod tests {
             vec![3, 4],
         ];
 
-        assert_eq!(true, are_valid_coordinates_for_2d_grid(&grid, (0, 0)));
-        assert_eq!(true, are_valid_coordinates_for_2d_grid(&grid, (0, 1)));
-        assert_eq!(true, are_valid_coordinates_for_2d_grid(&grid, (1, 0)));
-        assert_eq!(true, are_valid_coordinates_for_2d_grid(&grid, (1, 1)));
+        assert!(are_valid_coordinates_for_2d_grid(&grid, (0, 0)));
+        assert!(are_valid_coordinates_for_2d_grid(&grid, (0, 1)));
+        assert!(are_valid_coordinates_for_2d_grid(&grid, (1, 0)));
+        assert!(are_valid_coordinates_for_2d_grid(&grid, (1, 1)));
+
+        assert!(!are_valid_coordinates_for_2d_grid(&grid, (-1, 1)));
+        assert!(!are_valid_coordinates_for_2d_grid(&grid, (1, 3)));
+
+        assert!(are_valid_coordinates_for_2d_grid(&grid, (1usize, 1usize)));
+        assert!(!are_valid_coordinates_for_2d_grid(&grid, (1i64 << 40, 0)));
+        assert!(!are_valid_coordinates_for_2d_grid::<u32, i32>(&[], (0, 0)));
+    }
+
+    #[test]
+    fn test_neighbors_with_i64_directions() {
+        let grid = vec![vec![0; 3]; 3];
+        let directions = [(1i64 << 40, 0), (0, -1), (-1, 1)];
+
+        assert_eq!(vec![(1, 0), (0, 2)], get_neighbors_in_2d_grid(&grid, 1, 1, &directions));
+        assert_eq!(None, move_in_2d_grid(&grid, (0, 0), (i64::MAX, 0)));
+    }
+
+    #[test]
+    fn test_direction_vectors_constants() {
+        assert_eq!(get_all_direction_vectors_2d(), iter_all_direction_vectors_2d().collect::<Vec<_>>());
+        assert_eq!(4, iter_taxicab_direction_vectors_2d().count());
+        assert!(iter_diagonal_direction_vectors_2d().all(|(i, j)| i.abs() == 1 && j.abs() == 1));
+        assert_eq!(6, iter_taxicab_direction_vectors_3d().count());
+        assert_eq!(Some((-1, -1, -1)), iter_all_direction_vectors_3d().next());
+    }
+
+    #[test]
+    fn test_custom_neighborhoods() {
+        let knight = get_knight_move_vectors_2d();
+        assert!(knight.iter().all(|&(i, j)| i * i + j * j == 5));
+        assert_eq!(8, knight.len());
+
+        let mut chebyshev = offsets_within_chebyshev_radius(1);
+        let mut all = get_all_direction_vectors_2d();
+        chebyshev.sort();
+        all.sort();
+        assert_eq!(all, chebyshev);
+
+        let mut manhattan = offsets_within_manhattan_radius(1);
+        let mut taxicab = get_taxicab_direction_vectors_2d();
+        manhattan.sort();
+        taxicab.sort();
+        assert_eq!(taxicab, manhattan);
+
+        assert!(offsets_within_chebyshev_radius(0).is_empty());
+        assert!(offsets_within_manhattan_radius(-1).is_empty());
+        assert_eq!(24, offsets_within_chebyshev_radius(2i64).len());
+        assert_eq!(12, offsets_within_manhattan_radius(2i8).len());
+    }
+
+    #[test]
+    fn test_direction_vectors_3d() {
+        let taxicab = get_taxicab_direction_vectors_3d();
+        assert_eq!(6, taxicab.len());
+        assert!(taxicab.iter().all(|&(i, j, k)| i.abs() + j.abs() + k.abs() == 1));
+
+        let all = get_all_direction_vectors_3d();
+        assert_eq!(26, all.len());
+        assert!(taxicab.iter().all(|delta| all.contains(delta)));
+    }
+
+    #[test]
+    fn test_iter_coordinates_for_slices_and_arrays() {
+        let grid = vec![vec![0; 3]; 2];
+        let expected = vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)];
+
+        assert_eq!(expected, grid[..].iter_coordinates_2d().collect::<Vec<_>>());
+        assert_eq!(expected, [[0u8; 3]; 2].coordinates_2d());
+        assert_eq!(expected, [[0u8; 3], [0; 3]][..].coordinates_2d());
 
-        assert_eq!(false, are_valid_coordinates_for_2d_grid(&grid, (-1, 1)));
-        assert_eq!(false, are_valid_coordinates_for_2d_grid(&grid, (1, 3)));
+        assert_eq!(24, [[[0u8; 4]; 3]; 2].iter_coordinates_3d().count());
+        assert_eq!(Some((1, 2, 3)), [[[0u8; 4]; 3]; 2].iter_coordinates_3d().last());
     }
 }
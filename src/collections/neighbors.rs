@@ -0,0 +1,105 @@
+use crate::collections::{ALL_DIRECTION_VECTORS_2D, DIAGONAL_DIRECTION_VECTORS_2D, TAXICAB_DIRECTION_VECTORS_2D};
+
+/// The kind of neighborhood of a cell in a 2D grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Horizontal and vertical neighbors, also known as von Neumann neighborhood
+    Taxicab,
+
+    /// Diagonal neighbors only
+    Diagonal,
+
+    /// Horizontal, vertical and diagonal neighbors, also known as Moore neighborhood
+    All,
+}
+
+impl Neighborhood {
+    /// Gets the direction vectors leading to the neighbors.
+    pub fn direction_vectors(&self) -> Vec<(i32, i32)> {
+        self.direction_vectors_slice().to_vec()
+    }
+
+    /// Gets the direction vectors leading to the neighbors, without allocation.
+    pub fn direction_vectors_slice(&self) -> &'static [(i32, i32)] {
+        match self {
+            Neighborhood::Taxicab => &TAXICAB_DIRECTION_VECTORS_2D,
+            Neighborhood::Diagonal => &DIAGONAL_DIRECTION_VECTORS_2D,
+            Neighborhood::All => &ALL_DIRECTION_VECTORS_2D,
+        }
+    }
+}
+
+/// Builds a new grid by applying a function to each cell,
+/// receiving the cell value and the values of its neighbors in the grid.
+///
+/// Cells on the border simply have fewer neighbors.
+/// This is the core of cellular automata, convolutions or seating rules.
+///
+/// ```
+/// use enontekio::collections::{map_with_neighbors, Neighborhood};
+///
+/// let grid = vec![
+///     vec!['#', '.', '#'],
+///     vec!['.', '#', '.'],
+/// ];
+///
+/// let counts = map_with_neighbors(&grid, Neighborhood::All, |_, neighbors| {
+///     neighbors.iter().filter(|&&&c| c == '#').count()
+/// });
+///
+/// assert_eq!(vec![vec![1, 3, 1], vec![2, 2, 2]], counts);
+/// ```
+pub fn map_with_neighbors<T, U, F>(grid: &[Vec<T>], neighborhood: Neighborhood, mut f: F) -> Vec<Vec<U>>
+where F: FnMut(&T, &[&T]) -> U {
+    let directions = neighborhood.direction_vectors_slice();
+    let mut neighbors = Vec::with_capacity(directions.len());
+
+    grid.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, value)| {
+                    neighbors.clear();
+                    neighbors.extend(directions.iter().filter_map(|&(delta_i, delta_j)| {
+                        let ni = i.checked_add_signed(delta_i as isize)?;
+                        let nj = j.checked_add_signed(delta_j as isize)?;
+
+                        grid.get(ni)?.get(nj)
+                    }));
+
+                    f(value, &neighbors)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_with_neighbors_sum() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+        ];
+
+        let sums = map_with_neighbors(&grid, Neighborhood::Taxicab, |&value, neighbors| {
+            value + neighbors.iter().copied().sum::<i32>()
+        });
+
+        assert_eq!(vec![vec![7, 11, 11], vec![17, 25, 23], vec![19, 29, 23]], sums);
+    }
+
+    #[test]
+    fn test_map_with_neighbors_counts() {
+        let grid = vec![vec![0; 3]; 3];
+
+        let counts = map_with_neighbors(&grid, Neighborhood::Diagonal, |_, neighbors| neighbors.len());
+
+        assert_eq!(vec![vec![1, 2, 1], vec![2, 4, 2], vec![1, 2, 1]], counts);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::math::combinatorics::checked_binomial;
+
+/// Counts the paths from the top left cell to the bottom right cell of a grid,
+/// moving only right or down, through passable cells.
+///
+/// Each cell is reached from the cell above or the cell on the left,
+/// so the counts are summed row by row, in O(rows * columns).
+///
+/// The grid width is the one of the first row: cells beyond it are ignored,
+/// and cells missing from a shorter row are impassable.
+///
+/// Returns None if the count overflows an u64.
+///
+/// ```
+/// use enontekio::collections::count_monotone_paths;
+///
+/// let grid = vec![
+///     "...".chars().collect::<Vec<_>>(),
+///     ".#.".chars().collect(),
+///     "...".chars().collect(),
+/// ];
+///
+/// assert_eq!(Some(2), count_monotone_paths(&grid, |&c| c == '.'));
+/// ```
+pub fn count_monotone_paths<T, F>(grid: &[Vec<T>], passable: F) -> Option<u64>
+where F: Fn(&T) -> bool {
+    let width = match grid.first() {
+        None => return Some(0),
+        Some(row) => row.len(),
+    };
+
+    let mut counts = vec![0u64; width];
+    for (i, row) in grid.iter().enumerate() {
+        for j in 0..width {
+            counts[j] = match row.get(j) {
+                Some(cell) if passable(cell) => {
+                    if i == 0 && j == 0 {
+                        1
+                    } else {
+                        let left = if j > 0 { counts[j - 1] } else { 0 };
+                        counts[j].checked_add(left)?
+                    }
+                }
+                _ => 0,
+            };
+        }
+    }
+
+    Some(counts.last().copied().unwrap_or(0))
+}
+
+/// Counts the paths from the top left cell to the bottom right cell
+/// of a grid without obstacles, moving only right or down.
+///
+/// A path is an arrangement of rows - 1 moves down among
+/// rows + columns - 2 moves, so it's a binomial coefficient.
+///
+/// Returns None if the count doesn't fit in a u64.
+///
+/// ```
+/// use enontekio::collections::count_monotone_paths_without_obstacles;
+///
+/// // Project Euler 15: a 20x20 lattice has 21x21 points
+/// assert_eq!(Some(137846528820), count_monotone_paths_without_obstacles(21, 21));
+/// assert_eq!(None, count_monotone_paths_without_obstacles(100, 100));
+/// ```
+pub fn count_monotone_paths_without_obstacles(rows: u64, columns: u64) -> Option<u64> {
+    if rows == 0 || columns == 0 {
+        return Some(0);
+    }
+
+    checked_binomial((rows - 1).checked_add(columns - 1)?, rows - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_matches_formula_without_obstacles() {
+        for rows in 1..6 {
+            for columns in 1..6 {
+                let grid = vec![vec![true; columns]; rows];
+
+                assert_eq!(
+                    count_monotone_paths_without_obstacles(rows as u64, columns as u64),
+                    count_monotone_paths(&grid, |&cell| cell),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_with_obstacles() {
+        let blocked_start = vec![vec![false, true], vec![true, true]];
+        assert_eq!(Some(0), count_monotone_paths(&blocked_start, |&cell| cell));
+
+        let wall = vec![vec![true, false], vec![false, true]];
+        assert_eq!(Some(0), count_monotone_paths(&wall, |&cell| cell));
+
+        let empty: Vec<Vec<bool>> = vec![];
+        assert_eq!(Some(0), count_monotone_paths(&empty, |&cell| cell));
+        assert_eq!(Some(0), count_monotone_paths_without_obstacles(0, 5));
+    }
+
+    #[test]
+    fn test_count_with_ragged_rows() {
+        let grid = vec![vec![true, true], vec![true, true, true], vec![true]];
+
+        assert_eq!(Some(0), count_monotone_paths(&grid, |&cell| cell));
+        assert_eq!(Some(2), count_monotone_paths(&grid[..2], |&cell| cell));
+    }
+
+    #[test]
+    fn test_count_overflow() {
+        let grid = vec![vec![true; 40]; 40];
+
+        assert_eq!(None, count_monotone_paths(&grid, |&cell| cell));
+        assert!(count_monotone_paths(&grid[..20], |&cell| cell).is_some());
+
+        assert_eq!(None, count_monotone_paths_without_obstacles(40, 40));
+        assert_eq!(None, count_monotone_paths_without_obstacles(u64::MAX, 3));
+        assert_eq!(Some(1), count_monotone_paths_without_obstacles(u64::MAX, 1));
+    }
+}
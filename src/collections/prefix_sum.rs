@@ -0,0 +1,80 @@
+use std::ops::{Add, Sub};
+
+/// A summed-area table of a 2D grid, to get the sum of any rectangle in O(1).
+#[derive(Debug, Clone)]
+pub struct PrefixSum2D<T> {
+    /// sums[i][j] is the sum of the cells above and left of (i, j), exclusive,
+    /// so the first row and column are zeros.
+    sums: Vec<Vec<T>>,
+}
+
+impl<T> PrefixSum2D<T> where T: Copy + Default + Add<Output = T> + Sub<Output = T> {
+    /// Builds the summed-area table of a grid.
+    ///
+    /// This method assumes the grid argument to be a grid,
+    /// ie each line is expected to have the same length.
+    pub fn new(grid: &[Vec<T>]) -> Self {
+        let height = grid.len();
+        let width = grid.first().map_or(0, |row| row.len());
+        let mut sums = vec![vec![T::default(); width + 1]; height + 1];
+
+        for i in 0..height {
+            for j in 0..width {
+                sums[i + 1][j + 1] = grid[i][j] + sums[i][j + 1] + sums[i + 1][j] - sums[i][j];
+            }
+        }
+
+        Self { sums }
+    }
+
+    /// Gets the number of rows of the grid.
+    pub fn height(&self) -> usize {
+        self.sums.len() - 1
+    }
+
+    /// Gets the number of columns of the grid.
+    pub fn width(&self) -> usize {
+        self.sums[0].len() - 1
+    }
+
+    /// Gets the sum of the rectangle between two corners, both inclusive.
+    ///
+    /// ```
+    /// use enontekio::collections::PrefixSum2D;
+    ///
+    /// let sums = PrefixSum2D::new(&[vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(2 + 3 + 5 + 6, sums.sum((0, 1), (1, 2)));
+    /// ```
+    pub fn sum(&self, top_left: (usize, usize), bottom_right: (usize, usize)) -> T {
+        let (i1, j1) = top_left;
+        let (i2, j2) = (bottom_right.0 + 1, bottom_right.1 + 1);
+
+        self.sums[i2][j2] - self.sums[i1][j2] - self.sums[i2][j1] + self.sums[i1][j1]
+    }
+
+    /// Gets the sum of the square of the specified size with its top left corner at (i, j).
+    pub fn square_sum(&self, i: usize, j: usize, size: usize) -> T {
+        self.sum((i, j), (i + size - 1, j + size - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_sum_2d() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+        ];
+        let sums = PrefixSum2D::new(&grid);
+
+        assert_eq!(3, sums.height());
+        assert_eq!(3, sums.width());
+        assert_eq!(45, sums.sum((0, 0), (2, 2)));
+        assert_eq!(5, sums.sum((1, 1), (1, 1)));
+        assert_eq!(5 + 6 + 8 + 9, sums.square_sum(1, 1, 2));
+    }
+}
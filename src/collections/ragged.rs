@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Coordinates of 2D data structures whose rows can have different lengths.
+///
+/// Coordinates2D assumes every row has the length of the first one;
+/// this trait follows the length of each row instead.
+pub trait RaggedCoordinates2D {
+    /// Iterates over the coordinates of every cell, row by row.
+    ///
+    /// ```
+    /// use enontekio::collections::RaggedCoordinates2D;
+    ///
+    /// let triangle = vec![vec![1], vec![2, 3], vec![4, 5, 6]];
+    ///
+    /// let coordinates: Vec<_> = triangle.iter_ragged_coordinates_2d().collect();
+    /// assert_eq!(vec![(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (2, 2)], coordinates);
+    /// ```
+    fn iter_ragged_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)>;
+
+    fn ragged_coordinates_2d(&self) -> Vec<(usize, usize)> {
+        self.iter_ragged_coordinates_2d().collect()
+    }
+}
+
+impl<T> RaggedCoordinates2D for [Vec<T>] {
+    fn iter_ragged_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(i, row)| (0..row.len()).map(move |j| (i, j)))
+    }
+}
+
+impl<T> RaggedCoordinates2D for Vec<Vec<T>> {
+    fn iter_ragged_coordinates_2d(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.as_slice().iter_ragged_coordinates_2d()
+    }
+}
+
+/// Error returned when a rectangular grid is required,
+/// but a row hasn't the length of the first one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaggedGridError {
+    /// The index of the first row with an unexpected length
+    pub row: usize,
+
+    /// The length of the first row
+    pub expected: usize,
+
+    pub actual: usize,
+}
+
+impl Display for RaggedGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "row {} has {} cells, expected {} like the first row", self.row, self.actual, self.expected)
+    }
+}
+
+impl Error for RaggedGridError {}
+
+/// Checks if every row of a grid has the same length.
+pub fn is_rectangular<T>(grid: &[Vec<T>]) -> bool {
+    check_rectangular(grid).is_ok()
+}
+
+/// Checks if every row of a grid has the same length.
+///
+/// Returns the grid dimensions as (height, width),
+/// or an error describing the first row with another length.
+///
+/// ```
+/// use enontekio::collections::check_rectangular;
+///
+/// assert_eq!(Ok((2, 3)), check_rectangular(&[vec![1, 2, 3], vec![4, 5, 6]]));
+/// assert!(check_rectangular(&[vec![1, 2, 3], vec![4, 5]]).is_err());
+/// ```
+pub fn check_rectangular<T>(grid: &[Vec<T>]) -> Result<(usize, usize), RaggedGridError> {
+    let expected = grid.first().map_or(0, |row| row.len());
+
+    match grid.iter().position(|row| row.len() != expected) {
+        None => Ok((grid.len(), expected)),
+        Some(row) => Err(RaggedGridError {
+            row,
+            expected,
+            actual: grid[row].len(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::Coordinates2D;
+
+    #[test]
+    fn test_ragged_coordinates_cover_every_cell() {
+        let grid = vec![vec![1, 2, 3], vec![], vec![4], vec![5, 6, 7, 8]];
+
+        let coordinates = grid.ragged_coordinates_2d();
+
+        assert_eq!(8, coordinates.len());
+        assert!(coordinates.iter().all(|&(i, j)| j < grid[i].len()));
+    }
+
+    #[test]
+    fn test_ragged_coordinates_match_rectangular_ones() {
+        let grid = vec![vec![0; 4]; 3];
+
+        assert_eq!(grid.coordinates_2d(), grid.ragged_coordinates_2d());
+    }
+
+    #[test]
+    fn test_check_rectangular() {
+        let empty: Vec<Vec<u8>> = Vec::new();
+        assert_eq!(Ok((0, 0)), check_rectangular(&empty));
+
+        let error = check_rectangular(&[vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+        assert_eq!(RaggedGridError { row: 2, expected: 2, actual: 1 }, error);
+        assert!(!is_rectangular(&[vec![1, 2], vec![3, 4], vec![5]]));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::collections::{get_taxicab_neighbors_in_2d_grid, TAXICAB_DIRECTION_VECTORS_2D};
+
+/// Gets the distance of every cell from the start cell, moving horizontally
+/// or vertically through cells satisfying the predicate.
+///
+/// Unreachable cells have no distance. If the start cell is outside of the grid,
+/// like in an empty grid, no cell is reachable.
+pub fn bfs_distances<T, F>(grid: &[Vec<T>], start: (usize, usize), is_open: F) -> Vec<Vec<Option<usize>>>
+where F: Fn(&T) -> bool {
+    let mut distances: Vec<Vec<Option<usize>>> = grid.iter().map(|row| vec![None; row.len()]).collect();
+    match distances.get_mut(start.0).and_then(|row| row.get_mut(start.1)) {
+        Some(distance) => *distance = Some(0),
+        None => return distances,
+    }
+
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some(((i, j), distance)) = queue.pop_front() {
+        for (ni, nj) in get_taxicab_neighbors_in_2d_grid(grid, i, j) {
+            if distances[ni][nj].is_none() && is_open(&grid[ni][nj]) {
+                distances[ni][nj] = Some(distance + 1);
+                queue.push_back(((ni, nj), distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Counts the cells where a walk of exactly the specified number of steps
+/// from the start cell can end.
+///
+/// A cell at distance d can be reached in any number of steps d + 2k,
+/// going back and forth, so it counts when d <= steps with the same parity.
+///
+/// Nothing is reachable from a start cell outside of the grid.
+///
+/// ```
+/// use enontekio::collections::reachable_after_steps;
+///
+/// let grid: Vec<Vec<char>> = [
+///     "...",
+///     ".#.",
+///     "...",
+/// ].iter().map(|line| line.chars().collect()).collect();
+///
+/// // From the top left corner: the corner itself, and the three cells at distance 2
+/// assert_eq!(3, reachable_after_steps(&grid, (0, 0), 2, |&c| c != '#'));
+/// ```
+pub fn reachable_after_steps<T, F>(grid: &[Vec<T>], start: (usize, usize), steps: usize, is_open: F) -> usize
+where F: Fn(&T) -> bool {
+    bfs_distances(grid, start, is_open)
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+        .count()
+}
+
+/// Counts the cells where a walk of exactly the specified number of steps
+/// can end, for each number of steps, when the grid repeats infinitely.
+///
+/// The walk is simulated, with a single BFS up to the largest number of steps,
+/// so this is meant for the first values of a sequence to extrapolate,
+/// like a quadratic growth once the walk spans a few copies of the grid.
+///
+/// An empty grid has no cell to reach, so every count is 0.
+pub fn reachable_counts_tiled<T, F>(grid: &[Vec<T>], start: (usize, usize), steps: &[usize], is_open: F) -> Vec<usize>
+where F: Fn(&T) -> bool {
+    let max_steps = steps.iter().copied().max().unwrap_or(0);
+    let height = grid.len() as i64;
+    let width = grid.first().map_or(0, |row| row.len()) as i64;
+    if height == 0 || width == 0 {
+        return vec![0; steps.len()];
+    }
+
+    let start = (start.0 as i64, start.1 as i64);
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some(((i, j), distance)) = queue.pop_front() {
+        if distance == max_steps {
+            continue;
+        }
+
+        for (delta_i, delta_j) in TAXICAB_DIRECTION_VECTORS_2D {
+            let neighbor = (i + delta_i as i64, j + delta_j as i64);
+            let cell = &grid[neighbor.0.rem_euclid(height) as usize][neighbor.1.rem_euclid(width) as usize];
+
+            if !distances.contains_key(&neighbor) && is_open(cell) {
+                distances.insert(neighbor, distance + 1);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+
+    steps.iter()
+        .map(|&steps| distances.values()
+            .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+            .count())
+        .collect()
+}
+
+/// Counts the cells where a walk of exactly the specified number of steps
+/// can end, when the grid repeats infinitely.
+pub fn reachable_after_steps_tiled<T, F>(grid: &[Vec<T>], start: (usize, usize), steps: usize, is_open: F) -> usize
+where F: Fn(&T) -> bool {
+    reachable_counts_tiled(grid, start, &[steps], is_open)[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GARDEN: [&str; 11] = [
+        "...........",
+        ".....###.#.",
+        ".###.##..#.",
+        "..#.#...#..",
+        "....#.#....",
+        ".##..S####.",
+        ".##..#...#.",
+        ".......##..",
+        ".##.#.####.",
+        ".##..##.##.",
+        "...........",
+    ];
+
+    fn garden() -> Vec<Vec<char>> {
+        GARDEN.iter().map(|line| line.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_reachable_after_steps() {
+        assert_eq!(16, reachable_after_steps(&garden(), (5, 5), 6, |&c| c != '#'));
+    }
+
+    #[test]
+    fn test_reachable_counts_tiled() {
+        let counts = reachable_counts_tiled(&garden(), (5, 5), &[6, 10, 50, 100], |&c| c != '#');
+
+        assert_eq!(vec![16, 50, 1594, 6536], counts);
+        assert_eq!(16, reachable_after_steps_tiled(&garden(), (5, 5), 6, |&c| c != '#'));
+
+        let empty: Vec<Vec<char>> = vec![];
+        assert_eq!(vec![0, 0], reachable_counts_tiled(&empty, (0, 0), &[0, 5], |_| true));
+        assert_eq!(0, reachable_after_steps_tiled(&[vec![]], (0, 0), 5, |_: &char| true));
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let grid = vec![vec!['.', '#', '.'], vec!['.', '.', '.']];
+        let distances = bfs_distances(&grid, (0, 0), |&c| c != '#');
+
+        assert_eq!(Some(4), distances[0][2]);
+        assert_eq!(None, distances[0][1]);
+    }
+
+    #[test]
+    fn test_start_outside_of_the_grid() {
+        let empty: Vec<Vec<char>> = vec![];
+        assert!(bfs_distances(&empty, (0, 0), |_| true).is_empty());
+        assert_eq!(0, reachable_after_steps(&empty, (0, 0), 2, |_| true));
+
+        let grid = vec![vec!['.'; 2]; 2];
+        assert_eq!(vec![vec![None; 2]; 2], bfs_distances(&grid, (2, 0), |_| true));
+        assert_eq!(0, reachable_after_steps(&grid, (0, 5), 2, |_| true));
+    }
+}
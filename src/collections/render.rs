@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+
+use crate::collections::SparseGrid;
+
+/// Options to render a grid as text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderOptions {
+    /// Prints the row numbers on the left, and the last digit
+    /// of the column numbers on top
+    pub axis_labels: bool,
+}
+
+/// Renders a grid as text, one line per row, formatting each cell with a closure.
+///
+/// If every formatted cell is one character wide, cells are printed
+/// side by side, like a map. Otherwise, they're right aligned
+/// and separated by a space, like a matrix.
+///
+/// ```
+/// use enontekio::collections::{render_grid, RenderOptions};
+///
+/// let grid = vec![vec![true, false], vec![false, true]];
+/// let text = render_grid(&grid, &RenderOptions::default(), |&cell| if cell { '#' } else { '.' });
+/// assert_eq!("#.\n.#\n", text);
+///
+/// let grid = vec![vec![1, 20], vec![300, 4]];
+/// let text = render_grid(&grid, &RenderOptions::default(), |&cell| cell);
+/// assert_eq!("  1  20\n300   4\n", text);
+/// ```
+pub fn render_grid<T, D, F>(grid: &[Vec<T>], options: &RenderOptions, formatter: F) -> String
+where D: Display, F: Fn(&T) -> D {
+    let cells: Vec<Vec<String>> = grid.iter()
+        .map(|row| row.iter().map(|cell| formatter(cell).to_string()).collect())
+        .collect();
+
+    render_cells(&cells, (0, 0), options)
+}
+
+impl<T> SparseGrid<T> {
+    /// Renders the cells of the bounding box as text, one line per row,
+    /// formatting each cell with a closure, like render_grid.
+    ///
+    /// With axis labels, the actual coordinates of the cells are printed.
+    pub fn render<D, F>(&self, options: &RenderOptions, formatter: F) -> String
+    where D: Display, F: Fn(&T) -> D {
+        let ((min_i, min_j), (max_i, max_j)) = match self.bounding_box() {
+            None => return String::new(),
+            Some(bounding_box) => bounding_box,
+        };
+
+        let cells: Vec<Vec<String>> = (min_i..=max_i)
+            .map(|i| (min_j..=max_j).map(|j| formatter(self.get((i, j))).to_string()).collect())
+            .collect();
+
+        render_cells(&cells, (min_i, min_j), options)
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(&RenderOptions::default(), |cell| cell.to_string()))
+    }
+}
+
+fn render_cells(cells: &[Vec<String>], (first_i, first_j): (i64, i64), options: &RenderOptions) -> String {
+    let cell_width = cells.iter().flatten().map(|cell| cell.chars().count()).max().unwrap_or(0);
+    let separator = if cell_width > 1 { " " } else { "" };
+    let row_count = cells.len() as i64;
+    let column_count = cells.iter().map(|row| row.len()).max().unwrap_or(0) as i64;
+
+    let label_width = [first_i, first_i + row_count - 1]
+        .iter()
+        .map(|i| i.to_string().len())
+        .max()
+        .unwrap();
+
+    let mut text = String::new();
+
+    if options.axis_labels {
+        let labels: Vec<String> = (first_j..first_j + column_count)
+            .map(|j| format!("{:>width$}", j.rem_euclid(10), width = cell_width))
+            .collect();
+
+        text.push_str(&format!("{:width$} {}\n", "", labels.join(separator), width = label_width));
+    }
+
+    for (i, row) in cells.iter().enumerate() {
+        if options.axis_labels {
+            text.push_str(&format!("{:>width$} ", first_i + i as i64, width = label_width));
+        }
+
+        let row: Vec<String> = row.iter()
+            .map(|cell| format!("{:>width$}", cell, width = cell_width))
+            .collect();
+        text.push_str(&row.join(separator));
+        text.push('\n');
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_with_axis_labels() {
+        let grid: Vec<Vec<char>> = (0..11).map(|_| "#.#".chars().collect()).collect();
+        let options = RenderOptions { axis_labels: true };
+
+        let text = render_grid(&grid, &options, |&c| c);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!("   012", lines[0]);
+        assert_eq!(" 0 #.#", lines[1]);
+        assert_eq!("10 #.#", lines[11]);
+    }
+
+    #[test]
+    fn test_render_sparse_grid_crops_to_bounding_box() {
+        let mut grid = SparseGrid::new('.');
+        grid.set((-1, 5), '#');
+        grid.set((1, 7), '#');
+
+        assert_eq!("#..\n...\n..#\n", grid.to_string());
+
+        let text = grid.render(&RenderOptions { axis_labels: true }, |&c| c);
+        assert_eq!("   567\n-1 #..\n 0 ...\n 1 ..#\n", text);
+    }
+
+    #[test]
+    fn test_render_empty_grids() {
+        let grid: Vec<Vec<u8>> = Vec::new();
+
+        assert_eq!("", render_grid(&grid, &RenderOptions::default(), |&cell| cell));
+        assert_eq!("", SparseGrid::new(0).to_string());
+    }
+}
@@ -0,0 +1,286 @@
+use crate::collections::{flip_horizontally, rotate_90, ALL_DIRECTION_VECTORS_2D};
+
+/// Finds all the occurrences of a word in a grid of chars,
+/// horizontally, vertically or diagonally, forwards or backwards.
+///
+/// Returns the coordinates of the first letter of each occurrence,
+/// with the direction vector to follow to read the word.
+///
+/// A one-letter word reads the same in every direction,
+/// so each occurrence is reported once, with the first direction vector.
+///
+/// ```
+/// use enontekio::collections::search_word;
+///
+/// let grid = vec![
+///     "XMAS".chars().collect(),
+///     "MM..".chars().collect(),
+///     "A.A.".chars().collect(),
+///     "S..S".chars().collect(),
+/// ];
+///
+/// let occurrences = search_word(&grid, "XMAS");
+/// assert_eq!(vec![((0, 0), (1, 0)), ((0, 0), (0, 1)), ((0, 0), (1, 1))], occurrences);
+/// ```
+pub fn search_word(grid: &[Vec<char>], word: &str) -> Vec<((usize, usize), (i32, i32))> {
+    let letters: Vec<char> = word.chars().collect();
+    if letters.is_empty() {
+        return Vec::new();
+    }
+
+    // A one-letter word would match in all 8 directions
+    let directions = if letters.len() == 1 {
+        &ALL_DIRECTION_VECTORS_2D[..1]
+    } else {
+        &ALL_DIRECTION_VECTORS_2D[..]
+    };
+
+    let mut occurrences = Vec::new();
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &c) in row.iter().enumerate() {
+            if c != letters[0] {
+                continue;
+            }
+
+            for &direction in directions {
+                if matches_in_direction(grid, &letters, (i, j), direction) {
+                    occurrences.push(((i, j), direction));
+                }
+            }
+        }
+    }
+
+    occurrences
+}
+
+fn matches_in_direction(grid: &[Vec<char>], letters: &[char], (i, j): (usize, usize), (delta_i, delta_j): (i32, i32)) -> bool {
+    letters.iter().enumerate().all(|(k, &letter)| {
+        let cell = i.checked_add_signed(delta_i as isize * k as isize)
+            .zip(j.checked_add_signed(delta_j as isize * k as isize))
+            .and_then(|(ni, nj)| grid.get(ni)?.get(nj));
+
+        cell == Some(&letter)
+    })
+}
+
+/// Options to search a pattern in a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PatternOptions {
+    /// A pattern character matching any grid character, like ' ' or '?'
+    pub wildcard: Option<char>,
+
+    /// Searches the rotations and flips of the pattern too
+    pub all_orientations: bool,
+}
+
+/// A placement of a pattern in a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    /// The coordinates of the grid cell under the top left pattern cell
+    pub position: (usize, usize),
+
+    /// The index of the matching orientation in pattern_orientations,
+    /// 0 for the pattern as is
+    pub orientation: usize,
+}
+
+/// Gets the distinct rotations and flips of a pattern, starting with the pattern itself.
+///
+/// A pattern with symmetries has fewer than 8 orientations.
+///
+/// # Panics
+///
+/// Panics if the pattern rows have different lengths, as a ragged pattern
+/// can't be rotated: pad it first, like find_pattern does with the wildcard.
+pub fn pattern_orientations(pattern: &[Vec<char>]) -> Vec<Vec<Vec<char>>> {
+    assert!(
+        pattern.windows(2).all(|rows| rows[0].len() == rows[1].len()),
+        "a pattern must be rectangular to be rotated",
+    );
+
+    let mut orientations: Vec<Vec<Vec<char>>> = Vec::with_capacity(8);
+
+    let mut current = pattern.to_vec();
+    for _ in 0..2 {
+        for _ in 0..4 {
+            if !orientations.contains(&current) {
+                orientations.push(current.clone());
+            }
+            current = rotate_90(&current);
+        }
+        current = flip_horizontally(&current);
+    }
+
+    orientations
+}
+
+/// Finds all the placements of a pattern in a grid of chars.
+///
+/// Pattern rows can have different lengths, for example
+/// when trailing wildcards are trimmed. To search all the orientations,
+/// such a pattern is first padded to its widest row with the wildcard.
+///
+/// # Panics
+///
+/// Panics if all the orientations are searched for a ragged pattern
+/// without a wildcard to pad it.
+///
+/// ```
+/// use enontekio::collections::{find_pattern, PatternOptions};
+///
+/// let grid: Vec<Vec<char>> = vec!["#.#.".chars().collect(), ".#.#".chars().collect()];
+/// let pattern: Vec<Vec<char>> = vec!["#?".chars().collect(), "?#".chars().collect()];
+///
+/// let options = PatternOptions { wildcard: Some('?'), ..Default::default() };
+/// let positions: Vec<_> = find_pattern(&grid, &pattern, &options)
+///     .into_iter()
+///     .map(|found| found.position)
+///     .collect();
+///
+/// assert_eq!(vec![(0, 0), (0, 2)], positions);
+/// ```
+pub fn find_pattern(grid: &[Vec<char>], pattern: &[Vec<char>], options: &PatternOptions) -> Vec<PatternMatch> {
+    let orientations = if options.all_orientations {
+        match options.wildcard {
+            Some(wildcard) => pattern_orientations(&pad_rows(pattern, wildcard)),
+            None => pattern_orientations(pattern),
+        }
+    } else {
+        vec![pattern.to_vec()]
+    };
+
+    let mut matches = Vec::new();
+    for (orientation, pattern) in orientations.iter().enumerate() {
+        for i in 0..grid.len() {
+            for j in 0..grid[i].len() {
+                if pattern_matches_at(grid, pattern, (i, j), options.wildcard) {
+                    matches.push(PatternMatch {
+                        position: (i, j),
+                        orientation,
+                    });
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+fn pad_rows(pattern: &[Vec<char>], filler: char) -> Vec<Vec<char>> {
+    let width = pattern.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    pattern.iter()
+        .map(|row| {
+            let mut row = row.clone();
+            row.resize(width, filler);
+            row
+        })
+        .collect()
+}
+
+fn pattern_matches_at(grid: &[Vec<char>], pattern: &[Vec<char>], (i, j): (usize, usize), wildcard: Option<char>) -> bool {
+    pattern.iter().enumerate().all(|(pattern_i, row)| {
+        row.iter().enumerate().all(|(pattern_j, &expected)| {
+            let cell = grid.get(i + pattern_i).and_then(|grid_row| grid_row.get(j + pattern_j));
+
+            match cell {
+                None => false,
+                Some(_) if Some(expected) == wildcard => true,
+                Some(&c) => c == expected,
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(lines: &[&str]) -> Vec<Vec<char>> {
+        lines.iter().map(|line| line.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_search_word_count() {
+        let grid = parse(&[
+            "MMMSXXMASM",
+            "MSAMXMSMSA",
+            "AMXSXMAAMM",
+            "MSAMASMSMX",
+            "XMASAMXAMM",
+            "XXAMMXXAMA",
+            "SMSMSASXSS",
+            "SAXAMASAAA",
+            "MAMMMXMMMM",
+            "MXMXAXMASX",
+        ]);
+
+        assert_eq!(18, search_word(&grid, "XMAS").len());
+    }
+
+    #[test]
+    fn test_search_word_edge_cases() {
+        let grid = parse(&["AB", "C"]);
+
+        assert_eq!(vec![((0, 1), (0, -1))], search_word(&grid, "BA"));
+        assert_eq!(vec![((0, 0), (1, 0))], search_word(&grid, "A"));
+        assert!(search_word(&grid, "").is_empty());
+        assert_eq!(vec![((1, 0), (-1, 1))], search_word(&grid, "CB"));
+    }
+
+    #[test]
+    fn test_find_pattern_with_orientations() {
+        let monster = parse(&[
+            "                  # ",
+            "#    ##    ##    ###",
+            " #  #  #  #  #  #   ",
+        ]);
+
+        // The monster is drawn upside down
+        let grid = parse(&[
+            "..#..#..#..#..#..#...",
+            ".#....##....##....###",
+            "...................#.",
+            ".....................",
+        ]);
+
+        let plain = PatternOptions { wildcard: Some(' '), all_orientations: false };
+        assert!(find_pattern(&grid, &monster, &plain).is_empty());
+
+        let oriented = PatternOptions { wildcard: Some(' '), all_orientations: true };
+        let matches = find_pattern(&grid, &monster, &oriented);
+        assert_eq!(1, matches.len());
+        assert_eq!((0, 1), matches[0].position);
+    }
+
+    #[test]
+    fn test_pattern_orientations_are_distinct() {
+        assert_eq!(8, pattern_orientations(&parse(&["#.", "..", ".."])).len());
+        assert_eq!(1, pattern_orientations(&parse(&["##", "##"])).len());
+        assert_eq!(2, pattern_orientations(&parse(&["###"])).len());
+    }
+
+    #[test]
+    fn test_find_ragged_pattern_with_orientations() {
+        let grid = parse(&[
+            "....",
+            ".#..",
+            "##..",
+        ]);
+        let oriented = PatternOptions { wildcard: Some('?'), all_orientations: true };
+
+        // Each pattern is an L of 3 cells, the fourth one being trimmed
+        let positions = |pattern: &[&str]| -> Vec<(usize, usize)> {
+            find_pattern(&grid, &parse(pattern), &oriented).iter().map(|found| found.position).collect()
+        };
+        assert_eq!(vec![(1, 0)], positions(&["##", "#"]));
+        assert_eq!(vec![(1, 0)], positions(&["#", "##"]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pattern_orientations_reject_ragged_patterns() {
+        pattern_orientations(&parse(&["##", "#"]));
+    }
+}
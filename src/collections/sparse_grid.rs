@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+/// A 2D grid without bounds, storing only the cells set to a value.
+///
+/// Cells are located by (i, j) coordinates, like in dense grids:
+/// i is the row, j the column, but they can be negative.
+/// Any cell not set has the default value of the grid.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid, where every cell has the default value.
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Gets the value of a cell.
+    pub fn get(&self, coords: (i64, i64)) -> &T {
+        self.cells.get(&coords).unwrap_or(&self.default)
+    }
+
+    /// Sets the value of a cell.
+    pub fn set(&mut self, coords: (i64, i64), value: T) {
+        self.cells.insert(coords, value);
+    }
+
+    /// Resets a cell to the default value, returning the previous value if it was set.
+    pub fn remove(&mut self, coords: (i64, i64)) -> Option<T> {
+        self.cells.remove(&coords)
+    }
+
+    /// Checks if a cell has been set.
+    pub fn contains(&self, coords: (i64, i64)) -> bool {
+        self.cells.contains_key(&coords)
+    }
+
+    /// Gets the default value of the cells.
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Gets the number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterates over the cells set, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(&coords, value)| (coords, value))
+    }
+
+    /// Gets the smallest rectangle containing all the cells set,
+    /// as top left and bottom right corners, both inclusive.
+    pub fn bounding_box(&self) -> Option<((i64, i64), (i64, i64))> {
+        let mut coords = self.cells.keys();
+        let &first = coords.next()?;
+
+        Some(coords.fold((first, first), |((min_i, min_j), (max_i, max_j)), &(i, j)| {
+            ((min_i.min(i), min_j.min(j)), (max_i.max(i), max_j.max(j)))
+        }))
+    }
+
+    /// Gets the coordinates of the neighbors of a cell,
+    /// following the specified direction vectors.
+    ///
+    /// As the grid is unbounded, every neighbor is returned.
+    pub fn neighbors<D>(&self, (i, j): (i64, i64), directions: &[(D, D)]) -> Vec<(i64, i64)>
+    where D: Into<i64> + Copy {
+        directions
+            .iter()
+            .map(|&(delta_i, delta_j)| (i + delta_i.into(), j + delta_j.into()))
+            .collect()
+    }
+
+    /// Gets the values of the neighbors of a cell,
+    /// following the specified direction vectors.
+    pub fn neighbor_values<D>(&self, coords: (i64, i64), directions: &[(D, D)]) -> Vec<&T>
+    where D: Into<i64> + Copy {
+        self.neighbors(coords, directions)
+            .into_iter()
+            .map(|neighbor| self.get(neighbor))
+            .collect()
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Builds a sparse grid from a dense one, with (0, 0) as top left cell.
+    ///
+    /// Only the cells different from the default value are stored.
+    ///
+    /// ```
+    /// use enontekio::collections::SparseGrid;
+    ///
+    /// let grid = SparseGrid::from_dense(&[vec!['.', '#'], vec!['#', '.']], '.');
+    ///
+    /// assert_eq!(2, grid.len());
+    /// assert_eq!(&'#', grid.get((0, 1)));
+    /// assert_eq!(&'.', grid.get((-5, 12)));
+    /// ```
+    pub fn from_dense(grid: &[Vec<T>], default: T) -> Self {
+        let mut sparse = Self::new(default);
+
+        for (i, row) in grid.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                if *value != sparse.default {
+                    sparse.set((i as i64, j as i64), value.clone());
+                }
+            }
+        }
+
+        sparse
+    }
+
+    /// Builds a dense grid covering the bounding box.
+    ///
+    /// The top left cell of the dense grid is the top left corner
+    /// of the bounding box. The grid is empty if no cell is set.
+    pub fn to_dense(&self) -> Vec<Vec<T>> {
+        let ((min_i, min_j), (max_i, max_j)) = match self.bounding_box() {
+            None => return Vec::new(),
+            Some(bounding_box) => bounding_box,
+        };
+
+        (min_i..=max_i)
+            .map(|i| (min_j..=max_j).map(|j| self.get((i, j)).clone()).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::get_all_direction_vectors_2d;
+
+    #[test]
+    fn test_default_value_semantics() {
+        let mut grid = SparseGrid::new(0);
+        grid.set((-3, 7), 5);
+
+        assert_eq!(&5, grid.get((-3, 7)));
+        assert_eq!(&0, grid.get((1_000_000, -1_000_000)));
+
+        assert_eq!(Some(5), grid.remove((-3, 7)));
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(None, grid.bounding_box());
+
+        grid.set((2, -1), true);
+        grid.set((-4, 3), true);
+        grid.set((0, 0), true);
+
+        assert_eq!(Some(((-4, -1), (2, 3))), grid.bounding_box());
+    }
+
+    #[test]
+    fn test_neighbor_values() {
+        let grid = SparseGrid::from_dense(&[vec![1, 1], vec![1, 0]], 0);
+
+        let values = grid.neighbor_values((0, 0), &get_all_direction_vectors_2d());
+        assert_eq!(2, values.into_iter().sum::<i32>());
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let mut grid = SparseGrid::new('.');
+        grid.set((-1, -1), '#');
+        grid.set((0, 1), '#');
+
+        let dense = grid.to_dense();
+
+        assert_eq!(vec![vec!['#', '.', '.'], vec!['.', '.', '#']], dense);
+        assert_eq!(2, SparseGrid::from_dense(&dense, '.').len());
+    }
+}
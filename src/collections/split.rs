@@ -0,0 +1,92 @@
+/// Describes what to do with the elements splitting an iterator into chunks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeparatorPolicy {
+    /// The separators are dropped
+    Drop,
+
+    /// A separator ends the chunk it closes
+    KeepInPrevious,
+
+    /// A separator starts the next chunk
+    KeepInNext,
+}
+
+/// Groups the elements of an iterator into chunks, split at the elements
+/// matching the predicate. Empty chunks are omitted.
+///
+/// That's the in-memory analogue of parsing blocks separated by blank lines:
+///
+/// ```
+/// use enontekio::collections::{split_when, SeparatorPolicy};
+///
+/// let lines = vec!["1000", "2000", "", "4000", "", "5000", "6000"];
+/// let chunks = split_when(lines, |line| line.is_empty(), SeparatorPolicy::Drop);
+///
+/// assert_eq!(vec![vec!["1000", "2000"], vec!["4000"], vec!["5000", "6000"]], chunks);
+/// ```
+pub fn split_when<I, T, F>(iter: I, predicate: F, policy: SeparatorPolicy) -> Vec<Vec<T>>
+where I: IntoIterator<Item = T>, F: Fn(&T) -> bool {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+
+    for item in iter {
+        if !predicate(&item) {
+            chunk.push(item);
+            continue;
+        }
+
+        match policy {
+            SeparatorPolicy::Drop => {
+                push_chunk(&mut chunks, &mut chunk);
+            }
+            SeparatorPolicy::KeepInPrevious => {
+                chunk.push(item);
+                push_chunk(&mut chunks, &mut chunk);
+            }
+            SeparatorPolicy::KeepInNext => {
+                push_chunk(&mut chunks, &mut chunk);
+                chunk.push(item);
+            }
+        }
+    }
+
+    push_chunk(&mut chunks, &mut chunk);
+    chunks
+}
+
+fn push_chunk<T>(chunks: &mut Vec<Vec<T>>, chunk: &mut Vec<T>) {
+    if !chunk.is_empty() {
+        chunks.push(std::mem::take(chunk));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_when_drop() {
+        let chunks = split_when(vec![0, 1, 2, 0, 0, 3, 0], |&n| n == 0, SeparatorPolicy::Drop);
+
+        assert_eq!(vec![vec![1, 2], vec![3]], chunks);
+    }
+
+    #[test]
+    fn test_split_when_keep() {
+        let numbers = vec![1, 2, 0, 3, 0];
+
+        assert_eq!(
+            vec![vec![1, 2, 0], vec![3, 0]],
+            split_when(numbers.clone(), |&n| n == 0, SeparatorPolicy::KeepInPrevious)
+        );
+        assert_eq!(
+            vec![vec![1, 2], vec![0, 3], vec![0]],
+            split_when(numbers, |&n| n == 0, SeparatorPolicy::KeepInNext)
+        );
+    }
+
+    #[test]
+    fn test_split_when_empty() {
+        assert!(split_when(Vec::<u32>::new(), |_| true, SeparatorPolicy::Drop).is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Gets the k largest elements of an iterator, from the largest.
+///
+/// Only k elements are kept in a heap at any time,
+/// so it's O(n log k) instead of sorting the whole input.
+///
+/// ```
+/// use enontekio::collections::top_k;
+///
+/// let calories = vec![6000, 4000, 11000, 24000, 10000];
+/// assert_eq!(vec![24000, 11000, 10000], top_k(calories, 3));
+/// ```
+pub fn top_k<I, T>(iter: I, k: usize) -> Vec<T>
+where I: IntoIterator<Item = T>, T: Ord {
+    let iter = iter.into_iter();
+    let mut heap = BinaryHeap::with_capacity(heap_capacity(k, &iter));
+
+    for item in iter {
+        heap.push(Reverse(item));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    // Sorted ascending on Reverse, so descending on the items.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(item)| item)
+        .collect()
+}
+
+/// Gets the k smallest elements of an iterator, from the smallest.
+///
+/// Only k elements are kept in a heap at any time,
+/// so it's O(n log k) instead of sorting the whole input.
+///
+/// ```
+/// use enontekio::collections::bottom_k;
+///
+/// assert_eq!(vec![1, 2], bottom_k(vec![5, 1, 4, 2, 3], 2));
+/// ```
+pub fn bottom_k<I, T>(iter: I, k: usize) -> Vec<T>
+where I: IntoIterator<Item = T>, T: Ord {
+    let iter = iter.into_iter();
+    let mut heap = BinaryHeap::with_capacity(heap_capacity(k, &iter));
+
+    for item in iter {
+        heap.push(item);
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+}
+
+/// Gets the capacity of a heap keeping k elements, plus the one pushed
+/// before popping, without reserving more than the iterator items.
+fn heap_capacity<I: Iterator>(k: usize, iter: &I) -> usize {
+    k.saturating_add(1).min(iter.size_hint().0)
+}
+
+/// Gets the k elements of an iterator with the largest keys,
+/// from the largest, like top_k for items which aren't Ord.
+///
+/// Elements with the same key are kept in the iterator order.
+///
+/// ```
+/// use enontekio::collections::top_k_by_key;
+///
+/// let elves = vec![("Alabaster", 6000), ("Bushy", 24000), ("Pepper", 11000), ("Shinny", 24000)];
+/// assert_eq!(vec![("Bushy", 24000), ("Shinny", 24000)], top_k_by_key(elves, 2, |&(_, calories)| calories));
+/// ```
+pub fn top_k_by_key<I, T, K, F>(iter: I, k: usize, mut key: F) -> Vec<T>
+where I: IntoIterator<Item = T>, K: Ord, F: FnMut(&T) -> K {
+    let ranked = iter.into_iter()
+        .enumerate()
+        .map(|(index, item)| Ranked { rank: (key(&item), Reverse(index)), item });
+
+    top_k(ranked, k).into_iter().map(|ranked| ranked.item).collect()
+}
+
+/// Gets the k elements of an iterator with the smallest keys,
+/// from the smallest, like bottom_k for items which aren't Ord.
+///
+/// Elements with the same key are kept in the iterator order.
+pub fn bottom_k_by_key<I, T, K, F>(iter: I, k: usize, mut key: F) -> Vec<T>
+where I: IntoIterator<Item = T>, K: Ord, F: FnMut(&T) -> K {
+    let ranked = iter.into_iter()
+        .enumerate()
+        .map(|(index, item)| Ranked { rank: (key(&item), index), item });
+
+    bottom_k(ranked, k).into_iter().map(|ranked| ranked.item).collect()
+}
+
+/// An item ordered by its rank only
+struct Ranked<R, T> {
+    rank: R,
+    item: T,
+}
+
+impl<R: Ord, T> Ord for Ranked<R, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+impl<R: Ord, T> PartialOrd for Ranked<R, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R: Ord, T> PartialEq for Ranked<R, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank
+    }
+}
+
+impl<R: Ord, T> Eq for Ranked<R, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_k() {
+        assert_eq!(vec![9, 8, 8], top_k(vec![1, 8, 3, 9, 8, 2], 3));
+        assert_eq!(vec![2, 1], top_k(vec![1, 2], 5));
+        assert!(top_k(vec![1, 2], 0).is_empty());
+        assert_eq!(vec![2, 1, 0], top_k(0..3, usize::MAX));
+    }
+
+    #[test]
+    fn test_bottom_k() {
+        assert_eq!(vec![1, 2, 3], bottom_k(vec![1, 8, 3, 9, 8, 2], 3));
+        assert_eq!(vec!["a", "b"], bottom_k(["b", "a"], 5));
+        assert!(bottom_k(Vec::<u32>::new(), 2).is_empty());
+        assert_eq!(vec![0, 1, 2], bottom_k(0..3, usize::MAX));
+    }
+
+    #[test]
+    fn test_k_by_key() {
+        let words = vec!["kiwi", "fig", "banana", "plum", "apple"];
+
+        assert_eq!(vec!["banana", "apple", "kiwi"], top_k_by_key(words.clone(), 3, |word| word.len()));
+        assert_eq!(vec!["fig", "kiwi", "plum"], bottom_k_by_key(words.clone(), 3, |word| word.len()));
+        assert!(top_k_by_key(words, 0, |word| word.len()).is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+//! Transformations of 2D grids, like rotations, flips or cell by cell maps.
+//!
+//! Those functions assume the grid argument to be a grid,
+//! ie each line is expected to have the same length.
+
+/// Transposes a grid, so rows become columns: the cell (i, j) moves to (j, i).
+///
+/// ```
+/// use enontekio::collections::transpose;
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], transpose(&grid));
+/// ```
+pub fn transpose<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    if grid.is_empty() {
+        return Vec::new();
+    }
+
+    (0..grid[0].len())
+        .map(|j| grid.iter().map(|row| row[j].clone()).collect())
+        .collect()
+}
+
+/// Rotates a grid by 90 degrees clockwise.
+///
+/// ```
+/// use enontekio::collections::rotate_90;
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(vec![vec![4, 1], vec![5, 2], vec![6, 3]], rotate_90(&grid));
+/// ```
+pub fn rotate_90<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    flip_horizontally(&transpose(grid))
+}
+
+/// Rotates a grid by 180 degrees.
+pub fn rotate_180<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    grid.iter()
+        .rev()
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect()
+}
+
+/// Rotates a grid by 270 degrees clockwise, ie 90 degrees counterclockwise.
+pub fn rotate_270<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    flip_vertically(&transpose(grid))
+}
+
+/// Flips a grid horizontally, mirroring it left to right: each row is reversed.
+pub fn flip_horizontally<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    grid.iter()
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect()
+}
+
+/// Flips a grid vertically, mirroring it top to bottom: the rows order is reversed.
+pub fn flip_vertically<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    grid.iter()
+        .rev()
+        .cloned()
+        .collect()
+}
+
+/// Builds a new grid by applying a function to each cell,
+/// receiving the cell coordinates and value.
+///
+/// ```
+/// use enontekio::collections::map_grid;
+///
+/// let grid = vec![vec![1, 2], vec![3, 4]];
+/// let weighted = map_grid(&grid, |(i, j), &value| value * (i + j));
+///
+/// assert_eq!(vec![vec![0, 2], vec![3, 8]], weighted);
+/// ```
+pub fn map_grid<T, U, F>(grid: &[Vec<T>], mut f: F) -> Vec<Vec<U>>
+where F: FnMut((usize, usize), &T) -> U {
+    grid.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, value)| f((i, j), value))
+                .collect()
+        })
+        .collect()
+}
+
+/// Calls a function on each cell of a grid, in row-major order,
+/// receiving the cell coordinates and a mutable reference to the value.
+///
+/// ```
+/// use enontekio::collections::for_each_cell_mut;
+///
+/// let mut grid = vec![vec![0; 3]; 2];
+/// for_each_cell_mut(&mut grid, |(i, j), value| *value = 10 * i + j);
+///
+/// assert_eq!(vec![vec![0, 1, 2], vec![10, 11, 12]], grid);
+/// ```
+pub fn for_each_cell_mut<T, F>(grid: &mut [Vec<T>], mut f: F)
+where F: FnMut((usize, usize), &mut T) {
+    for (i, row) in grid.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            f((i, j), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> Vec<Vec<u32>> {
+        vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+        ]
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], transpose(&get_grid()));
+        assert_eq!(get_grid(), transpose(&transpose(&get_grid())));
+        assert!(transpose::<u32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_rotations() {
+        let grid = get_grid();
+
+        assert_eq!(vec![vec![4, 1], vec![5, 2], vec![6, 3]], rotate_90(&grid));
+        assert_eq!(vec![vec![6, 5, 4], vec![3, 2, 1]], rotate_180(&grid));
+        assert_eq!(vec![vec![3, 6], vec![2, 5], vec![1, 4]], rotate_270(&grid));
+
+        assert_eq!(rotate_180(&grid), rotate_90(&rotate_90(&grid)));
+        assert_eq!(grid, rotate_270(&rotate_90(&grid)));
+    }
+
+    #[test]
+    fn test_map_grid() {
+        let labels = map_grid(&get_grid(), |(i, j), value| format!("{}{}:{}", i, j, value));
+
+        assert_eq!(vec!["00:1", "01:2", "02:3"], labels[0]);
+        assert_eq!(vec!["10:4", "11:5", "12:6"], labels[1]);
+    }
+
+    #[test]
+    fn test_for_each_cell_mut() {
+        let mut grid = get_grid();
+        let mut visited = Vec::new();
+
+        for_each_cell_mut(&mut grid, |coords, value| {
+            visited.push(coords);
+            *value *= 2;
+        });
+
+        assert_eq!(vec![vec![2, 4, 6], vec![8, 10, 12]], grid);
+        assert_eq!(vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)], visited);
+    }
+
+    #[test]
+    fn test_flips() {
+        let grid = get_grid();
+
+        assert_eq!(vec![vec![3, 2, 1], vec![6, 5, 4]], flip_horizontally(&grid));
+        assert_eq!(vec![vec![4, 5, 6], vec![1, 2, 3]], flip_vertically(&grid));
+        assert_eq!(rotate_180(&grid), flip_vertically(&flip_horizontally(&grid)));
+    }
+}
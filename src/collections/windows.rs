@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+
+/// Iterator adapter calling a function on each window of n consecutive items.
+///
+/// See window_map.
+pub struct WindowMap<I: Iterator, F> {
+    iter: I,
+    window: VecDeque<I::Item>,
+    size: usize,
+    f: F,
+}
+
+impl<I, F, U> Iterator for WindowMap<I, F>
+where I: Iterator, F: FnMut(&[I::Item]) -> U {
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some((self.f)(self.window.make_contiguous()))
+    }
+}
+
+/// Calls a function on each window of n consecutive items of an iterator,
+/// like slice::windows, but without collecting the iterator first.
+///
+/// Only the current window is kept in memory.
+/// If the iterator has fewer than n items, there is no window.
+///
+/// ```
+/// use enontekio::collections::window_map;
+///
+/// let spreads: Vec<i32> = window_map([3, 8, 1, 6].into_iter(), 2, |window| window[1] - window[0]).collect();
+/// assert_eq!(vec![5, -7, 5], spreads);
+/// ```
+///
+/// # Panics
+///
+/// Panics if n is 0.
+pub fn window_map<I, F, U>(iter: I, n: usize, f: F) -> WindowMap<I::IntoIter, F>
+where I: IntoIterator, F: FnMut(&[I::Item]) -> U {
+    assert!(n > 0, "window size must be positive");
+
+    WindowMap {
+        iter: iter.into_iter(),
+        window: VecDeque::with_capacity(n),
+        size: n,
+        f,
+    }
+}
+
+/// Gets the sum of each window of n consecutive items of an iterator.
+///
+/// The sum is updated as the window slides, adding the new item
+/// and subtracting the item leaving the window.
+///
+/// ```
+/// use enontekio::collections::windows_sum;
+///
+/// let sums: Vec<i32> = windows_sum([199, 200, 208, 210, 200], 3).collect();
+/// assert_eq!(vec![607, 618, 618], sums);
+/// ```
+///
+/// # Panics
+///
+/// Panics if n is 0.
+pub fn windows_sum<I, T>(iter: I, n: usize) -> impl Iterator<Item = T>
+where I: IntoIterator<Item = T>, T: Copy + Default + Add<Output = T> + Sub<Output = T> {
+    let mut sum = None;
+
+    window_map(iter, n, move |window: &[T]| {
+        let current = match sum {
+            None => window.iter().fold(T::default(), |sum, &item| sum + item),
+            Some((previous, left)) => previous - left + window[n - 1],
+        };
+        sum = Some((current, window[0]));
+
+        current
+    })
+}
+
+/// Counts how many times the sum of a window of n consecutive items
+/// is larger than the sum of the previous window.
+///
+/// Both windows share n - 1 items, so the sums only differ by the item
+/// entering the window and the item leaving it. With n = 1, this counts
+/// how many times an item is larger than the previous one.
+///
+/// ```
+/// use enontekio::collections::count_increasing_windows;
+///
+/// let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+///
+/// assert_eq!(7, count_increasing_windows(depths, 1));
+/// assert_eq!(5, count_increasing_windows(depths, 3));
+/// ```
+///
+/// # Panics
+///
+/// Panics if n is 0.
+pub fn count_increasing_windows<I, T>(iter: I, n: usize) -> usize
+where I: IntoIterator<Item = T>, T: PartialOrd {
+    assert!(n > 0, "window size must be positive");
+
+    window_map(iter, n + 1, |window| window[n] > window[0])
+        .filter(|&is_increasing| is_increasing)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_map_matches_slice_windows() {
+        let items = [1, 4, 9, 16, 25];
+
+        for n in 1..7 {
+            let expected: Vec<Vec<i32>> = items.windows(n).map(|window| window.to_vec()).collect();
+            let actual: Vec<Vec<i32>> = window_map(items.iter().copied(), n, |window| window.to_vec()).collect();
+
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_windows_sum() {
+        assert_eq!(vec![1.5, 2.5], windows_sum(vec![0.5, 1.0, 1.5], 2).collect::<Vec<_>>());
+        assert_eq!(0, windows_sum(Vec::<u8>::new(), 2).count());
+        assert_eq!(vec![6u8], windows_sum(vec![1, 2, 3], 3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_count_increasing_windows_short_input() {
+        assert_eq!(0, count_increasing_windows([1, 2, 3], 3));
+        assert_eq!(1, count_increasing_windows([1, 2, 3, 4], 3));
+    }
+}
@@ -0,0 +1,64 @@
+//! Marble game where elves place marbles in a circle,
+//! scoring each time a marble number is a multiple of 23.
+
+use std::collections::VecDeque;
+
+/// Plays the marble game and returns the highest score.
+///
+/// Marbles are placed clockwise between the marbles 1 and 2 positions after the current one.
+/// When a marble is a multiple of 23, the player keeps it instead, and also takes
+/// the marble 7 positions counter-clockwise; the one after it becomes current.
+///
+/// The circle is a VecDeque whose back is the current marble: moving around
+/// the circle is a rotation, so each turn is O(1) amortized instead of the
+/// O(n) insertions and removals of a Vec, allowing millions of marbles.
+///
+/// ```
+/// use enontekio::games::marbles::marble_high_score;
+///
+/// assert_eq!(32, marble_high_score(9, 25));
+/// ```
+pub fn marble_high_score(players: usize, last_marble: u64) -> u64 {
+    if players == 0 {
+        return 0;
+    }
+
+    let mut scores = vec![0; players];
+    let mut circle = VecDeque::with_capacity(last_marble as usize + 1);
+    circle.push_back(0);
+
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            circle.rotate_right(7);
+            let removed = circle.pop_back().unwrap_or(0);
+            scores[(marble as usize - 1) % players] += marble + removed;
+            circle.rotate_left(1);
+        } else {
+            circle.rotate_left(1);
+            circle.push_back(marble);
+        }
+    }
+
+    scores.into_iter().max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marble_high_score() {
+        assert_eq!(32, marble_high_score(9, 25));
+        assert_eq!(8317, marble_high_score(10, 1618));
+        assert_eq!(146373, marble_high_score(13, 7999));
+        assert_eq!(2764, marble_high_score(17, 1104));
+        assert_eq!(54718, marble_high_score(21, 6111));
+        assert_eq!(37305, marble_high_score(30, 5807));
+    }
+
+    #[test]
+    fn test_marble_high_score_without_scoring() {
+        assert_eq!(0, marble_high_score(5, 22));
+        assert_eq!(0, marble_high_score(0, 100));
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::geometry::{Coordinate, Point2, Vec2};
+
+/// Gets the antinodes of every pair of points sharing a label,
+/// like antennas of the same frequency, within inclusive bounds.
+///
+/// For a pair a, b, the antinodes are the reflections of each point
+/// through the other one: a + (a - b) and b + (b - a).
+///
+/// With harmonics, the antinodes are every point on the line at
+/// a multiple of the pair distance, both points included:
+/// a + k (a - b) and b + k (b - a) for every k ≥ 0.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use enontekio::geometry::{antinodes, Point2};
+///
+/// let antennas = HashMap::from([('a', vec![Point2::new(4, 3), Point2::new(5, 5)])]);
+/// let bounds = (Point2::new(0, 0), Point2::new(9, 9));
+///
+/// let points = antinodes(&antennas, bounds, false);
+/// assert_eq!(2, points.len());
+/// assert!(points.contains(&Point2::new(3, 1)));
+/// assert!(points.contains(&Point2::new(6, 7)));
+///
+/// assert_eq!(5, antinodes(&antennas, bounds, true).len());
+/// ```
+pub fn antinodes<L, T>(
+    points_by_label: &HashMap<L, Vec<Point2<T>>>,
+    (min, max): (Point2<T>, Point2<T>),
+    harmonic: bool,
+) -> HashSet<Point2<T>>
+where T: Coordinate + Eq + Hash {
+    let is_within = |point: &Point2<T>| min.x <= point.x && point.x <= max.x && min.y <= point.y && point.y <= max.y;
+    let mut antinodes = HashSet::new();
+
+    for points in points_by_label.values() {
+        for (k, &a) in points.iter().enumerate() {
+            for &b in &points[k + 1..] {
+                if a == b {
+                    continue;
+                }
+
+                for (from, step) in [(a, a - b), (b, b - a)] {
+                    if harmonic {
+                        antinodes.extend(extend_line(from, step).take_while(is_within));
+                    } else if is_within(&(from + step)) {
+                        antinodes.insert(from + step);
+                    }
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+/// Iterates over from, from + step, from + 2 step, etc.
+fn extend_line<T: Coordinate>(from: Point2<T>, step: Vec2<T>) -> impl Iterator<Item = Point2<T>> {
+    std::iter::successors(Some(from), move |&point| Some(point + step))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn antennas() -> HashMap<char, Vec<Point2<i32>>> {
+        HashMap::from([
+            ('0', vec![Point2::new(8, 1), Point2::new(5, 2), Point2::new(7, 3), Point2::new(4, 4)]),
+            ('A', vec![Point2::new(6, 5), Point2::new(8, 8), Point2::new(9, 9)]),
+        ])
+    }
+
+    #[test]
+    fn test_antinodes() {
+        let bounds = (Point2::new(0, 0), Point2::new(11, 11));
+
+        assert_eq!(14, antinodes(&antennas(), bounds, false).len());
+        assert_eq!(34, antinodes(&antennas(), bounds, true).len());
+    }
+
+    #[test]
+    fn test_duplicate_points_and_single_points() {
+        let points = HashMap::from([(1, vec![Point2::new(2, 2), Point2::new(2, 2)]), (2, vec![Point2::new(0, 0)])]);
+        let bounds = (Point2::new(0, 0), Point2::new(4, 4));
+
+        assert!(antinodes(&points, bounds, true).is_empty());
+    }
+}
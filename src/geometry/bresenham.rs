@@ -0,0 +1,114 @@
+use crate::geometry::Point2;
+
+/// Iterator over the points of a line rasterized by the Bresenham algorithm
+#[derive(Debug, Clone)]
+pub struct Bresenham {
+    current: Point2<i64>,
+    end: Point2<i64>,
+    dx: i64,
+    dy: i64,
+    step_x: i64,
+    step_y: i64,
+    error: i64,
+    done: bool,
+}
+
+/// Gets the points of a line from p1 to p2, both included, for any slope.
+///
+/// For horizontal, vertical and 45° lines, the points are the same
+/// as ops::ranges::line_points.
+///
+/// ```
+/// use enontekio::geometry::{bresenham, Point2};
+///
+/// let points: Vec<_> = bresenham((0, 0), (5, 2)).collect();
+///
+/// assert_eq!(vec![
+///     Point2::new(0, 0),
+///     Point2::new(1, 0),
+///     Point2::new(2, 1),
+///     Point2::new(3, 1),
+///     Point2::new(4, 2),
+///     Point2::new(5, 2),
+/// ], points);
+/// ```
+pub fn bresenham<P: Into<Point2<i64>>>(p1: P, p2: P) -> Bresenham {
+    let start = p1.into();
+    let end = p2.into();
+
+    let dx = (end.x - start.x).abs();
+    let dy = -(end.y - start.y).abs();
+
+    Bresenham {
+        current: start,
+        end,
+        dx,
+        dy,
+        step_x: (end.x - start.x).signum(),
+        step_y: (end.y - start.y).signum(),
+        error: dx + dy,
+        done: false,
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Point2<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let point = self.current;
+        if point == self.end {
+            self.done = true;
+            return Some(point);
+        }
+
+        let doubled_error = 2 * self.error;
+        if doubled_error >= self.dy {
+            self.error += self.dy;
+            self.current.x += self.step_x;
+        }
+        if doubled_error <= self.dx {
+            self.error += self.dx;
+            self.current.y += self.step_y;
+        }
+
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::ranges::line_points;
+
+    fn as_tuples(p1: (i64, i64), p2: (i64, i64)) -> Vec<(i64, i64)> {
+        bresenham(p1, p2).map(|point| point.into()).collect()
+    }
+
+    #[test]
+    fn test_bresenham_matches_line_points() {
+        for (p1, p2) in [((0, 9), (5, 9)), ((9, 4), (3, 4)), ((2, 2), (2, 1)), ((8, 0), (0, 8)), ((3, 3), (3, 3))] {
+            assert_eq!(line_points(p1, p2).unwrap(), as_tuples(p1, p2));
+        }
+    }
+
+    #[test]
+    fn test_bresenham_steep_line() {
+        assert_eq!(vec![(0, 0), (0, 1), (1, 2), (1, 3)], as_tuples((0, 0), (1, 3)));
+    }
+
+    #[test]
+    fn test_bresenham_is_connected() {
+        let points = as_tuples((-7, 3), (12, -20));
+
+        assert_eq!((-7, 3), points[0]);
+        assert_eq!((12, -20), *points.last().unwrap());
+        assert_eq!(24, points.len());
+        assert!(points.windows(2).all(|pair| {
+            (pair[0].0 - pair[1].0).abs() <= 1 && (pair[0].1 - pair[1].1).abs() <= 1
+        }));
+    }
+}
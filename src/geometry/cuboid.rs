@@ -0,0 +1,122 @@
+//! Axis-aligned boxes in a 3D integer space.
+
+use std::ops::RangeInclusive;
+
+use num_integer::Integer;
+
+use crate::ops::nd_range::NdRange;
+
+/// An axis-aligned box, described by an inclusive range on each axis
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub x: RangeInclusive<T>,
+    pub y: RangeInclusive<T>,
+    pub z: RangeInclusive<T>,
+}
+
+impl<T: Integer + Copy> Cuboid<T> {
+    pub fn new(x: RangeInclusive<T>, y: RangeInclusive<T>, z: RangeInclusive<T>) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Checks if the cuboid contains no point, ie if a range is reversed.
+    pub fn is_empty(&self) -> bool {
+        self.to_nd_range().is_empty()
+    }
+
+    /// Gets the number of integer points in the cuboid.
+    ///
+    /// ```
+    /// use enontekio::geometry::cuboid::Cuboid;
+    ///
+    /// let cuboid = Cuboid::new(10..=12, 10..=12, 10..=12);
+    /// assert_eq!(27, cuboid.volume());
+    /// ```
+    pub fn volume(&self) -> T {
+        self.to_nd_range().volume()
+    }
+
+    /// Checks if the cuboid contains the specified point.
+    pub fn contains(&self, (x, y, z): (T, T, T)) -> bool {
+        self.to_nd_range().contains([x, y, z])
+    }
+
+    /// Gets the cuboid shared with another one, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.to_nd_range().intersection(&other.to_nd_range()).map(Self::from)
+    }
+
+    /// Removes another cuboid from this one.
+    ///
+    /// Returns up to 6 disjoint cuboids covering the remaining points:
+    /// the slabs along x, then along y within the common x range,
+    /// then along z within the common x and y ranges.
+    ///
+    /// ```
+    /// use enontekio::geometry::cuboid::Cuboid;
+    ///
+    /// let cuboid = Cuboid::new(10..=12, 10..=12, 10..=12);
+    /// let hole = Cuboid::new(11..=11, 11..=11, 11..=11);
+    ///
+    /// let remaining: i64 = cuboid.subtract(&hole).iter().map(|piece| piece.volume()).sum();
+    /// assert_eq!(26, remaining);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        self.to_nd_range()
+            .subtract(&other.to_nd_range())
+            .into_iter()
+            .map(Self::from)
+            .collect()
+    }
+
+    /// Gets the cuboid as a 3D range, which implements the operations.
+    pub fn to_nd_range(&self) -> NdRange<T, 3> {
+        NdRange::from(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersection() {
+        let a = Cuboid::new(0..=5, 0..=5, 0..=5);
+        let b = Cuboid::new(3..=8, -2..=1, 5..=9);
+
+        assert_eq!(Some(Cuboid::new(3..=5, 0..=1, 5..=5)), a.intersection(&b));
+        assert_eq!(None, a.intersection(&Cuboid::new(6..=8, 0..=5, 0..=5)));
+    }
+
+    #[test]
+    fn test_subtract_keeps_disjoint_pieces() {
+        let a = Cuboid::new(0..=9, 0..=9, 0..=9);
+        let b = Cuboid::new(5..=14, -3..=2, 4..=4);
+
+        let pieces = a.subtract(&b);
+
+        assert_eq!(1000 - 5 * 3, pieces.iter().map(|piece| piece.volume()).sum::<i32>());
+        for (i, piece) in pieces.iter().enumerate() {
+            assert_eq!(None, piece.intersection(&b));
+            assert!(pieces[i + 1..].iter().all(|other| piece.intersection(other).is_none()));
+        }
+    }
+
+    #[test]
+    fn test_subtract_edge_cases() {
+        let a: Cuboid<u32> = Cuboid::new(0..=1, 0..=1, 0..=1);
+
+        assert_eq!(vec![a.clone()], a.subtract(&Cuboid::new(5..=6, 5..=6, 5..=6)));
+        assert!(a.subtract(&Cuboid::new(0..=2, 0..=2, 0..=1)).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_empty_cuboid() {
+        let empty = Cuboid::new(3..=2, 0..=5, 0..=5);
+
+        assert!(empty.is_empty());
+        assert_eq!(0, empty.volume());
+        assert!(!empty.contains((2, 0, 0)));
+    }
+}
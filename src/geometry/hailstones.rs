@@ -0,0 +1,321 @@
+//! Hailstones moving in straight lines at constant velocity.
+//!
+//! All computations are exact, using i128 integers and rationals
+//! compared by cross-multiplication, as positions can be huge.
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::math::euclid::gcd;
+
+type Vector3 = [i128; 3];
+
+/// A hailstone, at a position at time 0, moving at a constant velocity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hailstone {
+    pub position: Vector3,
+    pub velocity: Vector3,
+}
+
+impl Hailstone {
+    pub fn new(position: Vector3, velocity: Vector3) -> Self {
+        Self { position, velocity }
+    }
+
+    /// Gets the position at the specified time.
+    pub fn position_at(&self, time: i128) -> Vector3 {
+        add(self.position, scale(self.velocity, time))
+    }
+}
+
+impl FromStr for Hailstone {
+    type Err = String;
+
+    /// Parses a hailstone like "19, 13, 30 @ -2, 1, -2".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s.split_once('@')
+            .ok_or_else(|| format!("expected position @ velocity, got {}", s))?;
+
+        Ok(Self::new(parse_vector(position)?, parse_vector(velocity)?))
+    }
+}
+
+fn parse_vector(expression: &str) -> Result<Vector3, String> {
+    let components = expression
+        .split(',')
+        .map(|component| component.trim().parse::<i128>().map_err(|error| error.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    components.try_into()
+        .map_err(|_| format!("expected 3 components, got {}", expression.trim()))
+}
+
+/// Counts the pairs of hailstones whose paths cross in the future,
+/// ignoring the z axis, within the window on both x and y axes.
+///
+/// Paths can cross at different times for each hailstone;
+/// parallel paths are considered as never crossing.
+///
+/// ```
+/// use enontekio::geometry::hailstones::{count_future_xy_intersections, Hailstone};
+///
+/// let stones: Vec<Hailstone> = [
+///     "19, 13, 30 @ -2, 1, -2",
+///     "18, 19, 22 @ -1, -1, -2",
+///     "20, 25, 34 @ -2, -2, -4",
+///     "12, 31, 28 @ -1, -2, -1",
+///     "20, 19, 15 @ 1, -5, -3",
+/// ].iter().map(|line| line.parse().unwrap()).collect();
+///
+/// assert_eq!(2, count_future_xy_intersections(&stones, 7..=27));
+/// ```
+pub fn count_future_xy_intersections(stones: &[Hailstone], window: RangeInclusive<i128>) -> usize {
+    let mut count = 0;
+
+    for (i, a) in stones.iter().enumerate() {
+        for b in &stones[i + 1..] {
+            if paths_cross_in_xy_window(a, b, *window.start(), *window.end()) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn paths_cross_in_xy_window(a: &Hailstone, b: &Hailstone, min: i128, max: i128) -> bool {
+    let [ax, ay, _] = a.position;
+    let [avx, avy, _] = a.velocity;
+    let [bvx, bvy, _] = b.velocity;
+    let dx = b.position[0] - ax;
+    let dy = b.position[1] - ay;
+
+    // a.position + t * a.velocity = b.position + s * b.velocity
+    // t = (d x bv) / (av x bv), s = (d x av) / (av x bv)
+    let mut denominator = avx * bvy - avy * bvx;
+    if denominator == 0 {
+        return false;
+    }
+
+    let mut t = dx * bvy - dy * bvx;
+    let mut s = dx * avy - dy * avx;
+    if denominator < 0 {
+        denominator = -denominator;
+        t = -t;
+        s = -s;
+    }
+
+    if t < 0 || s < 0 {
+        return false;
+    }
+
+    // x = ax + t / denominator * avx, compared to the window scaled by denominator
+    let x = ax * denominator + t * avx;
+    let y = ay * denominator + t * avy;
+    let range = min * denominator..=max * denominator;
+
+    range.contains(&x) && range.contains(&y)
+}
+
+/// Finds the rock throw, ie the position and velocity at time 0,
+/// hitting every hailstone, each at an integer time.
+///
+/// The problem is solved in the reference frame of the first hailstone:
+/// there, the rock goes through the origin, so its path is in the plane
+/// containing the origin and the path of each other hailstone.
+/// The intersection of two such planes gives the direction of the rock.
+///
+/// Returns None if there are fewer than 3 hailstones, or no such rock.
+///
+/// ```
+/// use enontekio::geometry::hailstones::{solve_rock_throw, Hailstone};
+///
+/// let stones: Vec<Hailstone> = [
+///     "19, 13, 30 @ -2, 1, -2",
+///     "18, 19, 22 @ -1, -1, -2",
+///     "20, 25, 34 @ -2, -2, -4",
+///     "12, 31, 28 @ -1, -2, -1",
+///     "20, 19, 15 @ 1, -5, -3",
+/// ].iter().map(|line| line.parse().unwrap()).collect();
+///
+/// let rock = solve_rock_throw(&stones).unwrap();
+/// assert_eq!([24, 13, 10], rock.position);
+/// assert_eq!([-3, 1, 2], rock.velocity);
+/// ```
+pub fn solve_rock_throw(stones: &[Hailstone]) -> Option<Hailstone> {
+    let (origin, others) = stones.split_first()?;
+
+    let relative: Vec<Hailstone> = others.iter()
+        .map(|stone| Hailstone::new(
+            sub(stone.position, origin.position),
+            sub(stone.velocity, origin.velocity),
+        ))
+        .collect();
+
+    for (i, a) in relative.iter().enumerate() {
+        for b in &relative[i + 1..] {
+            let rock = solve_relative_rock_throw(a, b).map(|rock| Hailstone::new(
+                add(rock.position, origin.position),
+                add(rock.velocity, origin.velocity),
+            ));
+
+            if let Some(rock) = rock {
+                if stones.iter().all(|stone| hits(&rock, stone)) {
+                    return Some(rock);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Solves the rock throw going through the origin and hitting a and b.
+fn solve_relative_rock_throw(a: &Hailstone, b: &Hailstone) -> Option<Hailstone> {
+    let direction = reduce(cross(
+        cross(a.position, a.velocity),
+        cross(b.position, b.velocity),
+    ))?;
+
+    let time_a = hit_time(a, direction)?;
+    let time_b = hit_time(b, direction)?;
+    if time_a == time_b {
+        return None;
+    }
+
+    let hit_a = a.position_at(time_a);
+    let hit_b = b.position_at(time_b);
+    let velocity = divide(sub(hit_b, hit_a), time_b - time_a)?;
+    let position = sub(hit_a, scale(velocity, time_a));
+
+    Some(Hailstone::new(position, velocity))
+}
+
+/// Gets the time when a stone crosses the line through the origin
+/// with the specified direction, if it's an integer.
+///
+/// (p + t v) x d = 0, so t = -(p x d) / (v x d) on any axis.
+fn hit_time(stone: &Hailstone, direction: Vector3) -> Option<i128> {
+    let numerator = cross(stone.position, direction);
+    let denominator = cross(stone.velocity, direction);
+
+    let axis = (0..3).find(|&axis| denominator[axis] != 0)?;
+    if numerator[axis] % denominator[axis] != 0 {
+        return None;
+    }
+
+    Some(-numerator[axis] / denominator[axis])
+}
+
+fn hits(rock: &Hailstone, stone: &Hailstone) -> bool {
+    let offset = sub(stone.position, rock.position);
+    let closing_speed = sub(rock.velocity, stone.velocity);
+
+    // The offset must be covered at closing speed, at the same time on every axis
+    if cross(offset, closing_speed) != [0; 3] {
+        return false;
+    }
+
+    match (0..3).find(|&axis| closing_speed[axis] != 0) {
+        None => offset == [0; 3],
+        Some(axis) => offset[axis] % closing_speed[axis] == 0,
+    }
+}
+
+fn add(a: Vector3, b: Vector3) -> Vector3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vector3, b: Vector3) -> Vector3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: Vector3, factor: i128) -> Vector3 {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+fn divide(a: Vector3, divisor: i128) -> Option<Vector3> {
+    if a.iter().any(|component| component % divisor != 0) {
+        return None;
+    }
+
+    Some([a[0] / divisor, a[1] / divisor, a[2] / divisor])
+}
+
+fn cross(a: Vector3, b: Vector3) -> Vector3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Divides a vector by the gcd of its components, or None for the zero vector.
+fn reduce(a: Vector3) -> Option<Vector3> {
+    let divisor = a.iter().fold(0, |divisor, &component| gcd(divisor, component));
+    if divisor == 0 {
+        return None;
+    }
+
+    divide(a, divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stones(rock: Hailstone, hits: &[(i128, Vector3)]) -> Vec<Hailstone> {
+        hits.iter()
+            .map(|&(time, velocity)| {
+                let hit = rock.position_at(time);
+                Hailstone::new(sub(hit, scale(velocity, time)), velocity)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_hailstone() {
+        let stone: Hailstone = "19, 13, 30 @ -2,  1, -2".parse().unwrap();
+
+        assert_eq!(Hailstone::new([19, 13, 30], [-2, 1, -2]), stone);
+        assert_eq!([17, 14, 28], stone.position_at(1));
+        assert!("19, 13 @ -2, 1".parse::<Hailstone>().is_err());
+    }
+
+    #[test]
+    fn test_xy_intersections_exclude_past_and_parallel() {
+        let a = Hailstone::new([0, 0, 0], [1, 1, 0]);
+
+        // Crosses at (5, 5), in the future for both
+        assert_eq!(1, count_future_xy_intersections(&[a, Hailstone::new([10, 0, 0], [-1, 1, 0])], 0..=10));
+
+        // Crossing at (5, 5), in the past for b
+        assert_eq!(0, count_future_xy_intersections(&[a, Hailstone::new([6, 4, 0], [1, -1, 0])], 0..=10));
+
+        // Crossing at (5, 5), outside the window
+        assert_eq!(0, count_future_xy_intersections(&[a, Hailstone::new([10, 0, 0], [-1, 1, 0])], 0..=4));
+
+        // Parallel
+        assert_eq!(0, count_future_xy_intersections(&[a, Hailstone::new([0, 10, 0], [1, 1, 0])], 0..=10));
+    }
+
+    #[test]
+    fn test_solve_rock_throw_with_huge_coordinates() {
+        let rock = Hailstone::new([287_430_900_705_823, 451_620_998_712_421, 260_730_677_041_648], [-14, 17, 126]);
+        let stones = stones(rock, &[
+            (412_341_287_031, [-31, 12, -18]),
+            (926_784_011_556, [151, -290, 80]),
+            (87_122_004_617, [-84, 236, 7]),
+            (544_003_910_020, [31, 44, -102]),
+        ]);
+
+        assert_eq!(Some(rock), solve_rock_throw(&stones));
+    }
+
+    #[test]
+    fn test_solve_rock_throw_needs_three_stones() {
+        let stone = Hailstone::new([0, 0, 0], [1, 1, 1]);
+
+        assert_eq!(None, solve_rock_throw(&[stone, stone]));
+    }
+}
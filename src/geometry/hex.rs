@@ -0,0 +1,328 @@
+//! Hexagonal grids, using axial coordinates.
+//!
+//! A hex is located by two coordinates q and r; the third cube
+//! coordinate s = -q - r is implicit. Directions depend on the layout:
+//! pointy-top hexes have east and west neighbors, flat-top hexes
+//! have north and south neighbors.
+//!
+//! Reference: https://www.redblobgames.com/grids/hexagons/
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Sub};
+use std::str::FromStr;
+
+/// A hex in axial coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(0, 0)
+    }
+
+    /// Gets the third cube coordinate.
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Gets the number of steps to reach another hex.
+    ///
+    /// ```
+    /// use enontekio::geometry::hex::Hex;
+    ///
+    /// assert_eq!(3, Hex::origin().distance(&Hex::new(3, -1)));
+    /// ```
+    pub fn distance(&self, other: &Self) -> i64 {
+        let delta = *other - *self;
+
+        (delta.q.abs() + delta.r.abs() + delta.s().abs()) / 2
+    }
+
+    /// Gets the number of steps to reach the hex from the origin.
+    pub fn length(&self) -> i64 {
+        Hex::origin().distance(self)
+    }
+
+    /// Gets the adjacent hex in the specified direction,
+    /// or None if the direction doesn't exist in this layout.
+    pub fn neighbor(&self, direction: HexDirection, layout: Layout) -> Option<Self> {
+        layout.direction_vector(direction).map(|delta| *self + delta)
+    }
+
+    /// Gets the 6 adjacent hexes.
+    pub fn neighbors(&self, layout: Layout) -> Vec<Self> {
+        layout.direction_vectors()
+            .iter()
+            .map(|&delta| *self + delta)
+            .collect()
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+/// The orientation of the hexes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Hexes have a vertex on top: rows are horizontal, neighbors are
+    /// east, west, northeast, northwest, southeast and southwest.
+    PointyTop,
+
+    /// Hexes have an edge on top: columns are vertical, neighbors are
+    /// north, south, northeast, northwest, southeast and southwest.
+    FlatTop,
+}
+
+impl Layout {
+    /// Gets the directions available in this layout, clockwise.
+    pub fn directions(&self) -> [HexDirection; 6] {
+        use HexDirection::*;
+
+        match self {
+            Layout::PointyTop => [NorthEast, East, SouthEast, SouthWest, West, NorthWest],
+            Layout::FlatTop => [North, NorthEast, SouthEast, South, SouthWest, NorthWest],
+        }
+    }
+
+    /// Gets the move in axial coordinates to go in the specified direction,
+    /// or None if the direction doesn't exist in this layout.
+    pub fn direction_vector(&self, direction: HexDirection) -> Option<Hex> {
+        use HexDirection::*;
+
+        let (q, r) = match (self, direction) {
+            (Layout::PointyTop, East) => (1, 0),
+            (Layout::PointyTop, NorthEast) => (1, -1),
+            (Layout::PointyTop, NorthWest) => (0, -1),
+            (Layout::PointyTop, West) => (-1, 0),
+            (Layout::PointyTop, SouthWest) => (-1, 1),
+            (Layout::PointyTop, SouthEast) => (0, 1),
+            (Layout::PointyTop, North | South) => return None,
+
+            (Layout::FlatTop, North) => (0, -1),
+            (Layout::FlatTop, NorthEast) => (1, -1),
+            (Layout::FlatTop, SouthEast) => (1, 0),
+            (Layout::FlatTop, South) => (0, 1),
+            (Layout::FlatTop, SouthWest) => (-1, 1),
+            (Layout::FlatTop, NorthWest) => (-1, 0),
+            (Layout::FlatTop, East | West) => return None,
+        };
+
+        Some(Hex::new(q, r))
+    }
+
+    /// Gets the 6 moves to the adjacent hexes, clockwise.
+    pub fn direction_vectors(&self) -> [Hex; 6] {
+        self.directions().map(|direction| self.direction_vector(direction).unwrap())
+    }
+}
+
+/// A direction on a hex grid, written "n", "ne", "e", "se", "s", "sw", "w" or "nw"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl HexDirection {
+    pub const ALL: [HexDirection; 8] = [
+        HexDirection::North,
+        HexDirection::NorthEast,
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::South,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HexDirection::North => "n",
+            HexDirection::NorthEast => "ne",
+            HexDirection::East => "e",
+            HexDirection::SouthEast => "se",
+            HexDirection::South => "s",
+            HexDirection::SouthWest => "sw",
+            HexDirection::West => "w",
+            HexDirection::NorthWest => "nw",
+        }
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HexDirection::ALL
+            .iter()
+            .find(|direction| direction.name() == s)
+            .copied()
+            .ok_or_else(|| format!("unknown hex direction: {}", s))
+    }
+}
+
+impl Display for HexDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Parses comma separated directions, like "ne,ne,s,s".
+///
+/// ```
+/// use enontekio::geometry::hex::{parse_directions, HexDirection};
+///
+/// let directions = parse_directions("ne, sw,e").unwrap();
+/// assert_eq!(vec![HexDirection::NorthEast, HexDirection::SouthWest, HexDirection::East], directions);
+/// ```
+pub fn parse_directions(expression: &str) -> Result<Vec<HexDirection>, String> {
+    let expression = expression.trim();
+    if expression.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    expression
+        .split(',')
+        .map(|direction| direction.trim().parse())
+        .collect()
+}
+
+/// Parses directions written without separator, like "esenee".
+///
+/// A "n" or "s" is always read with the following "e" or "w" if any,
+/// so this format is only unambiguous for pointy-top hexes.
+pub fn parse_concatenated_directions(expression: &str) -> Result<Vec<HexDirection>, String> {
+    let mut directions = Vec::new();
+    let mut chars = expression.trim().chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let mut name = c.to_string();
+        if (c == 'n' || c == 's') && matches!(chars.peek(), Some('e' | 'w')) {
+            name.push(chars.next().unwrap());
+        }
+
+        directions.push(name.parse()?);
+    }
+
+    Ok(directions)
+}
+
+/// Walks from the origin following comma separated moves on flat-top hexes,
+/// like "ne,ne,s,s".
+///
+/// Returns the distance from the origin at the end of the walk,
+/// and the maximum distance seen along the walk.
+///
+/// ```
+/// use enontekio::geometry::hex::walk;
+///
+/// assert_eq!(Ok((2, 2)), walk("ne,ne,s,s"));
+/// assert_eq!(Ok((0, 2)), walk("ne,ne,sw,sw"));
+/// ```
+pub fn walk(moves: &str) -> Result<(i64, i64), String> {
+    walk_directions(&parse_directions(moves)?, Layout::FlatTop)
+}
+
+/// Walks from the origin following the directions.
+///
+/// Returns the distance from the origin at the end of the walk,
+/// and the maximum distance seen along the walk,
+/// or an error if a direction doesn't exist in the layout.
+pub fn walk_directions(directions: &[HexDirection], layout: Layout) -> Result<(i64, i64), String> {
+    let mut hex = Hex::origin();
+    let mut max_distance = 0;
+
+    for &direction in directions {
+        hex = hex.neighbor(direction, layout)
+            .ok_or_else(|| format!("no {} direction in {:?} layout", direction, layout))?;
+        max_distance = max_distance.max(hex.length());
+    }
+
+    Ok((hex.length(), max_distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directions_are_symmetric() {
+        for layout in [Layout::PointyTop, Layout::FlatTop] {
+            let vectors = layout.direction_vectors();
+
+            for (i, vector) in vectors.iter().enumerate() {
+                assert_eq!(1, vector.length());
+                assert_eq!(Hex::origin(), *vector + vectors[(i + 3) % 6]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_flat_top_walk() {
+        let distance = |expression: &str| walk(expression).unwrap().0;
+
+        assert_eq!(3, distance("ne,ne,ne"));
+        assert_eq!(0, distance("ne,ne,sw,sw"));
+        assert_eq!(2, distance("ne,ne,s,s"));
+        assert_eq!(3, distance("se,sw,se,sw,sw"));
+    }
+
+    #[test]
+    fn test_walk_max_distance() {
+        assert_eq!(Ok((1, 4)), walk("n,n,n,n,s,s,s\n"));
+        assert_eq!(Ok((0, 0)), walk(""));
+        assert!(walk("ne,e").is_err());
+        assert!(walk_directions(&[HexDirection::East], Layout::PointyTop).is_ok());
+    }
+
+    #[test]
+    fn test_pointy_top_concatenated_directions() {
+        let directions = parse_concatenated_directions("nwwswee").unwrap();
+        assert_eq!(5, directions.len());
+
+        let end = directions
+            .into_iter()
+            .fold(Hex::origin(), |hex, direction| hex.neighbor(direction, Layout::PointyTop).unwrap());
+        assert_eq!(Hex::origin(), end);
+    }
+
+    #[test]
+    fn test_missing_directions() {
+        assert_eq!(None, Hex::origin().neighbor(HexDirection::North, Layout::PointyTop));
+        assert_eq!(None, Hex::origin().neighbor(HexDirection::East, Layout::FlatTop));
+        assert!(parse_directions("ne,up").is_err());
+        assert_eq!(Ok(vec![]), parse_directions(""));
+    }
+}
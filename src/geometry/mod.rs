@@ -0,0 +1,15 @@
+//! Points and vectors in a plane, and geometric algorithms on them.
+
+mod antinodes;
+mod bresenham;
+mod point;
+
+pub mod cuboid;
+pub mod hailstones;
+pub mod hex;
+pub mod polygon;
+pub mod segments;
+
+pub use antinodes::antinodes;
+pub use bresenham::{bresenham, Bresenham};
+pub use point::{Coordinate, Point2, Vec2};
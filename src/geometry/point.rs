@@ -0,0 +1,341 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::collections::{
+    ALL_DIRECTION_VECTORS_2D, DIAGONAL_DIRECTION_VECTORS_2D, KNIGHT_MOVE_VECTORS_2D, TAXICAB_DIRECTION_VECTORS_2D,
+};
+
+/// A numeric type usable as coordinate, integer or float.
+pub trait Coordinate: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self> {
+    fn zero() -> Self;
+    fn abs(self) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                fn zero() -> Self {
+                    0 as $t
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// A point in a 2D plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A vector in a 2D plane, like a move or a direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    /// Gets the Manhattan distance to another point, ie |dx| + |dy|.
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        (*other - *self).manhattan_length()
+    }
+
+    /// Gets the Euclidean distance to another point.
+    pub fn euclidean_distance(&self, other: &Self) -> f64 {
+        (*other - *self).length()
+    }
+
+    /// Gets the vector from the origin to this point.
+    pub fn to_vec(&self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+}
+
+impl Point2<i32> {
+    /// Iterates over the 4 neighbors sharing a side.
+    pub fn taxicab_neighbors(self) -> impl Iterator<Item = Point2<i32>> {
+        Vec2::taxicab_directions().map(move |direction| self + direction)
+    }
+
+    /// Iterates over the 8 neighbors, diagonals included.
+    pub fn all_neighbors(self) -> impl Iterator<Item = Point2<i32>> {
+        Vec2::all_directions().map(move |direction| self + direction)
+    }
+}
+
+/// The direction vectors of the collections module, as Vec2.
+///
+/// The tuples are converted with x the first component,
+/// so x is delta_i, the row, for a grid in row-major order.
+impl Vec2<i32> {
+    /// Iterates over the vectors to move horizontally or vertically.
+    ///
+    /// ```
+    /// use enontekio::geometry::Vec2;
+    ///
+    /// let sum = Vec2::taxicab_directions().fold(Vec2::zero(), |sum, direction| sum + direction);
+    /// assert_eq!(Vec2::zero(), sum);
+    /// ```
+    pub fn taxicab_directions() -> impl Iterator<Item = Vec2<i32>> {
+        TAXICAB_DIRECTION_VECTORS_2D.into_iter().map(Vec2::from)
+    }
+
+    /// Iterates over the vectors to move horizontally, vertically or in diagonal.
+    pub fn all_directions() -> impl Iterator<Item = Vec2<i32>> {
+        ALL_DIRECTION_VECTORS_2D.into_iter().map(Vec2::from)
+    }
+
+    /// Iterates over the vectors to move in diagonal.
+    pub fn diagonal_directions() -> impl Iterator<Item = Vec2<i32>> {
+        DIAGONAL_DIRECTION_VECTORS_2D.into_iter().map(Vec2::from)
+    }
+
+    /// Iterates over the vectors of the moves of a knight on a chessboard.
+    pub fn knight_moves() -> impl Iterator<Item = Vec2<i32>> {
+        KNIGHT_MOVE_VECTORS_2D.into_iter().map(Vec2::from)
+    }
+}
+
+impl<T: Coordinate> Vec2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    /// Gets |x| + |y|.
+    pub fn manhattan_length(&self) -> T {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Gets the Euclidean length.
+    pub fn length(&self) -> f64 {
+        self.x.to_f64().hypot(self.y.to_f64())
+    }
+
+    /// Rotates by 90 degrees counterclockwise, with the y axis pointing up.
+    ///
+    /// With the y axis pointing down, like rows in a grid, the rotation is clockwise.
+    pub fn rotate_90_counterclockwise(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90 degrees clockwise, with the y axis pointing up.
+    ///
+    /// With the y axis pointing down, like rows in a grid, the rotation is counterclockwise.
+    pub fn rotate_90_clockwise(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Gets the z component of the cross product: positive if other
+    /// is counterclockwise from self, negative if clockwise, 0 if collinear.
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// Converts a direction vector, like the ones of `get_all_direction_vectors_2d`.
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(vector: Vec2<T>) -> Self {
+        (vector.x, vector.y)
+    }
+}
+
+impl<T: Coordinate> Add<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, vector: Vec2<T>) -> Point2<T> {
+        Point2::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, vector: Vec2<T>) {
+        *self = *self + vector;
+    }
+}
+
+impl<T: Coordinate> Sub<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, vector: Vec2<T>) -> Point2<T> {
+        Point2::new(self.x - vector.x, self.y - vector.y)
+    }
+}
+
+impl<T: Coordinate> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, vector: Vec2<T>) {
+        *self = *self - vector;
+    }
+}
+
+/// The difference of two points is the vector going from the second to the first.
+impl<T: Coordinate> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Point2<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Vec2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Vec2<T>) {
+        *self = *self - other;
+    }
+}
+
+/// Scales a vector.
+impl<T: Coordinate> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, factor: T) -> Vec2<T> {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Coordinate> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operations() {
+        let p = Point2::new(1, 2);
+        let v = Vec2::new(3, -1);
+
+        assert_eq!(Point2::new(4, 1), p + v);
+        assert_eq!(Point2::new(-2, 3), p - v);
+        assert_eq!(v, (p + v) - p);
+        assert_eq!(Vec2::new(6, -2), v * 2);
+        assert_eq!(Vec2::new(-3, 1), -v);
+
+        let mut q = p;
+        q += v;
+        q -= v * 2;
+        assert_eq!(Point2::new(-2, 3), q);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(0, 0);
+        let b = Point2::new(3, -4);
+
+        assert_eq!(7, a.manhattan_distance(&b));
+        assert_eq!(5.0, a.euclidean_distance(&b));
+        assert_eq!(5.0, Point2::new(0.0, 0.0).euclidean_distance(&Point2::new(3.0, 4.0)));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let east = Vec2::new(1, 0);
+
+        assert_eq!(Vec2::new(0, 1), east.rotate_90_counterclockwise());
+        assert_eq!(Vec2::new(0, -1), east.rotate_90_clockwise());
+        assert_eq!(east, east.rotate_90_clockwise().rotate_90_counterclockwise());
+        assert_eq!(-east, east.rotate_90_clockwise().rotate_90_clockwise());
+    }
+
+    #[test]
+    fn test_products() {
+        let a = Vec2::new(2, 0);
+        let b = Vec2::new(0, 3);
+
+        assert_eq!(0, a.dot(&b));
+        assert_eq!(6, a.cross(&b));
+        assert_eq!(-6, b.cross(&a));
+        assert_eq!(4.0, Vec2::new(2.0, 0.0).dot(&Vec2::new(2.0, 5.0)));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let directions: Vec<Vec2<i32>> = Vec2::taxicab_directions().collect();
+
+        assert_eq!(Vec2::new(1, 0), directions[0]);
+        assert_eq!((1, 2), Point2::new(1, 2).into());
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(8, Vec2::all_directions().count());
+        assert!(Vec2::diagonal_directions().all(|direction| direction.manhattan_length() == 2));
+        assert!(Vec2::knight_moves().all(|direction| direction.manhattan_length() == 3));
+
+        let neighbors: Vec<_> = Point2::new(0, 0).taxicab_neighbors().collect();
+        assert!(neighbors.contains(&Point2::new(0, -1)));
+        assert_eq!(8, Point2::new(5, 5).all_neighbors().count());
+    }
+}
@@ -0,0 +1,138 @@
+//! Area and lattice points of simple polygons with integer vertices.
+//!
+//! Vertices are given in order, clockwise or counterclockwise,
+//! as Point2<i64> or (i64, i64). The polygon is closed implicitly:
+//! the last vertex is connected to the first one.
+
+use crate::geometry::Point2;
+use crate::math::euclid::gcd;
+
+/// Gets twice the area of the polygon, using the shoelace formula.
+///
+/// The doubled area is always an integer for integer vertices.
+pub fn double_area<P>(vertices: &[P]) -> i64
+where P: Copy + Into<Point2<i64>> {
+    edges(vertices)
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum::<i64>()
+        .abs()
+}
+
+/// Gets the area of the polygon, using the shoelace formula.
+///
+/// ```
+/// use enontekio::geometry::polygon::area;
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+/// assert_eq!(16.0, area(&square));
+/// ```
+pub fn area<P>(vertices: &[P]) -> f64
+where P: Copy + Into<Point2<i64>> {
+    double_area(vertices) as f64 / 2.0
+}
+
+/// Counts the integer points on the boundary of the polygon, vertices included.
+pub fn boundary_points<P>(vertices: &[P]) -> i64
+where P: Copy + Into<Point2<i64>> {
+    edges(vertices)
+        .map(|(a, b)| gcd(b.x - a.x, b.y - a.y))
+        .sum()
+}
+
+/// Counts the integer points strictly inside the polygon, using Pick's theorem:
+/// A = i + b/2 - 1.
+///
+/// A degenerate polygon, with fewer than 3 vertices or a zero area, has no point.
+///
+/// ```
+/// use enontekio::geometry::polygon::interior_points;
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+/// assert_eq!(9, interior_points(&square));
+/// ```
+pub fn interior_points<P>(vertices: &[P]) -> i64
+where P: Copy + Into<Point2<i64>> {
+    if is_degenerate(vertices) {
+        return 0;
+    }
+
+    (double_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+/// Counts the integer points inside the polygon or on its boundary.
+///
+/// When the vertices are the centers of the cells of a dug trench,
+/// this is the count of cells of the trench and its inside.
+///
+/// A degenerate polygon, with fewer than 3 vertices or a zero area, has no point.
+pub fn lattice_points<P>(vertices: &[P]) -> i64
+where P: Copy + Into<Point2<i64>> {
+    if is_degenerate(vertices) {
+        return 0;
+    }
+
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+fn is_degenerate<P>(vertices: &[P]) -> bool
+where P: Copy + Into<Point2<i64>> {
+    vertices.len() < 3 || double_area(vertices) == 0
+}
+
+fn edges<P>(vertices: &[P]) -> impl Iterator<Item = (Point2<i64>, Point2<i64>)> + '_
+where P: Copy + Into<Point2<i64>> {
+    let count = vertices.len();
+
+    (0..count).map(move |i| (vertices[i].into(), vertices[(i + 1) % count].into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_is_orientation_independent() {
+        let triangle = [(0, 0), (4, 0), (0, 3)];
+        let reversed = [(0, 3), (4, 0), (0, 0)];
+
+        assert_eq!(12, double_area(&triangle));
+        assert_eq!(6.0, area(&reversed));
+    }
+
+    #[test]
+    fn test_pick_theorem() {
+        let triangle = [Point2::new(0, 0), Point2::new(4, 0), Point2::new(0, 3)];
+
+        // 4 + 3 + gcd(4, 3)
+        assert_eq!(8, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+        assert_eq!(11, lattice_points(&triangle));
+    }
+
+    #[test]
+    fn test_lattice_points_of_rectilinear_polygon() {
+        // An L shape: a 3x3 square with its top right 2x2 corner removed
+        let shape = [(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)];
+
+        assert_eq!(8, lattice_points(&shape));
+    }
+
+    #[test]
+    fn test_degenerate_polygons() {
+        let empty: [(i64, i64); 0] = [];
+        assert_eq!(0, double_area(&empty));
+        assert_eq!(0, boundary_points(&empty));
+
+        assert_eq!(0, interior_points(&empty));
+        assert_eq!(0, lattice_points(&empty));
+
+        assert_eq!(0, double_area(&[(1, 1)]));
+
+        let segment = [(0, 0), (4, 0)];
+        assert_eq!(0, interior_points(&segment));
+        assert_eq!(0, lattice_points(&segment));
+
+        let flat = [(0, 0), (2, 0), (4, 0)];
+        assert_eq!(0, lattice_points(&flat));
+    }
+}
@@ -0,0 +1,230 @@
+//! Line segments in a plane and their intersection.
+//!
+//! The intersection is decided with orientation tests, ie signs of
+//! cross products, so it's exact for integer coordinates.
+
+use std::cmp::Ordering;
+
+use crate::geometry::{Coordinate, Point2};
+
+/// A segment between two points, endpoints included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment<T> {
+    pub start: Point2<T>,
+    pub end: Point2<T>,
+}
+
+impl<T: Coordinate> Segment<T> {
+    pub fn new(start: Point2<T>, end: Point2<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Gets the endpoints, ordered by x then y.
+    ///
+    /// For collinear segments, this order is the order along the line.
+    fn ordered_endpoints(&self) -> (Point2<T>, Point2<T>) {
+        if self.end < self.start {
+            (self.end, self.start)
+        } else {
+            (self.start, self.end)
+        }
+    }
+
+    /// Checks if a point collinear with the segment is between its endpoints.
+    fn contains_collinear(&self, point: &Point2<T>) -> bool {
+        let (min_x, max_x) = min_max(self.start.x, self.end.x);
+        let (min_y, max_y) = min_max(self.start.y, self.end.y);
+
+        min_x <= point.x && point.x <= max_x && min_y <= point.y && point.y <= max_y
+    }
+}
+
+impl<T> From<((T, T), (T, T))> for Segment<T> {
+    fn from((start, end): ((T, T), (T, T))) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+}
+
+/// Description of where two segments meet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection<T> {
+    /// The segments don't meet
+    None,
+
+    /// The segments meet at one point.
+    ///
+    /// The point can have non-integer coordinates even for integer segments.
+    Point(Point2<f64>),
+
+    /// The segments are collinear and share a segment
+    Overlap(Segment<T>),
+}
+
+impl<T> SegmentIntersection<T> {
+    /// Checks if the segments meet.
+    pub fn is_any(&self) -> bool {
+        !matches!(self, SegmentIntersection::None)
+    }
+}
+
+/// Gets the orientation of the r point compared to the p -> q line:
+/// Greater for counterclockwise, Less for clockwise, Equal for collinear.
+pub fn orientation<T: Coordinate>(p: &Point2<T>, q: &Point2<T>, r: &Point2<T>) -> Ordering {
+    let cross = (*q - *p).cross(&(*r - *p));
+
+    cross.partial_cmp(&T::zero()).unwrap_or(Ordering::Equal)
+}
+
+/// Determines if two segments intersect, and where.
+///
+/// ```
+/// use enontekio::geometry::Point2;
+/// use enontekio::geometry::segments::{intersection, Segment, SegmentIntersection};
+///
+/// let a = Segment::from(((0, 0), (4, 4)));
+/// let b = Segment::from(((0, 4), (4, 0)));
+/// assert_eq!(SegmentIntersection::Point(Point2::new(2.0, 2.0)), intersection(&a, &b));
+///
+/// let c = Segment::from(((2, 2), (8, 8)));
+/// assert_eq!(SegmentIntersection::Overlap(Segment::from(((2, 2), (4, 4)))), intersection(&a, &c));
+/// ```
+pub fn intersection<T: Coordinate>(a: &Segment<T>, b: &Segment<T>) -> SegmentIntersection<T> {
+    let o1 = orientation(&a.start, &a.end, &b.start);
+    let o2 = orientation(&a.start, &a.end, &b.end);
+    let o3 = orientation(&b.start, &b.end, &a.start);
+    let o4 = orientation(&b.start, &b.end, &a.end);
+
+    if o1 == Ordering::Equal && o2 == Ordering::Equal {
+        return collinear_intersection(a, b);
+    }
+
+    if o1 == o2 || o3 == o4 {
+        // Both endpoints of a segment are strictly on the same side of the other
+        return SegmentIntersection::None;
+    }
+
+    // An endpoint touching the other segment is the exact intersection
+    if o1 == Ordering::Equal {
+        return SegmentIntersection::Point(to_f64(&b.start));
+    }
+    if o2 == Ordering::Equal {
+        return SegmentIntersection::Point(to_f64(&b.end));
+    }
+    if o3 == Ordering::Equal {
+        return SegmentIntersection::Point(to_f64(&a.start));
+    }
+    if o4 == Ordering::Equal {
+        return SegmentIntersection::Point(to_f64(&a.end));
+    }
+
+    // Proper crossing: solve a.start + t * da = b.start + u * db
+    let da = a.end - a.start;
+    let db = b.end - b.start;
+    let t = (b.start - a.start).cross(&db).to_f64() / da.cross(&db).to_f64();
+
+    SegmentIntersection::Point(Point2::new(
+        a.start.x.to_f64() + t * da.x.to_f64(),
+        a.start.y.to_f64() + t * da.y.to_f64(),
+    ))
+}
+
+fn collinear_intersection<T: Coordinate>(a: &Segment<T>, b: &Segment<T>) -> SegmentIntersection<T> {
+    // When a is reduced to a point, any b is "collinear" with it.
+    if a.start == a.end {
+        return if b.contains_collinear(&a.start) && orientation(&b.start, &b.end, &a.start) == Ordering::Equal {
+            SegmentIntersection::Point(to_f64(&a.start))
+        } else {
+            SegmentIntersection::None
+        };
+    }
+
+    let (a_min, a_max) = a.ordered_endpoints();
+    let (b_min, b_max) = b.ordered_endpoints();
+
+    let low = if a_min < b_min { b_min } else { a_min };
+    let high = if a_max < b_max { a_max } else { b_max };
+
+    match low.partial_cmp(&high) {
+        Some(Ordering::Less) => SegmentIntersection::Overlap(Segment::new(low, high)),
+        Some(Ordering::Equal) => SegmentIntersection::Point(to_f64(&low)),
+        _ => SegmentIntersection::None,
+    }
+}
+
+fn min_max<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn to_f64<T: Coordinate>(point: &Point2<T>) -> Point2<f64> {
+    Point2::new(point.x.to_f64(), point.y.to_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> Segment<i64> {
+        Segment::from(((x1, y1), (x2, y2)))
+    }
+
+    #[test]
+    fn test_crossing_segments() {
+        let a = segment(0, 0, 3, 1);
+        let b = segment(0, 1, 3, 0);
+
+        assert_eq!(SegmentIntersection::Point(Point2::new(1.5, 0.5)), intersection(&a, &b));
+        assert_eq!(SegmentIntersection::Point(Point2::new(1.5, 0.5)), intersection(&b, &a));
+    }
+
+    #[test]
+    fn test_touching_segments() {
+        let a = segment(0, 0, 4, 0);
+        let b = segment(2, 0, 2, 5);
+
+        assert_eq!(SegmentIntersection::Point(Point2::new(2.0, 0.0)), intersection(&a, &b));
+    }
+
+    #[test]
+    fn test_disjoint_segments() {
+        assert!(!intersection(&segment(0, 0, 1, 1), &segment(2, 0, 3, -5)).is_any());
+
+        // Parallel
+        assert!(!intersection(&segment(0, 0, 4, 0), &segment(0, 1, 4, 1)).is_any());
+
+        // Collinear but apart
+        assert!(!intersection(&segment(0, 0, 1, 1), &segment(3, 3, 2, 2)).is_any());
+    }
+
+    #[test]
+    fn test_collinear_segments() {
+        assert_eq!(
+            SegmentIntersection::Overlap(segment(2, 7, 5, 7)),
+            intersection(&segment(0, 7, 5, 7), &segment(9, 7, 2, 7)),
+        );
+
+        assert_eq!(
+            SegmentIntersection::Point(Point2::new(0.0, 3.0)),
+            intersection(&segment(0, 0, 0, 3), &segment(0, 3, 0, 8)),
+        );
+
+        assert_eq!(
+            SegmentIntersection::Point(Point2::new(1.0, 1.0)),
+            intersection(&segment(1, 1, 1, 1), &segment(0, 0, 2, 2)),
+        );
+    }
+
+    #[test]
+    fn test_float_segments() {
+        let a = Segment::from(((0.0, 0.0), (1.0, 1.0)));
+        let b = Segment::from(((0.0, 0.5), (1.0, 0.5)));
+
+        assert_eq!(SegmentIntersection::Point(Point2::new(0.5, 0.5)), intersection(&a, &b));
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+/// Colors the nodes with two colors, so the nodes of each edge have
+/// different colors, ie checks if the graph is bipartite.
+///
+/// Edges direction is ignored. Each connected component starts with
+/// false for its first node.
+///
+/// Returns the color of each node, by index, or the labels of the nodes
+/// along a cycle of odd length, making such a coloring impossible.
+///
+/// ```
+/// use enontekio::graph::{two_color, Graph};
+///
+/// let mut graph = Graph::new_undirected();
+/// graph.add_edge("a", "b", ());
+/// graph.add_edge("b", "c", ());
+/// assert_eq!(Ok(vec![false, true, false]), two_color(&graph));
+///
+/// graph.add_edge("c", "a", ());
+/// assert_eq!(3, two_color(&graph).unwrap_err().len());
+/// ```
+pub fn two_color<N, E>(graph: &Graph<N, E>) -> Result<Vec<bool>, Vec<N>>
+where N: Eq + Hash + Clone {
+    let mut neighbors = vec![Vec::new(); graph.len()];
+    for (from, to, _) in graph.edges() {
+        neighbors[from].push(to);
+        if from != to {
+            neighbors[to].push(from);
+        }
+    }
+
+    let mut colors: Vec<Option<bool>> = vec![None; graph.len()];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.len()];
+
+    for root in 0..graph.len() {
+        if colors[root].is_some() {
+            continue;
+        }
+
+        colors[root] = Some(false);
+        let mut queue = VecDeque::from([root]);
+        while let Some(node) = queue.pop_front() {
+            let color = colors[node].unwrap();
+
+            for &next in &neighbors[node] {
+                match colors[next] {
+                    None => {
+                        colors[next] = Some(!color);
+                        parents[next] = Some(node);
+                        queue.push_back(next);
+                    }
+                    Some(next_color) if next_color == color => {
+                        let cycle = odd_cycle(&parents, node, next);
+                        return Err(cycle.into_iter().map(|index| graph.label(index).clone()).collect());
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Ok(colors.into_iter().map(|color| color.unwrap()).collect())
+}
+
+/// Builds the cycle closed by an edge between two nodes of the same
+/// BFS tree, through their lowest common ancestor.
+fn odd_cycle(parents: &[Option<usize>], a: usize, b: usize) -> Vec<usize> {
+    let ancestors = |node: usize| {
+        let mut path = vec![node];
+        while let Some(parent) = parents[*path.last().unwrap()] {
+            path.push(parent);
+        }
+        path
+    };
+
+    let mut path_a = ancestors(a);
+    let mut path_b = ancestors(b);
+
+    // Removes the common part above the lowest common ancestor
+    while path_a.len() > 1 && path_b.len() > 1 && path_a[path_a.len() - 2] == path_b[path_b.len() - 2] {
+        path_a.pop();
+        path_b.pop();
+    }
+    path_b.pop();
+    path_b.reverse();
+
+    path_a.extend(path_b);
+    path_a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_color_bipartite() {
+        // A square with a tail, and an isolated node
+        let mut graph = Graph::new_directed();
+        for (from, to) in [(1, 2), (2, 3), (4, 3), (4, 1), (3, 5)] {
+            graph.add_edge(from, to, ());
+        }
+        graph.add_node(6);
+
+        let colors = two_color(&graph).unwrap();
+        for (from, to, _) in graph.edges() {
+            assert_ne!(colors[from], colors[to]);
+        }
+    }
+
+    #[test]
+    fn test_two_color_odd_cycle_witness() {
+        // A pentagon, with a tail to move the cycle away from the root
+        let mut graph = Graph::new_undirected();
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 2)] {
+            graph.add_edge(from, to, ());
+        }
+
+        let mut cycle = two_color(&graph).unwrap_err();
+        cycle.sort();
+
+        assert_eq!(vec![2, 3, 4, 5, 6], cycle);
+    }
+}
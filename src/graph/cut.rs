@@ -0,0 +1,171 @@
+//! Minimum edge cuts, found with unit capacity max-flows.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+/// Finds a set of exactly <size> edges disconnecting the graph in two parts.
+///
+/// Edges direction is ignored. The graph is expected to be connected,
+/// with no cut smaller than <size>.
+///
+/// As the first node is on one side of the cut, the max-flow from it
+/// to each other node is computed, until a node on the other side is found:
+/// the flow is then <size>, and the nodes still reachable in the residual
+/// graph form the side of the first node. Each flow search stops after
+/// <size> + 1 augmenting paths, so the cost is O(nodes * size * edges).
+///
+/// Returns the sizes of both sides, the first node side first.
+pub fn find_edge_cut<N, E>(graph: &Graph<N, E>, size: usize) -> Option<(usize, usize)>
+where N: Eq + Hash + Clone {
+    let mut capacities: Vec<HashMap<usize, i64>> = vec![HashMap::new(); graph.len()];
+    for (from, to, _) in graph.edges() {
+        if from != to {
+            *capacities[from].entry(to).or_insert(0) += 1;
+            *capacities[to].entry(from).or_insert(0) += 1;
+        }
+    }
+
+    let source = 0;
+    for sink in 1..graph.len() {
+        let mut residual = capacities.clone();
+
+        let mut flow = 0;
+        while flow <= size && augment(&mut residual, source, sink) {
+            flow += 1;
+        }
+
+        if flow == size {
+            let side = reachable(&residual, source).len();
+            return Some((side, graph.len() - side));
+        }
+    }
+
+    None
+}
+
+/// Finds 3 edges disconnecting the graph in two parts,
+/// and returns the sizes of both parts.
+///
+/// ```
+/// use enontekio::graph::{find_three_edge_cut, Graph};
+///
+/// // Two complete graphs of 5 nodes, linked by 3 edges
+/// let mut graph = Graph::new_undirected();
+/// for offset in [0, 5] {
+///     for a in 0..5 {
+///         for b in a + 1..5 {
+///             graph.add_edge(a + offset, b + offset, ());
+///         }
+///     }
+/// }
+/// for (a, b) in [(0, 5), (1, 6), (2, 7)] {
+///     graph.add_edge(a, b, ());
+/// }
+///
+/// assert_eq!(Some((5, 5)), find_three_edge_cut(&graph));
+/// ```
+pub fn find_three_edge_cut<N, E>(graph: &Graph<N, E>) -> Option<(usize, usize)>
+where N: Eq + Hash + Clone {
+    find_edge_cut(graph, 3)
+}
+
+/// Finds a path with residual capacity from source to sink with a BFS,
+/// and pushes one unit of flow along it.
+fn augment(residual: &mut [HashMap<usize, i64>], source: usize, sink: usize) -> bool {
+    let mut parents = vec![None; residual.len()];
+    parents[source] = Some(source);
+
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        if node == sink {
+            break;
+        }
+
+        for (&next, &capacity) in &residual[node] {
+            if capacity > 0 && parents[next].is_none() {
+                parents[next] = Some(node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if parents[sink].is_none() {
+        return false;
+    }
+
+    let mut node = sink;
+    while node != source {
+        let parent = parents[node].unwrap();
+        *residual[parent].get_mut(&node).unwrap() -= 1;
+        *residual[node].entry(parent).or_insert(0) += 1;
+        node = parent;
+    }
+
+    true
+}
+
+fn reachable(residual: &[HashMap<usize, i64>], source: usize) -> Vec<usize> {
+    let mut seen = vec![false; residual.len()];
+    seen[source] = true;
+
+    let mut stack = vec![source];
+    let mut nodes = Vec::new();
+    while let Some(node) = stack.pop() {
+        nodes.push(node);
+
+        for (&next, &capacity) in &residual[node] {
+            if capacity > 0 && !seen[next] {
+                seen[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIRES: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn test_find_three_edge_cut() {
+        let mut graph = Graph::new_undirected();
+        for line in WIRES.lines() {
+            let (from, targets) = line.split_once(": ").unwrap();
+            for to in targets.split(' ') {
+                graph.add_edge(from, to, ());
+            }
+        }
+
+        let (a, b) = find_three_edge_cut(&graph).unwrap();
+        assert_eq!(54, a * b);
+    }
+
+    #[test]
+    fn test_no_cut_of_this_size() {
+        let mut graph = Graph::new_undirected();
+        for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+            graph.add_edge(a, b, ());
+        }
+
+        assert_eq!(None, find_three_edge_cut(&graph));
+        assert_eq!(Some((1, 2)), find_edge_cut(&graph, 2));
+    }
+}
@@ -0,0 +1,290 @@
+//! Longest simple paths, in a directed acyclic graph, or by exhaustive
+//! search in a grid, after compressing its corridors.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::collections::TAXICAB_DIRECTION_VECTORS_2D;
+use crate::graph::Graph;
+
+/// Finds the longest path of a directed acyclic graph, using the edges weights.
+///
+/// The path can start and end at any node. Nodes are processed
+/// in topological order, so this runs in linear time.
+///
+/// Returns the labels of the nodes along the path and its total weight,
+/// or None if the graph is empty, undirected or has a cycle.
+///
+/// ```
+/// use enontekio::graph::{longest_path_dag, Graph};
+///
+/// let mut graph = Graph::new_directed();
+/// graph.add_edge("a", "b", 3);
+/// graph.add_edge("b", "d", 1);
+/// graph.add_edge("a", "c", 1);
+/// graph.add_edge("c", "d", 5);
+///
+/// assert_eq!(Some((vec!["a", "c", "d"], 6)), longest_path_dag(&graph));
+/// ```
+pub fn longest_path_dag<N, E>(graph: &Graph<N, E>) -> Option<(Vec<N>, E)>
+where N: Eq + Hash + Clone, E: Copy + Ord + Add<Output = E> + Default {
+    if !graph.is_directed() || graph.is_empty() {
+        return None;
+    }
+
+    // Kahn's algorithm
+    let mut in_degrees = vec![0; graph.len()];
+    for (_, to, _) in graph.edges() {
+        in_degrees[to] += 1;
+    }
+
+    let mut queue: VecDeque<usize> = (0..graph.len()).filter(|&node| in_degrees[node] == 0).collect();
+    let mut order = Vec::with_capacity(graph.len());
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+
+        for (next, _) in graph.neighbors(node) {
+            in_degrees[next] -= 1;
+            if in_degrees[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if order.len() < graph.len() {
+        return None;
+    }
+
+    // Best path ending at each node, as its weight and previous node
+    let mut best: Vec<(E, Option<usize>)> = vec![(E::default(), None); graph.len()];
+    for &node in &order {
+        for (next, &weight) in graph.neighbors(node) {
+            let candidate = best[node].0 + weight;
+            if candidate > best[next].0 {
+                best[next] = (candidate, Some(node));
+            }
+        }
+    }
+
+    let end = (0..graph.len()).max_by_key(|&node| best[node].0)?;
+
+    let mut path = vec![graph.label(end).clone()];
+    let mut node = end;
+    while let Some(previous) = best[node].1 {
+        path.push(graph.label(previous).clone());
+        node = previous;
+    }
+    path.reverse();
+
+    Some((path, best[end].0))
+}
+
+/// Finds the length of the longest path from start to end in a grid,
+/// never visiting a cell twice.
+///
+/// The rule tells if a move is allowed, from a cell value to a neighbor
+/// cell value, in a direction (delta_i, delta_j). Only horizontal and
+/// vertical moves are considered.
+///
+/// The grid is first compressed into a graph between junctions, ie cells
+/// with 3 neighbors or more, weighted by the length of the corridors
+/// between them. The exhaustive search then only explores the junctions.
+///
+/// Returns None if end can't be reached.
+///
+/// ```
+/// use enontekio::graph::longest_path_grid;
+///
+/// let grid: Vec<Vec<char>> = [
+///     "#.###",
+///     "#...#",
+///     "#.#.#",
+///     "#...#",
+///     "###.#",
+/// ].iter().map(|line| line.chars().collect()).collect();
+///
+/// let length = longest_path_grid(&grid, (0, 1), (4, 3), |_, &to, _| to != '#');
+/// assert_eq!(Some(6), length);
+/// ```
+pub fn longest_path_grid<T, F>(grid: &[Vec<T>], start: (usize, usize), end: (usize, usize), rule: F) -> Option<usize>
+where F: Fn(&T, &T, (i32, i32)) -> bool {
+    let graph = compress_corridors(grid, start, end, &rule);
+
+    let start = graph.node_index(&start)?;
+    let end = graph.node_index(&end)?;
+
+    let mut visited = vec![false; graph.len()];
+    visited[start] = true;
+
+    longest_path_between(&graph, start, end, &mut visited)
+}
+
+fn longest_path_between(graph: &Graph<(usize, usize), usize>, node: usize, end: usize, visited: &mut [bool]) -> Option<usize> {
+    if node == end {
+        return Some(0);
+    }
+
+    let mut best = None;
+    for (next, &length) in graph.neighbors(node) {
+        if visited[next] {
+            continue;
+        }
+
+        visited[next] = true;
+        if let Some(rest) = longest_path_between(graph, next, end, visited) {
+            best = best.max(Some(length + rest));
+        }
+        visited[next] = false;
+    }
+
+    best
+}
+
+/// Builds the directed graph between junctions, start and end,
+/// where edges are corridors weighted by their length.
+fn compress_corridors<T, F>(grid: &[Vec<T>], start: (usize, usize), end: (usize, usize), rule: &F) -> Graph<(usize, usize), usize>
+where F: Fn(&T, &T, (i32, i32)) -> bool {
+    let directions = TAXICAB_DIRECTION_VECTORS_2D;
+
+    let step = |(i, j): (usize, usize), (delta_i, delta_j): (i32, i32)| -> Option<(usize, usize)> {
+        let ni = i.checked_add_signed(delta_i as isize)?;
+        let nj = j.checked_add_signed(delta_j as isize)?;
+        grid.get(ni)?.get(nj)?;
+
+        Some((ni, nj))
+    };
+
+    // Cells connected to a neighbor, whatever the direction of the move
+    let is_linked = |from: (usize, usize), direction: (i32, i32)| -> bool {
+        step(from, direction).is_some_and(|to| {
+            rule(&grid[from.0][from.1], &grid[to.0][to.1], direction)
+                || rule(&grid[to.0][to.1], &grid[from.0][from.1], (-direction.0, -direction.1))
+        })
+    };
+
+    let mut junctions: Vec<(usize, usize)> = vec![start, end];
+    for (i, row) in grid.iter().enumerate() {
+        for j in 0..row.len() {
+            let links = directions.iter().filter(|&&direction| is_linked((i, j), direction)).count();
+            if links >= 3 && (i, j) != start && (i, j) != end {
+                junctions.push((i, j));
+            }
+        }
+    }
+    let is_junction: HashMap<(usize, usize), ()> = junctions.iter().map(|&cell| (cell, ())).collect();
+
+    let mut graph = Graph::new_directed();
+    for &junction in &junctions {
+        graph.add_node(junction);
+    }
+
+    for &junction in &junctions {
+        for &direction in &directions {
+            let allowed = |from: (usize, usize), direction: (i32, i32)| {
+                step(from, direction).filter(|to| rule(&grid[from.0][from.1], &grid[to.0][to.1], direction))
+            };
+
+            let mut previous = junction;
+            let mut current = match allowed(junction, direction) {
+                None => continue,
+                Some(cell) => cell,
+            };
+            let mut length = 1;
+
+            // Follows the corridor until the next junction, or a dead end
+            while !is_junction.contains_key(&current) {
+                let next = directions.iter()
+                    .filter_map(|&direction| allowed(current, direction))
+                    .find(|&cell| cell != previous);
+
+                match next {
+                    None => break,
+                    Some(cell) => {
+                        previous = current;
+                        current = cell;
+                        length += 1;
+                    }
+                }
+            }
+
+            if is_junction.contains_key(&current) && current != junction {
+                graph.add_edge(junction, current, length);
+            }
+        }
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRAILS: [&str; 23] = [
+        "#.#####################",
+        "#.......#########...###",
+        "#######.#########.#.###",
+        "###.....#.>.>.###.#.###",
+        "###v#####.#v#.###.#.###",
+        "###.>...#.#.#.....#...#",
+        "###v###.#.#.#########.#",
+        "###...#.#.#.......#...#",
+        "#####.#.#.#######.#.###",
+        "#.....#.#.#.......#...#",
+        "#.#####.#.#.#########v#",
+        "#.#...#...#...###...>.#",
+        "#.#.#v#######v###.###v#",
+        "#...#.>.#...>.>.#.###.#",
+        "#####v#.#.###v#.#.###.#",
+        "#.....#...#...#.#.#...#",
+        "#.#########.###.#.#.###",
+        "#...###...#...#...#.###",
+        "###.###.#.###v#####v###",
+        "#...#...#.#.>.>.#.>.###",
+        "#.###.###.#.###.#.#v###",
+        "#.....###...###...#...#",
+        "#####################.#",
+    ];
+
+    fn slopes(from: &char, to: &char, direction: (i32, i32)) -> bool {
+        let slope_direction = match from {
+            '^' => (-1, 0),
+            '>' => (0, 1),
+            'v' => (1, 0),
+            '<' => (0, -1),
+            _ => direction,
+        };
+
+        *to != '#' && direction == slope_direction
+    }
+
+    #[test]
+    fn test_longest_path_grid() {
+        let grid: Vec<Vec<char>> = TRAILS.iter().map(|line| line.chars().collect()).collect();
+
+        assert_eq!(Some(94), longest_path_grid(&grid, (0, 1), (22, 21), slopes));
+        assert_eq!(Some(154), longest_path_grid(&grid, (0, 1), (22, 21), |_, &to, _| to != '#'));
+    }
+
+    #[test]
+    fn test_longest_path_grid_unreachable() {
+        let grid: Vec<Vec<char>> = vec!["..#..".chars().collect()];
+
+        assert_eq!(None, longest_path_grid(&grid, (0, 0), (0, 4), |_, &to, _| to != '#'));
+    }
+
+    #[test]
+    fn test_longest_path_dag_rejects_cycles() {
+        let mut graph = Graph::new_directed();
+        graph.add_edge(1, 2, 1);
+        graph.add_edge(2, 1, 1);
+
+        assert_eq!(None, longest_path_dag(&graph));
+
+        let mut graph = Graph::new_undirected();
+        graph.add_edge(1, 2, 1);
+
+        assert_eq!(None, longest_path_dag(&graph));
+    }
+}
@@ -0,0 +1,227 @@
+//! Graphs represented as adjacency lists.
+//!
+//! Nodes are identified by a label, like a name or coordinates,
+//! and internally by an index, in the order they've been added.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::collections::DisjointSet;
+use crate::pathfinding::dijkstra;
+
+mod coloring;
+mod cut;
+mod longest_path;
+
+pub use coloring::two_color;
+pub use cut::{find_edge_cut, find_three_edge_cut};
+pub use longest_path::{longest_path_dag, longest_path_grid};
+
+/// A graph with nodes labelled by N and edges weighted by E,
+/// stored as an adjacency list.
+#[derive(Debug, Clone)]
+pub struct Graph<N, E> {
+    labels: Vec<N>,
+    indices: HashMap<N, usize>,
+    adjacency: Vec<Vec<(usize, E)>>,
+    directed: bool,
+}
+
+impl<N, E> Graph<N, E> where N: Eq + Hash + Clone {
+    /// Creates an empty graph where edges have a direction.
+    pub fn new_directed() -> Self {
+        Self::new(true)
+    }
+
+    /// Creates an empty graph where edges can be followed both ways.
+    pub fn new_undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            labels: Vec::new(),
+            indices: HashMap::new(),
+            adjacency: Vec::new(),
+            directed,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Gets the number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Gets the number of edges. An undirected edge is counted once.
+    pub fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+
+    /// Adds a node if it doesn't exist yet, and returns its index.
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(&index) = self.indices.get(&label) {
+            return index;
+        }
+
+        let index = self.labels.len();
+        self.indices.insert(label.clone(), index);
+        self.labels.push(label);
+        self.adjacency.push(Vec::new());
+
+        index
+    }
+
+    /// Adds an edge between two nodes, adding the nodes if needed.
+    ///
+    /// For an undirected graph, the edge can be followed both ways.
+    pub fn add_edge(&mut self, from: N, to: N, weight: E) where E: Clone {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight.clone()));
+        }
+        self.adjacency[from].push((to, weight));
+    }
+
+    /// Gets the index of the node with the specified label.
+    pub fn node_index(&self, label: &N) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+
+    /// Gets the label of the node at the specified index.
+    pub fn label(&self, index: usize) -> &N {
+        &self.labels[index]
+    }
+
+    /// Iterates over the nodes, yielding their index and label.
+    pub fn nodes(&self) -> impl Iterator<Item = (usize, &N)> {
+        self.labels.iter().enumerate()
+    }
+
+    /// Iterates over the neighbors of a node, yielding their index and the edge weight.
+    pub fn neighbors(&self, index: usize) -> impl Iterator<Item = (usize, &E)> {
+        self.adjacency[index].iter().map(|(to, weight)| (*to, weight))
+    }
+
+    /// Iterates over the labels of the neighbors of a node.
+    pub fn neighbors_by_label(&self, label: &N) -> impl Iterator<Item = &N> {
+        self.node_index(label)
+            .into_iter()
+            .flat_map(move |index| self.neighbors(index))
+            .map(move |(to, _)| self.label(to))
+    }
+
+    /// Iterates over the edges, yielding the index of both nodes and the weight.
+    ///
+    /// For an undirected graph, each edge is yielded once.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, &E)> {
+        self.adjacency.iter()
+            .enumerate()
+            .flat_map(|(from, neighbors)| neighbors.iter().map(move |(to, weight)| (from, *to, weight)))
+            .filter(|&(from, to, _)| self.directed || from <= to)
+    }
+
+    /// Gets the connected components, as lists of node indices.
+    ///
+    /// Edges direction is ignored, so for a directed graph,
+    /// those are the weakly connected components.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut set = DisjointSet::new(self.len());
+
+        for (from, to, _) in self.edges() {
+            set.union(from, to);
+        }
+
+        set.sets()
+    }
+
+    /// Finds the shortest path between two nodes, using the edges weights as costs.
+    ///
+    /// Returns the labels of the nodes along the path and its total cost.
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<(Vec<N>, E)>
+    where E: Copy + Ord + Add<Output = E> + Default {
+        let start = self.node_index(from)?;
+        let goal = self.node_index(to)?;
+
+        let (path, cost) = dijkstra(
+            start,
+            |&index| self.neighbors(index).map(|(to, &weight)| (to, weight)),
+            |&index| index == goal,
+        )?;
+
+        Some((path.into_iter().map(|index| self.label(index).clone()).collect(), cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed_graph() {
+        let mut graph = Graph::new_directed();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "c", 2);
+
+        assert_eq!(3, graph.len());
+        assert_eq!(2, graph.edge_count());
+        assert_eq!(vec![&"b"], graph.neighbors_by_label(&"a").collect::<Vec<_>>());
+        assert_eq!(0, graph.neighbors_by_label(&"c").count());
+        assert_eq!(None, graph.shortest_path(&"c", &"a"));
+    }
+
+    #[test]
+    fn test_undirected_graph() {
+        let mut graph = Graph::new_undirected();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "c", 2);
+
+        assert_eq!(2, graph.edge_count());
+        assert_eq!(vec![&"a", &"c"], graph.neighbors_by_label(&"b").collect::<Vec<_>>());
+        assert_eq!(Some((vec!["c", "b", "a"], 3)), graph.shortest_path(&"c", &"a"));
+    }
+
+    #[test]
+    fn test_node_lookup() {
+        let mut graph: Graph<&str, u32> = Graph::new_directed();
+        let index = graph.add_node("a");
+
+        assert_eq!(index, graph.add_node("a"));
+        assert_eq!(Some(index), graph.node_index(&"a"));
+        assert_eq!(None, graph.node_index(&"z"));
+        assert_eq!(&"a", graph.label(index));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = Graph::new_directed();
+        graph.add_edge(0, 2, ());
+        graph.add_edge(1, 3, ());
+        graph.add_edge(4, 1, ());
+        graph.add_node(5);
+
+        // Node indices follow insertion order: 0, 2, 1, 3, 4, 5
+        assert_eq!(vec![vec![0, 1], vec![2, 3, 4], vec![5]], graph.connected_components());
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = Graph::new_undirected();
+        for (from, to, weight) in [("London", "Dublin", 464), ("London", "Belfast", 518), ("Dublin", "Belfast", 141)] {
+            graph.add_edge(from, to, weight);
+        }
+
+        assert_eq!(Some((vec!["London", "Belfast"], 518)), graph.shortest_path(&"London", &"Belfast"));
+        assert_eq!(Some((vec!["Dublin", "Belfast"], 141)), graph.shortest_path(&"Dublin", &"Belfast"));
+    }
+}
@@ -1,4 +1,13 @@
+pub mod algorithms;
 pub mod parser;
+pub mod bits;
 pub mod collections;
+pub mod games;
+pub mod geometry;
+pub mod graph;
 pub mod math;
 pub mod ops;
+pub mod pathfinding;
+pub mod simulation;
+pub mod strings;
+pub mod vm;
@@ -0,0 +1,201 @@
+/// Iterator over the weak compositions of a total into a number of parts,
+/// ie every vector of <parts> nonnegative integers summing to <total>.
+///
+/// Compositions are yielded in lexicographic order.
+pub struct Compositions {
+    current: Vec<u32>,
+    total: u32,
+    free_sum: u32,
+    done: bool,
+}
+
+/// Gets an iterator over all vectors of <parts> nonnegative integers summing to <total>.
+///
+/// ```
+/// use enontekio::math::combinatorics::compositions;
+///
+/// let expected = vec![vec![0, 2], vec![1, 1], vec![2, 0]];
+/// assert_eq!(expected, compositions(2, 2).collect::<Vec<_>>());
+/// ```
+pub fn compositions(total: u32, parts: usize) -> Compositions {
+    let mut current = vec![0; parts];
+    if let Some(last) = current.last_mut() {
+        *last = total;
+    }
+
+    Compositions {
+        current,
+        total,
+        free_sum: 0,
+        done: parts == 0 && total > 0,
+    }
+}
+
+impl Compositions {
+    fn advance(&mut self) {
+        let free_parts = self.current.len().saturating_sub(1);
+        if free_parts == 0 {
+            self.done = true;
+            return;
+        }
+
+        let mut i = free_parts - 1;
+        loop {
+            if self.free_sum < self.total {
+                self.current[i] += 1;
+                self.free_sum += 1;
+                break;
+            }
+
+            self.free_sum -= self.current[i];
+            self.current[i] = 0;
+
+            if i == 0 {
+                self.done = true;
+                return;
+            }
+            i -= 1;
+        }
+
+        self.current[free_parts] = self.total - self.free_sum;
+    }
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let composition = self.current.clone();
+        self.advance();
+
+        Some(composition)
+    }
+}
+
+/// Finds the amounts of each ingredient maximizing the product of the property sums.
+///
+/// Each ingredient is described by a vector of property values. For a given
+/// composition of <total> into amounts, each property sums the amount of each
+/// ingredient multiplied by its value; negative sums count as 0.
+/// The score is the product of those sums.
+///
+/// Only compositions satisfying the constraint callback are considered,
+/// for example to require an exact calories count.
+///
+/// Returns the best score and the amounts used to reach it,
+/// or None if no composition satisfies the constraint.
+pub fn maximize_product_of_sums<F>(ingredients: &[Vec<i64>], total: u32, constraint: F) -> Option<(i64, Vec<u32>)>
+where F: Fn(&[u32]) -> bool {
+    let properties_count = ingredients.iter().map(|properties| properties.len()).max().unwrap_or(0);
+
+    compositions(total, ingredients.len())
+        .filter(|amounts| constraint(amounts))
+        .map(|amounts| {
+            let score = (0..properties_count)
+                .map(|property| {
+                    ingredients.iter()
+                        .zip(amounts.iter())
+                        .map(|(properties, &amount)| properties.get(property).unwrap_or(&0) * amount as i64)
+                        .sum::<i64>()
+                        .max(0)
+                })
+                .product();
+
+            (score, amounts)
+        })
+        .max_by_key(|(score, _)| *score)
+}
+
+/// Gets the binomial coefficient C(n, k), ie the number of ways
+/// to choose k elements among n.
+///
+/// ```
+/// use enontekio::math::combinatorics::binomial;
+///
+/// assert_eq!(10, binomial(5, 2));
+/// assert_eq!(0, binomial(2, 5));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the coefficient doesn't fit in a u64.
+pub fn binomial(n: u64, k: u64) -> u64 {
+    checked_binomial(n, k).expect("binomial coefficient overflow")
+}
+
+/// Gets the binomial coefficient C(n, k), or None if it doesn't fit in a u64.
+///
+/// ```
+/// use enontekio::math::combinatorics::checked_binomial;
+///
+/// assert_eq!(Some(10), checked_binomial(5, 2));
+/// assert_eq!(None, checked_binomial(100, 50));
+/// ```
+pub fn checked_binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+
+    // C(n, i + 1) = C(n, i) * (n - i) / (i + 1) is exact at each step
+    let k = k.min(n - k);
+    let coefficient = (0..k).try_fold(1u128, |coefficient, i| {
+        coefficient.checked_mul((n - i) as u128).map(|product| product / (i + 1) as u128)
+    })?;
+
+    u64::try_from(coefficient).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compositions_count() {
+        // C(103, 3)
+        assert_eq!(176851, compositions(100, 4).count());
+    }
+
+    #[test]
+    fn test_compositions_sum_to_total() {
+        assert!(compositions(10, 3).all(|amounts| amounts.iter().sum::<u32>() == 10));
+    }
+
+    #[test]
+    fn test_compositions_edge_cases() {
+        assert_eq!(vec![vec![5]], compositions(5, 1).collect::<Vec<_>>());
+        assert_eq!(vec![Vec::<u32>::new()], compositions(0, 0).collect::<Vec<_>>());
+        assert_eq!(0, compositions(5, 0).count());
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(1, binomial(0, 0));
+        assert_eq!(1, binomial(7, 7));
+        assert_eq!(176851, binomial(103, 3));
+        assert_eq!(binomial(40, 12), binomial(40, 28));
+        assert_eq!(14_226_520_737_620_288_370, binomial(67, 33));
+        assert_eq!(None, checked_binomial(68, 34));
+        assert_eq!(Some(1), checked_binomial(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_maximize_product_of_sums() {
+        let ingredients = vec![
+            vec![-1, -2, 6, 3],
+            vec![2, 3, -2, -1],
+        ];
+
+        let best = maximize_product_of_sums(&ingredients, 100, |_| true);
+        assert_eq!(Some((62842880, vec![44, 56])), best);
+
+        let calories = [8, 3];
+        let best = maximize_product_of_sums(&ingredients, 100, |amounts| {
+            amounts.iter().zip(calories.iter()).map(|(&amount, &calorie)| amount * calorie).sum::<u32>() == 500
+        });
+        assert_eq!(Some((57600000, vec![40, 60])), best);
+    }
+}
@@ -0,0 +1,127 @@
+//! Cycle detection in sequences of states obtained by iterating a function,
+//! like a simulation step.
+
+/// A cycle in an iterated sequence x0, f(x0), f(f(x0)), ...
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index of the first state belonging to the cycle
+    pub start: usize,
+
+    /// The number of states in the cycle
+    pub length: usize,
+}
+
+/// Detects the cycle of the sequence obtained by iterating step_fn from the initial state,
+/// using Brent's algorithm.
+///
+/// Only a few states are kept in memory at a time, so no hashing is needed.
+/// The sequence must be eventually periodic, or this function won't return.
+///
+/// ```
+/// use enontekio::math::cycle::{detect_cycle, Cycle};
+///
+/// // 0, 1, 2, 3, 4, 2, 3, 4, ...
+/// let cycle = detect_cycle(0, |&n| if n == 4 { 2 } else { n + 1 });
+/// assert_eq!(Cycle { start: 2, length: 3 }, cycle);
+/// ```
+pub fn detect_cycle<S, F>(initial_state: S, step_fn: F) -> Cycle
+where S: Clone + Eq, F: Fn(&S) -> S {
+    // Searches successive powers of two for the cycle length.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial_state.clone();
+    let mut hare = step_fn(&initial_state);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step_fn(&hare);
+        length += 1;
+    }
+
+    // With the hare length steps ahead, both meet at the cycle start.
+    let mut tortoise = initial_state.clone();
+    let mut hare = initial_state;
+    for _ in 0..length {
+        hare = step_fn(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step_fn(&tortoise);
+        hare = step_fn(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Gets the state after n iterations of step_fn from the initial state,
+/// skipping the full turns of the cycle, so n can be huge.
+///
+/// ```
+/// use enontekio::math::cycle::state_after;
+///
+/// let state = state_after(0, |&n| (n + 1) % 7, 1_000_000_000);
+/// assert_eq!(1_000_000_000 % 7, state);
+/// ```
+pub fn state_after<S, F>(initial_state: S, step_fn: F, n: usize) -> S
+where S: Clone + Eq, F: Fn(&S) -> S {
+    let cycle = detect_cycle(initial_state.clone(), &step_fn);
+
+    let iterations = if n < cycle.start {
+        n
+    } else {
+        cycle.start + (n - cycle.start) % cycle.length
+    };
+
+    (0..iterations).fold(initial_state, |state, _| step_fn(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_cycle_pure_cycle() {
+        assert_eq!(Cycle { start: 0, length: 5 }, detect_cycle(0, |&n| (n + 1) % 5));
+        assert_eq!(Cycle { start: 0, length: 1 }, detect_cycle(42, |&n| n));
+    }
+
+    #[test]
+    fn test_detect_cycle_with_tail() {
+        // The memory banks redistribution example: 0 2 7 0
+        let step = |banks: &Vec<u32>| {
+            let mut banks = banks.clone();
+            let max = *banks.iter().max().unwrap();
+            let mut index = banks.iter().position(|&blocks| blocks == max).unwrap();
+            let blocks = banks[index];
+            banks[index] = 0;
+
+            for _ in 0..blocks {
+                index = (index + 1) % banks.len();
+                banks[index] += 1;
+            }
+
+            banks
+        };
+
+        let cycle = detect_cycle(vec![0, 2, 7, 0], step);
+        assert_eq!(5, cycle.start + cycle.length);
+        assert_eq!(4, cycle.length);
+    }
+
+    #[test]
+    fn test_state_after() {
+        let step = |&n: &u32| if n == 4 { 2 } else { n + 1 };
+
+        assert_eq!(1, state_after(0, step, 1));
+        assert_eq!(4, state_after(0, step, 4));
+        assert_eq!(2, state_after(0, step, 5));
+        assert_eq!(4, state_after(0, step, 1_000_000_000));
+    }
+}
@@ -0,0 +1,145 @@
+//! Greatest common divisor and least common multiple,
+//! for any integer type.
+
+use std::ops::Neg;
+
+use num_integer::Integer;
+
+/// Gets the greatest common divisor of a and b, always nonnegative.
+///
+/// ```
+/// use enontekio::math::euclid::gcd;
+///
+/// assert_eq!(6, gcd(12, 18));
+/// assert_eq!(6, gcd(-12, 18));
+/// ```
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// Gets the least common multiple of a and b, always nonnegative.
+///
+/// ```
+/// use enontekio::math::euclid::lcm;
+///
+/// assert_eq!(36, lcm(12, 18));
+/// ```
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a.lcm(&b)
+}
+
+/// Gets the least common multiple of all the numbers.
+///
+/// Returns 1 for an empty slice.
+///
+/// ```
+/// use enontekio::math::euclid::lcm_of_slice;
+///
+/// assert_eq!(60, lcm_of_slice(&[2, 3, 4, 5, 6]));
+/// ```
+pub fn lcm_of_slice<T: Integer + Copy>(numbers: &[T]) -> T {
+    numbers.iter().fold(T::one(), |acc, &n| lcm(acc, n))
+}
+
+/// Runs the extended Euclidean algorithm: gets the greatest common divisor g of a and b,
+/// and Bézout coefficients x and y such as a * x + b * y = g.
+///
+/// The type must be signed, as coefficients can be negative.
+///
+/// ```
+/// use enontekio::math::euclid::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46);
+/// assert_eq!(2, g);
+/// assert_eq!(g, 240 * x + 46 * y);
+/// ```
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T, T) where T: Integer + Copy + Neg<Output = T> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while r != T::zero() {
+        let quotient = old_r / r;
+
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Solves a system of congruences x ≡ residue (mod modulus), given as
+/// (residue, modulus) pairs, with the Chinese remainder theorem.
+///
+/// Moduli don't need to be coprime. Returns the smallest nonnegative
+/// solution and the lcm of the moduli, or None if the congruences are
+/// incompatible.
+///
+/// ```
+/// use enontekio::math::euclid::chinese_remainder;
+///
+/// assert_eq!(Some((23, 105)), chinese_remainder(&[(2, 3), (3, 5), (2, 7)]));
+/// assert_eq!(Some((10, 12)), chinese_remainder(&[(4, 6), (2, 4)]));
+/// assert_eq!(None, chinese_remainder(&[(1, 4), (2, 6)]));
+/// ```
+pub fn chinese_remainder<T>(congruences: &[(T, T)]) -> Option<(T, T)> where T: Integer + Copy + Neg<Output = T> {
+    congruences.iter().try_fold((T::zero(), T::one()), |(residue, modulus), &(other_residue, other_modulus)| {
+        let (g, x, _) = extended_gcd(modulus, other_modulus);
+        let difference = other_residue - residue;
+        if !difference.is_multiple_of(&g) {
+            return None;
+        }
+
+        // residue + modulus * k ≡ other_residue (mod other_modulus)
+        let step = other_modulus / g;
+        let k = (difference / g * x).mod_floor(&step);
+        let combined_modulus = modulus * step;
+
+        Some(((residue + modulus * k).mod_floor(&combined_modulus), combined_modulus))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(1, gcd(17u64, 5));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(0, lcm(0, 5));
+        assert_eq!(85, lcm(17u64, 5));
+    }
+
+    #[test]
+    fn test_lcm_of_slice() {
+        assert_eq!(1, lcm_of_slice::<u64>(&[]));
+        assert_eq!(232_792_560, lcm_of_slice(&(1..=20).collect::<Vec<u64>>()));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for &(a, b) in &[(240i64, 46), (46, 240), (-12, 18), (7, 0), (0, 7), (17, 5)] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(gcd(a, b), g);
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(Some((0, 1)), chinese_remainder::<i64>(&[]));
+        assert_eq!(Some((3, 7)), chinese_remainder(&[(-4i64, 7)]));
+
+        let (x, modulus) = chinese_remainder(&[(1_000_000_006i128, 1_000_000_007), (4, 998_244_353)]).unwrap();
+        assert_eq!(1_000_000_006, x % 1_000_000_007);
+        assert_eq!(4, x % 998_244_353);
+        assert_eq!(1_000_000_007 * 998_244_353, modulus);
+    }
+}
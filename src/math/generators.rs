@@ -0,0 +1,104 @@
+//! Multiplicative congruential generators, producing each value by
+//! multiplying the previous one by a factor, modulo a prime,
+//! and a judge comparing the values of two generators.
+
+/// The modulus of the generators, 2^31 - 1
+pub const MODULUS: u64 = 2_147_483_647;
+
+/// The factor of the usual first generator
+pub const FACTOR_A: u64 = 16807;
+
+/// The factor of the usual second generator
+pub const FACTOR_B: u64 = 48271;
+
+/// A generator yielding value * factor % modulus, forever.
+///
+/// The seed isn't yielded.
+///
+/// ```
+/// use enontekio::math::generators::{Generator, FACTOR_A};
+///
+/// let values: Vec<u64> = Generator::new(65, FACTOR_A).take(3).collect();
+/// assert_eq!(vec![1092455, 1181022009, 245556042], values);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Generator {
+    value: u64,
+    factor: u64,
+    modulus: u64,
+}
+
+impl Generator {
+    /// Creates a generator with the usual modulus, 2^31 - 1.
+    pub fn new(seed: u64, factor: u64) -> Self {
+        Self::with_modulus(seed, factor, MODULUS)
+    }
+
+    /// Creates a generator with a custom modulus.
+    ///
+    /// The factor and the modulus must be small enough
+    /// for their product to fit in a u64.
+    pub fn with_modulus(seed: u64, factor: u64, modulus: u64) -> Self {
+        Self { value: seed % modulus, factor, modulus }
+    }
+}
+
+impl Iterator for Generator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.value = self.value * self.factor % self.modulus;
+
+        Some(self.value)
+    }
+}
+
+/// Keeps only the values of a generator which are multiples of a number.
+pub fn filtered<I>(generator: I, multiple_of: u64) -> impl Iterator<Item = u64>
+where I: Iterator<Item = u64> {
+    generator.filter(move |value| value % multiple_of == 0)
+}
+
+/// Counts the pairs where the values of both generators
+/// have the same lowest 16 bits, among the first pairs.
+///
+/// ```
+/// use enontekio::math::generators::{count_low16_matches, filtered, Generator, FACTOR_A, FACTOR_B};
+///
+/// let a = Generator::new(65, FACTOR_A);
+/// let b = Generator::new(8921, FACTOR_B);
+/// assert_eq!(1, count_low16_matches(a, b, 5));
+///
+/// let a = filtered(Generator::new(65, FACTOR_A), 4);
+/// let b = filtered(Generator::new(8921, FACTOR_B), 8);
+/// assert_eq!(1, count_low16_matches(a, b, 1056));
+/// ```
+pub fn count_low16_matches<A, B>(generator_a: A, generator_b: B, pairs: usize) -> usize
+where A: Iterator<Item = u64>, B: Iterator<Item = u64> {
+    generator_a
+        .zip(generator_b)
+        .take(pairs)
+        .filter(|(a, b)| a & 0xffff == b & 0xffff)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filtered() {
+        let values: Vec<u64> = filtered(Generator::new(8921, FACTOR_B), 8).take(2).collect();
+
+        assert_eq!(vec![1233683848, 862516352], values);
+    }
+
+    #[test]
+    fn test_count_low16_matches() {
+        let a = filtered(Generator::new(65, FACTOR_A), 4);
+        let b = filtered(Generator::new(8921, FACTOR_B), 8);
+
+        assert_eq!(0, count_low16_matches(a, b, 1055));
+        assert_eq!(2, count_low16_matches(0.., 65536.., 2));
+    }
+}
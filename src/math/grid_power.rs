@@ -0,0 +1,110 @@
+//! Fuel cells grid of 300x300, where each cell power level depends on
+//! its coordinates and the grid serial number.
+//!
+//! Coordinates are (x, y), from 1 to 300.
+
+use crate::collections::PrefixSum2D;
+
+const GRID_SIZE: usize = 300;
+
+/// A square of fuel cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Square {
+    /// The x coordinate of the top left cell
+    pub x: usize,
+
+    /// The y coordinate of the top left cell
+    pub y: usize,
+
+    pub size: usize,
+
+    /// The total power of the cells in the square
+    pub power: i64,
+}
+
+/// Gets the power level of the fuel cell at (x, y).
+///
+/// The rack ID is x + 10. The power starts at the rack ID times y,
+/// increased by the serial number, then multiplied by the rack ID.
+/// The hundreds digit is kept, minus 5.
+pub fn power_level(x: usize, y: usize, serial: i64) -> i64 {
+    let rack_id = x as i64 + 10;
+    let power = (rack_id * y as i64 + serial) * rack_id;
+
+    (power / 100) % 10 - 5
+}
+
+fn build_sums(serial: i64) -> PrefixSum2D<i64> {
+    let grid: Vec<Vec<i64>> = (1..=GRID_SIZE)
+        .map(|y| (1..=GRID_SIZE).map(|x| power_level(x, y, serial)).collect())
+        .collect();
+
+    PrefixSum2D::new(&grid)
+}
+
+fn best_square_in(sums: &PrefixSum2D<i64>, size: usize) -> Option<Square> {
+    if size == 0 || size > GRID_SIZE {
+        return None;
+    }
+
+    (0..=GRID_SIZE - size)
+        .flat_map(|i| (0..=GRID_SIZE - size).map(move |j| (i, j)))
+        .map(|(i, j)| Square {
+            x: j + 1,
+            y: i + 1,
+            size,
+            power: sums.square_sum(i, j, size),
+        })
+        .max_by_key(|square| square.power)
+}
+
+/// Finds the square of the specified size with the largest total power.
+///
+/// Returns None if the size doesn't fit in the grid.
+///
+/// ```
+/// use enontekio::math::grid_power::best_square;
+///
+/// let square = best_square(18, 3).unwrap();
+/// assert_eq!((33, 45, 29), (square.x, square.y, square.power));
+/// ```
+pub fn best_square(serial: i64, size: usize) -> Option<Square> {
+    best_square_in(&build_sums(serial), size)
+}
+
+/// Finds the square of any size with the largest total power.
+///
+/// The summed-area table is computed once, so each square sum is O(1).
+pub fn best_any_square(serial: i64) -> Square {
+    let sums = build_sums(serial);
+
+    (1..=GRID_SIZE)
+        .filter_map(|size| best_square_in(&sums, size))
+        .max_by_key(|square| square.power)
+        .expect("The grid isn't empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_level() {
+        assert_eq!(4, power_level(3, 5, 8));
+        assert_eq!(-5, power_level(122, 79, 57));
+        assert_eq!(0, power_level(217, 196, 39));
+        assert_eq!(4, power_level(101, 153, 71));
+    }
+
+    #[test]
+    fn test_best_square() {
+        assert_eq!(Some(Square { x: 21, y: 61, size: 3, power: 30 }), best_square(42, 3));
+        assert_eq!(None, best_square(42, 0));
+        assert_eq!(None, best_square(42, 301));
+    }
+
+    #[test]
+    fn test_best_any_square() {
+        assert_eq!(Square { x: 90, y: 269, size: 16, power: 113 }, best_any_square(18));
+    }
+}
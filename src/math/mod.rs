@@ -1 +1,10 @@
+pub mod combinatorics;
+pub mod cycle;
+pub mod euclid;
+pub mod generators;
+pub mod grid_power;
+pub mod mod_int;
 pub mod modular_arithmetic;
+pub mod partial_sums;
+
+pub use combinatorics::{binomial, checked_binomial, compositions, maximize_product_of_sums, Compositions};
@@ -0,0 +1,192 @@
+//! Integers modulo M, with arithmetic operators.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::math::modular_arithmetic::inverse;
+
+/// An integer modulo M, always stored in [0, M).
+///
+/// ```
+/// use enontekio::math::mod_int::ModInt;
+///
+/// type Mod7 = ModInt<7>;
+///
+/// let a = Mod7::new(5);
+/// let b = Mod7::new(4);
+///
+/// assert_eq!(Mod7::new(2), a + b);
+/// assert_eq!(Mod7::new(6), a * b);
+/// assert_eq!(Mod7::new(3), a / b);
+/// assert_eq!(Mod7::new(4), a.pow(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        Self { value: value % M }
+    }
+
+    /// Gets the representative in [0, M).
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Elevates to the specified exponent, by squaring.
+    pub fn pow(self, exponent: u64) -> Self {
+        let mut result = Self::new(1);
+        let mut base = self;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Gets the multiplicative inverse, if the value and M are coprime.
+    pub fn inverse(self) -> Option<Self> {
+        inverse(self.value, M).map(Self::new)
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        // Through i128, as M may not fit in an i64
+        Self::new((value as i128).rem_euclid(M as i128) as u64)
+    }
+}
+
+impl<const M: u64> Display for ModInt<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(((self.value as u128 + other.value as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(((self.value as u128 * other.value as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of the divisor.
+    ///
+    /// Panics if the divisor isn't invertible modulo M.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        let other_inverse = other.inverse()
+            .expect("The divisor should be coprime with the modulus");
+
+        self * other_inverse
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(M - self.value)
+    }
+}
+
+impl<const M: u64> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const M: u64> SubAssign for ModInt<M> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const M: u64> MulAssign for ModInt<M> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Mod = ModInt<1_000_000_007>;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Mod::new(1_000_000_000);
+        let b = Mod::new(10);
+
+        assert_eq!(3, (a + b).value());
+        assert_eq!(999_999_990, (a - b).value());
+        assert_eq!(999_999_937, (a * b).value());
+        assert_eq!(a, (a * b) / b);
+        assert_eq!(Mod::new(0), a + (-a));
+        assert_eq!(Mod::new(0), -Mod::new(0));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Mod::new(1_000_000_006), Mod::from(-1i64));
+        assert_eq!(ModInt::<{ u64::MAX }>::new(u64::MAX - 1), ModInt::<{ u64::MAX }>::from(-1i64));
+        assert_eq!(Mod::new(0), Mod::from(1_000_000_007u64));
+        assert_eq!("42", Mod::new(42).to_string());
+    }
+
+    #[test]
+    fn test_pow_and_inverse() {
+        // Fermat's little theorem
+        let a = Mod::new(123_456_789);
+        assert_eq!(Mod::new(1), a.pow(1_000_000_006));
+        assert_eq!(Some(a.pow(1_000_000_005)), a.inverse());
+
+        assert_eq!(None, ModInt::<9>::new(3).inverse());
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut n = ModInt::<5>::new(3);
+        n += ModInt::new(4);
+        assert_eq!(2, n.value());
+        n *= ModInt::new(4);
+        assert_eq!(3, n.value());
+        n -= ModInt::new(4);
+        assert_eq!(4, n.value());
+    }
+}
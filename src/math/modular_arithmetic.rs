@@ -1,3 +1,5 @@
+use crate::math::euclid::extended_gcd;
+
 /// In modular arithmetic, gets the product of number a and b,
 /// for a specific modulus.
 ///
@@ -42,9 +44,65 @@ pub fn power(n: u64, exponent: u64, modulus: u64) -> u64 {
         if b & 1 != 0 {
             result = multiplication(result, a, modulus);
         }
-        b = b >> 1;
+        b >>= 1;
         a = multiplication(a, a, modulus);
     }
 
     result
 }
+
+/// In modular arithmetic, gets the inverse of a, ie the number x
+/// such as a * x ≡ 1 (mod modulus), for a specific modulus.
+///
+/// The inverse only exists when a and the modulus are coprime,
+/// otherwise None is returned.
+///
+/// Source: https://en.wikipedia.org/wiki/Modular_multiplicative_inverse
+pub fn inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a as i128 % modulus as i128, modulus as i128);
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(modulus as i128) as u64)
+}
+
+/// In modular arithmetic, divides a by b, ie multiplies a by the inverse of b,
+/// for a specific modulus.
+///
+/// Returns None when b has no inverse for this modulus.
+pub fn division(a: u64, b: u64, modulus: u64) -> Option<u64> {
+    inverse(b, modulus).map(|b_inverse| multiplication(a, b_inverse, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(Some(4), inverse(3, 11));
+        assert_eq!(Some(1), inverse(1, 7));
+        assert_eq!(Some(4), inverse(14, 11));
+        assert_eq!(None, inverse(6, 9));
+        assert_eq!(None, inverse(3, 0));
+        assert_eq!(Some(0), inverse(5, 1));
+    }
+
+    #[test]
+    fn test_division() {
+        assert_eq!(Some(5), division(15, 3, 11));
+        assert_eq!(Some(3), division(1, 4, 11));
+        assert_eq!(None, division(1, 3, 9));
+
+        let a = 123_456;
+        let b = 789;
+        let modulus = 1_000_000_007;
+        let quotient = division(a, b, modulus).unwrap();
+        assert_eq!(a, multiplication(quotient, b, modulus));
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+/// Gets the sum of all the deltas, ie the final value after applying them once from 0.
+pub fn final_sum(deltas: &[i64]) -> i64 {
+    deltas.iter().sum()
+}
+
+/// Applies the deltas from 0, repeating the list indefinitely,
+/// and gets the first cumulative sum reached twice.
+///
+/// The initial 0 counts as reached.
+/// Returns None when no sum will ever repeat, for example with [1, 2].
+///
+/// ```
+/// use enontekio::math::partial_sums::first_repeated_partial_sum;
+///
+/// assert_eq!(Some(10), first_repeated_partial_sum(&[3, 3, 4, -2, -4]));
+/// ```
+pub fn first_repeated_partial_sum(deltas: &[i64]) -> Option<i64> {
+    if deltas.is_empty() {
+        return None;
+    }
+
+    // A sum s reached in the first pass is reached again at pass m as s + m * total,
+    // so once the passes covered the range of the first pass sums, nothing can repeat anymore.
+    let total = final_sum(deltas);
+    let (min, max) = deltas.iter()
+        .scan(0, |sum, delta| {
+            *sum += delta;
+            Some(*sum)
+        })
+        .fold((0, 0), |(min, max), sum| (min.min(sum), max.max(sum)));
+    let max_passes = if total == 0 { 1 } else { (max - min) / total.abs() + 2 };
+
+    let mut seen = HashSet::from([0]);
+    let mut sum = 0;
+
+    for delta in deltas.iter().cycle().take(deltas.len() * max_passes as usize) {
+        sum += delta;
+
+        if !seen.insert(sum) {
+            return Some(sum);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_final_sum() {
+        assert_eq!(3, final_sum(&[1, 1, 1]));
+        assert_eq!(-6, final_sum(&[-1, -2, -3]));
+    }
+
+    #[test]
+    fn test_first_repeated_partial_sum() {
+        assert_eq!(Some(0), first_repeated_partial_sum(&[1, -1]));
+        assert_eq!(Some(10), first_repeated_partial_sum(&[3, 3, 4, -2, -4]));
+        assert_eq!(Some(5), first_repeated_partial_sum(&[-6, 3, 8, 5, -6]));
+        assert_eq!(Some(14), first_repeated_partial_sum(&[7, 7, -2, -7, -4]));
+    }
+
+    #[test]
+    fn test_first_repeated_partial_sum_never_repeating() {
+        assert_eq!(None, first_repeated_partial_sum(&[]));
+        assert_eq!(None, first_repeated_partial_sum(&[1, 2]));
+        assert_eq!(None, first_repeated_partial_sum(&[-3]));
+    }
+}
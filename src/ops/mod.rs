@@ -1 +1,4 @@
+pub mod nd_range;
+pub mod range_map;
+pub mod range_set;
 pub mod ranges;
@@ -0,0 +1,233 @@
+//! Axis-aligned boxes in an integer space of any dimension.
+//!
+//! A Cuboid is the 3D case, and delegates its operations to NdRange.
+
+use std::ops::RangeInclusive;
+
+use num_integer::Integer;
+
+use crate::geometry::cuboid::Cuboid;
+use crate::ops::ranges::{Intersect, IntersectionDescription, RangeValue};
+
+/// An axis-aligned box in N dimensions, described by an inclusive range on each axis
+///
+/// ```
+/// use enontekio::ops::nd_range::NdRange;
+///
+/// let reactor = NdRange::new([-50..=50, -50..=50, -50..=50]);
+/// let step = NdRange::new([10..=12, 10..=12, 10..=12]);
+///
+/// assert!(step.is_within(&reactor));
+/// assert_eq!(27, step.volume());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NdRange<T, const N: usize> {
+    pub axes: [RangeInclusive<T>; N],
+}
+
+impl<T: Integer + Copy, const N: usize> NdRange<T, N> {
+    pub fn new(axes: [RangeInclusive<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    /// Checks if the box contains no point, ie if a range is reversed.
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|axis| axis.is_empty())
+    }
+
+    /// Gets the number of integer points in the box.
+    pub fn volume(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+
+        self.axes.iter().fold(T::one(), |volume, axis| volume * length(axis))
+    }
+
+    /// Checks if the box contains the specified point.
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(axis, value)| axis.contains(&value))
+    }
+
+    /// Checks if every point of the box belongs to another one.
+    ///
+    /// An empty box is within any box.
+    pub fn is_within(&self, other: &Self) -> bool {
+        self.is_empty() || self.axes.iter().zip(&other.axes).all(|(axis, other_axis)| {
+            other_axis.start() <= axis.start() && axis.end() <= other_axis.end()
+        })
+    }
+
+    /// Checks if the box shares at least a point with another one.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Gets the box shared with another one, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes.clone();
+        for (axis, other_axis) in axes.iter_mut().zip(&other.axes) {
+            *axis = intersect(axis, other_axis);
+        }
+
+        let intersection = Self::new(axes);
+        if intersection.is_empty() {
+            None
+        } else {
+            Some(intersection)
+        }
+    }
+
+    /// Removes another box from this one.
+    ///
+    /// Returns up to 2N disjoint boxes covering the remaining points:
+    /// for each axis in turn, the slabs below and above the common part,
+    /// within the common ranges of the previous axes.
+    ///
+    /// ```
+    /// use enontekio::ops::nd_range::NdRange;
+    ///
+    /// let square = NdRange::new([0..=2, 0..=2]);
+    /// let pieces = square.subtract(&NdRange::new([1..=1, 1..=5]));
+    ///
+    /// assert_eq!(vec![NdRange::new([0..=0, 0..=2]), NdRange::new([2..=2, 0..=2]), NdRange::new([1..=1, 0..=0])], pieces);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let common = match self.intersection(other) {
+            None => return if self.is_empty() { vec![] } else { vec![self.clone()] },
+            Some(common) => common,
+        };
+
+        let one = T::one();
+        let mut pieces = Vec::new();
+        let mut remaining = self.clone();
+
+        for axis in 0..N {
+            let (start, end) = (*self.axes[axis].start(), *self.axes[axis].end());
+            let (common_start, common_end) = (*common.axes[axis].start(), *common.axes[axis].end());
+
+            if start < common_start {
+                let mut piece = remaining.clone();
+                piece.axes[axis] = start..=common_start - one;
+                pieces.push(piece);
+            }
+            if common_end < end {
+                let mut piece = remaining.clone();
+                piece.axes[axis] = common_end + one..=end;
+                pieces.push(piece);
+            }
+
+            remaining.axes[axis] = common.axes[axis].clone();
+        }
+
+        pieces
+    }
+}
+
+impl<T: Integer + Copy + RangeValue, const N: usize> NdRange<T, N> {
+    /// Describes the intersection with another box, axis by axis,
+    /// like Intersect does for two ranges.
+    ///
+    /// The boxes intersect if they intersect on every axis,
+    /// and a box is within another one if it's within on every axis.
+    ///
+    /// ```
+    /// use enontekio::ops::nd_range::NdRange;
+    /// use enontekio::ops::ranges::IntersectionDescription;
+    ///
+    /// let a = NdRange::new([0..=5, 2..=3]);
+    /// let b = NdRange::new([3..=8, 0..=9]);
+    ///
+    /// assert_eq!([IntersectionDescription::BelowOverlap, IntersectionDescription::Within], a.describe_intersection(&b));
+    /// ```
+    pub fn describe_intersection(&self, other: &Self) -> [IntersectionDescription; N] {
+        std::array::from_fn(|axis| self.axes[axis].describe_intersection(&other.axes[axis]))
+    }
+}
+
+impl<T> From<Cuboid<T>> for NdRange<T, 3> {
+    fn from(cuboid: Cuboid<T>) -> Self {
+        Self { axes: [cuboid.x, cuboid.y, cuboid.z] }
+    }
+}
+
+impl<T> From<NdRange<T, 3>> for Cuboid<T> {
+    fn from(range: NdRange<T, 3>) -> Self {
+        let [x, y, z] = range.axes;
+
+        Self { x, y, z }
+    }
+}
+
+fn length<T: Integer + Copy>(range: &RangeInclusive<T>) -> T {
+    *range.end() - *range.start() + T::one()
+}
+
+fn intersect<T: Integer + Copy>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> RangeInclusive<T> {
+    *a.start().max(b.start())..=*a.end().min(b.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersection() {
+        let a = NdRange::new([0..=5, 0..=5]);
+
+        assert_eq!(Some(NdRange::new([3..=5, 0..=1])), a.intersection(&NdRange::new([3..=8, -2..=1])));
+        assert!(!a.intersects(&NdRange::new([6..=8, 0..=5])));
+        assert!(a.contains([5, 0]));
+        assert!(!a.contains([5, 6]));
+    }
+
+    #[test]
+    fn test_subtract_matches_cuboid() {
+        let a = NdRange::new([0..=9, 0..=9, 0..=9, 0..=1]);
+        let b = NdRange::new([5..=14, -3..=2, 4..=4, 1..=1]);
+
+        let pieces = a.subtract(&b);
+
+        assert_eq!(2000 - 5 * 3, pieces.iter().map(|piece| piece.volume()).sum::<i32>());
+        for (i, piece) in pieces.iter().enumerate() {
+            assert!(!piece.intersects(&b));
+            assert!(piece.is_within(&a));
+            assert!(pieces[i + 1..].iter().all(|other| !piece.intersects(other)));
+        }
+
+        let cuboid = Cuboid::new(0..=9, 0..=9, 0..=9);
+        let hole = Cuboid::new(5..=14, -3..=2, 4..=4);
+        let expected: Vec<NdRange<i32, 3>> = cuboid.subtract(&hole).into_iter().map(NdRange::from).collect();
+        assert_eq!(expected, NdRange::from(cuboid).subtract(&NdRange::from(hole)));
+    }
+
+    #[test]
+    fn test_describe_intersection_matches_box_operations() {
+        let a = NdRange::new([0..=5, 0..=5, 0..=5]);
+        let others = [
+            NdRange::new([3..=8, -2..=1, 5..=9]),
+            NdRange::new([6..=8, 0..=5, 0..=5]),
+            NdRange::new([-1..=6, 0..=5, 0..=9]),
+            NdRange::new([1..=2, 1..=2, 1..=2]),
+        ];
+
+        for other in others {
+            let axes = a.describe_intersection(&other);
+
+            assert_eq!(a.intersects(&other), axes.iter().all(|axis| axis.is_any()));
+            assert_eq!(a.is_within(&other), axes.iter().all(|axis| axis.is_within()));
+            assert_eq!(other.is_within(&a), axes.iter().all(|axis| axis.is_over()));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_empty_range() {
+        let empty = NdRange::new([3..=2, 0..=5]);
+
+        assert!(empty.is_empty());
+        assert_eq!(0, empty.volume());
+        assert!(empty.is_within(&NdRange::new([0..=0, 0..=0])));
+        assert!(empty.subtract(&NdRange::new([0..=0, 0..=0])).is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+use std::ops::Range;
+
+use num_integer::Integer;
+
+use crate::ops::range_set::RangeSet;
+
+/// A map associating values to sorted disjoint ranges of keys.
+///
+/// Inserting a range replaces the values of the keys it covers:
+/// the overlapped entries are shortened or split around it.
+///
+/// ```
+/// use enontekio::ops::range_map::RangeMap;
+///
+/// let mut map = RangeMap::new();
+/// map.insert(0..10, 'a');
+/// map.insert(3..5, 'b');
+///
+/// assert_eq!(Some(&'a'), map.get(2));
+/// assert_eq!(Some(&'b'), map.get(3));
+/// assert_eq!(Some(&'a'), map.get(5));
+/// assert_eq!(None, map.get(10));
+/// assert_eq!(3, map.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<K, V> {
+    entries: Vec<(Range<K>, V)>,
+}
+
+impl<K, V> Default for RangeMap<K, V> {
+    fn default() -> Self {
+        Self { entries: Vec::new() }
+    }
+}
+
+impl<K: Ord + Copy, V: Clone> RangeMap<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Associates a value to every key of a range.
+    pub fn insert(&mut self, range: Range<K>, value: V) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.entries.partition_point(|(other, _)| other.end <= range.start);
+        let last = self.entries.partition_point(|(other, _)| other.start < range.end);
+
+        let mut replacement = Vec::with_capacity(3);
+        if first < last {
+            let (left, left_value) = &self.entries[first];
+            if left.start < range.start {
+                replacement.push((left.start..range.start, left_value.clone()));
+            }
+        }
+
+        let right = if first < last {
+            let (right, right_value) = &self.entries[last - 1];
+            (right.end > range.end).then(|| (range.end..right.end, right_value.clone()))
+        } else {
+            None
+        };
+
+        replacement.push((range, value));
+        replacement.extend(right);
+
+        self.entries.splice(first..last, replacement);
+    }
+
+    /// Gets the value associated to a key.
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// Gets the entry containing a key, as its range and value.
+    pub fn get_entry(&self, key: K) -> Option<(&Range<K>, &V)> {
+        let index = self.entries.partition_point(|(range, _)| range.end <= key);
+
+        self.entries.get(index)
+            .filter(|(range, _)| range.start <= key)
+            .map(|(range, value)| (range, value))
+    }
+
+    /// Gets the sorted entries of the map.
+    pub fn entries(&self) -> &[(Range<K>, V)] {
+        &self.entries
+    }
+
+    /// Gets the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Splits a range at the boundaries of the entries,
+    /// each part with the value associated to its keys, or None in a gap.
+    ///
+    /// ```
+    /// use enontekio::ops::range_map::RangeMap;
+    ///
+    /// let map: RangeMap<i32, char> = vec![(0..5, 'a'), (8..10, 'b')].into_iter().collect();
+    ///
+    /// assert_eq!(
+    ///     vec![(3..5, Some(&'a')), (5..8, None), (8..9, Some(&'b'))],
+    ///     map.segments(3..9),
+    /// );
+    /// ```
+    pub fn segments(&self, range: Range<K>) -> Vec<(Range<K>, Option<&V>)> {
+        let mut segments = Vec::new();
+        if range.is_empty() {
+            return segments;
+        }
+
+        let first = self.entries.partition_point(|(other, _)| other.end <= range.start);
+        let mut cursor = range.start;
+
+        for (other, value) in self.entries[first..].iter().take_while(|(other, _)| other.start < range.end) {
+            if other.start > cursor {
+                segments.push((cursor..other.start, None));
+            }
+
+            let end = other.end.min(range.end);
+            segments.push((cursor.max(other.start)..end, Some(value)));
+            cursor = end;
+        }
+
+        if cursor < range.end {
+            segments.push((cursor..range.end, None));
+        }
+
+        segments
+    }
+}
+
+impl<K: Integer + Copy> RangeMap<K, K> {
+    /// Translates a range, each key being shifted by the offset associated to it.
+    /// Keys without offset are kept as is.
+    ///
+    /// The translated ranges are given in the order of the source keys.
+    ///
+    /// ```
+    /// use enontekio::ops::range_map::RangeMap;
+    ///
+    /// // Seeds 98..100 go to 50..52, seeds 50..98 go to 52..100
+    /// let map: RangeMap<i64, i64> = vec![(98..100, -48), (50..98, 2)].into_iter().collect();
+    ///
+    /// assert_eq!(vec![45..50, 52..57], map.translate(45..55));
+    /// assert_eq!(vec![99..100, 50..52, 100..101], map.translate(97..101));
+    /// ```
+    pub fn translate(&self, range: Range<K>) -> Vec<Range<K>> {
+        self.segments(range)
+            .into_iter()
+            .map(|(segment, offset)| match offset {
+                Some(&offset) => segment.start + offset..segment.end + offset,
+                None => segment,
+            })
+            .collect()
+    }
+
+    /// Translates every range of a set, merging the translated ranges.
+    ///
+    /// Maps can so be chained, like seed to soil to fertilizer.
+    pub fn translate_set(&self, set: &RangeSet<K>) -> RangeSet<K> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.translate(range.clone()))
+            .collect()
+    }
+}
+
+impl<K: Ord + Copy, V: Clone> FromIterator<(Range<K>, V)> for RangeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_splits_overlapped_entries() {
+        let map: RangeMap<i32, u8> = vec![(0..4, 1), (4..8, 2), (8..12, 3), (2..10, 4), (20..20, 5)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(&[(0..2, 1), (2..10, 4), (10..12, 3)], map.entries());
+
+        let map: RangeMap<i32, u8> = vec![(2..4, 1), (0..10, 2)].into_iter().collect();
+        assert_eq!(&[(0..10, 2)], map.entries());
+    }
+
+    #[test]
+    fn test_get_entry() {
+        let map: RangeMap<i32, u8> = vec![(0..3, 1), (5..7, 2)].into_iter().collect();
+
+        assert_eq!(Some((&(5..7), &2)), map.get_entry(6));
+        assert_eq!(None, map.get(3));
+        assert_eq!(None, map.get(-1));
+        assert_eq!(None, map.get(7));
+    }
+
+    #[test]
+    fn test_translate_set() {
+        let soil: RangeMap<i64, i64> = vec![(98..100, -48), (50..98, 2)].into_iter().collect();
+        let fertilizer: RangeMap<i64, i64> = vec![(15..52, -15), (52..54, -15), (0..15, 39)].into_iter().collect();
+        let seeds: RangeSet<i64> = vec![79..93, 55..68].into_iter().collect();
+
+        let translated = fertilizer.translate_set(&soil.translate_set(&seeds));
+
+        assert_eq!(&[57..70, 81..95], translated.ranges());
+    }
+}
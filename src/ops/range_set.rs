@@ -0,0 +1,196 @@
+use std::ops::Range;
+
+use num_integer::Integer;
+
+/// A set of values stored as sorted disjoint ranges.
+///
+/// Inserted ranges are merged when they overlap or touch,
+/// so 0..3 and 3..5 are stored as 0..5.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Adds the values of a range to the set.
+    ///
+    /// ```
+    /// use enontekio::ops::range_set::RangeSet;
+    ///
+    /// let mut set = RangeSet::new();
+    /// set.insert(5..8);
+    /// set.insert(0..3);
+    /// set.insert(2..5);
+    ///
+    /// assert_eq!(&[0..8], set.ranges());
+    /// ```
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self.ranges.partition_point(|other| other.start <= range.end);
+
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Gets the sorted disjoint ranges of the set.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Gets the number of disjoint ranges.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Checks if a value belongs to one of the ranges.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges.get(index).is_some_and(|range| range.start <= value)
+    }
+
+    /// Gets the parts of the bounds not covered by the set, in order.
+    ///
+    /// ```
+    /// use enontekio::ops::range_set::RangeSet;
+    ///
+    /// let set: RangeSet<i32> = vec![0..10, 15..20].into_iter().collect();
+    ///
+    /// assert_eq!(vec![10..15, 20..25], set.gaps_within(5..25));
+    /// assert!(set.gaps_within(2..8).is_empty());
+    /// ```
+    pub fn gaps_within(&self, bounds: Range<T>) -> Vec<Range<T>> {
+        let mut gaps = Vec::new();
+        if bounds.is_empty() {
+            return gaps;
+        }
+
+        let first = self.ranges.partition_point(|range| range.end <= bounds.start);
+        let mut cursor = bounds.start;
+
+        for range in self.ranges[first..].iter().take_while(|range| range.start < bounds.end) {
+            if range.start > cursor {
+                gaps.push(cursor..range.start);
+            }
+            cursor = cursor.max(range.end);
+        }
+
+        if cursor < bounds.end {
+            gaps.push(cursor..bounds.end);
+        }
+
+        gaps
+    }
+
+    /// Gets the first value of the bounds not covered by the set.
+    ///
+    /// ```
+    /// use enontekio::ops::range_set::RangeSet;
+    ///
+    /// let set: RangeSet<i32> = vec![0..10, 11..20].into_iter().collect();
+    ///
+    /// assert_eq!(Some(10), set.first_uncovered_point(0..20));
+    /// assert_eq!(None, set.first_uncovered_point(12..20));
+    /// ```
+    pub fn first_uncovered_point(&self, bounds: Range<T>) -> Option<T> {
+        let index = self.ranges.partition_point(|range| range.end <= bounds.start);
+
+        // Ranges are disjoint and don't touch, so the end of the range
+        // containing the start of the bounds is never covered.
+        let point = match self.ranges.get(index) {
+            Some(range) if range.start <= bounds.start => range.end,
+            _ => bounds.start,
+        };
+
+        (point < bounds.end).then_some(point)
+    }
+}
+
+impl<T: Integer + Copy> RangeSet<T> {
+    /// Gets the number of values covered by the set.
+    ///
+    /// ```
+    /// use enontekio::ops::range_set::RangeSet;
+    ///
+    /// let set: RangeSet<i64> = vec![-5..3, 0..5, 8..10].into_iter().collect();
+    ///
+    /// assert_eq!(12, set.total_covered());
+    /// ```
+    pub fn total_covered(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |total, range| total + range.end - range.start)
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_merges_ranges() {
+        let set: RangeSet<i32> = vec![10..12, 0..2, 4..6, 1..5, 12..13, 20..20].into_iter().collect();
+
+        assert_eq!(&[0..6, 10..13], set.ranges());
+    }
+
+    #[test]
+    fn test_contains() {
+        let set: RangeSet<i32> = vec![0..3, 5..7].into_iter().collect();
+
+        assert!(set.contains(0));
+        assert!(set.contains(6));
+        assert!(!set.contains(3));
+        assert!(!set.contains(7));
+        assert!(!set.contains(-1));
+    }
+
+    #[test]
+    fn test_gaps_within() {
+        let set: RangeSet<i32> = vec![0..3, 5..7].into_iter().collect();
+
+        assert_eq!(vec![-2..0, 3..5, 7..9], set.gaps_within(-2..9));
+        assert_eq!(vec![3..4], set.gaps_within(3..4));
+        assert!(set.gaps_within(4..4).is_empty());
+        assert_eq!(vec![0..5], RangeSet::new().gaps_within(0..5));
+    }
+
+    #[test]
+    fn test_first_uncovered_point() {
+        let set: RangeSet<i32> = vec![0..3, 5..7].into_iter().collect();
+
+        assert_eq!(Some(-1), set.first_uncovered_point(-1..3));
+        assert_eq!(Some(3), set.first_uncovered_point(1..10));
+        assert_eq!(Some(4), set.first_uncovered_point(4..10));
+        assert_eq!(None, set.first_uncovered_point(5..7));
+        assert_eq!(0, RangeSet::<i32>::new().total_covered());
+    }
+}
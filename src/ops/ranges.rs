@@ -1,7 +1,12 @@
-use std::cmp::PartialOrd;
+use std::cmp::{Ordering, PartialOrd};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 use std::ops::*;
 use num_integer::Integer;
 
+use crate::ops::range_map::RangeMap;
+use crate::ops::range_set::RangeSet;
+
 /// Description of how two ranges intersect
 #[derive(Debug, PartialEq)]
 pub enum IntersectionDescription {
@@ -30,33 +35,85 @@ pub enum IntersectionDescription {
 impl IntersectionDescription {
     /// Tests if there is any intersection
     pub fn is_any(&self) -> bool {
-        match self {
-            IntersectionDescription::Below => false,
-            IntersectionDescription::Above => false,
-            _ => true,
-        }
+        !matches!(self, IntersectionDescription::Below | IntersectionDescription::Above)
     }
 
     /// Tests if the range is fully within the other
     pub fn is_within(&self) -> bool {
-        match self {
-            IntersectionDescription::Within | IntersectionDescription::Same => true,
-            _ => false,
-        }
+        matches!(self, IntersectionDescription::Within | IntersectionDescription::Same)
     }
 
     /// Tests if the range is fully over the other
     pub fn is_over(&self) -> bool {
+        matches!(self, IntersectionDescription::Over | IntersectionDescription::Same)
+    }
+}
+
+/// Error returned when a range to compare contains no value,
+/// like 5..5 or the reversed 9..=7
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidRange {
+    /// The self range is empty
+    EmptySelf,
+
+    /// The other range is empty
+    EmptyOther,
+}
+
+impl Display for InvalidRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            IntersectionDescription::Over | IntersectionDescription::Same => true,
-            _ => false,
+            InvalidRange::EmptySelf => write!(f, "the range is empty"),
+            InvalidRange::EmptyOther => write!(f, "the other range is empty"),
+        }
+    }
+}
+
+impl Error for InvalidRange {}
+
+/// Checks if a range contains no value, ie if its start isn't below its end.
+///
+/// A range unbounded on at least one side is never empty.
+pub fn is_empty_range<T: PartialOrd, R: RangeBounds<T> + ?Sized>(range: &R) -> bool {
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Included(start), Bound::Included(end)) => {
+            !matches!(start.partial_cmp(end), Some(Ordering::Less | Ordering::Equal))
+        }
+        (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end))
+        | (Bound::Excluded(start), Bound::Included(end)) => {
+            !matches!(start.partial_cmp(end), Some(Ordering::Less))
         }
+        _ => false,
     }
 }
 
 pub trait Intersect<T: PartialOrd, U: RangeBounds<T>>: RangeBounds<T> {
     /// Describes the intersection between two ranges.
+    ///
+    /// The ranges are expected to be non-empty: for an empty or reversed
+    /// range, the description is meaningless. For example, an empty 5..5
+    /// would be described as within 0..10, and 9..=7 as within 0..20.
+    /// Use try_describe_intersection when ranges can be empty.
     fn describe_intersection(&self, other: &U) -> IntersectionDescription;
+
+    /// Describes the intersection between two ranges,
+    /// or fails if one of them is empty.
+    ///
+    /// ```
+    /// use enontekio::ops::ranges::{Intersect, IntersectionDescription, InvalidRange};
+    ///
+    /// assert_eq!(Ok(IntersectionDescription::Within), (3..5).try_describe_intersection(&(0..10)));
+    /// assert_eq!(Err(InvalidRange::EmptySelf), (5..5).try_describe_intersection(&(0..10)));
+    /// ```
+    fn try_describe_intersection(&self, other: &U) -> Result<IntersectionDescription, InvalidRange> {
+        if is_empty_range(self) {
+            Err(InvalidRange::EmptySelf)
+        } else if is_empty_range(other) {
+            Err(InvalidRange::EmptyOther)
+        } else {
+            Ok(self.describe_intersection(other))
+        }
+    }
 }
 
 impl<T: PartialOrd> Intersect<T, Range<T>> for Range<T> {
@@ -119,44 +176,560 @@ impl<T: PartialOrd> Intersect<T, RangeTo<T>> for Range<T> {
     }
 }
 
-impl<T: PartialOrd + Integer + Copy> Intersect<T, Range<T>> for RangeInclusive<T> {
-    fn describe_intersection(&self, other: &Range<T>) -> IntersectionDescription {
-        let end = *self.end() + T::one();
-        (*self.start()..end).describe_intersection(other)
+/// A value bounding a range to intersect.
+///
+/// Discrete types like integers have a successor: an inclusive bound
+/// is then the same as the next value excluded, so 3..=9 and 3..10
+/// are described as the same range. Other ordered types, like floats,
+/// have no successor, and their bounds are compared as they are.
+///
+/// To intersect inclusive ranges of another ordered type,
+/// implement this trait without any method. For an integer type
+/// of another crate, like a big integer, use integer_successor.
+pub trait RangeValue: PartialOrd + Sized {
+    /// Gets the value right after this one, if the type is discrete.
+    fn successor(&self) -> Option<Self> {
+        None
     }
 }
 
-impl<T: PartialOrd + Integer + Copy> Intersect<T, RangeFrom<T>> for RangeInclusive<T> {
-    fn describe_intersection(&self, other: &RangeFrom<T>) -> IntersectionDescription {
-        let end = *self.end() + T::one();
-        (*self.start()..end).describe_intersection(other)
+macro_rules! impl_discrete_range_value {
+    ($($type:ty),+) => {
+        $(
+            impl RangeValue for $type {
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )+
+    };
+}
+
+impl_discrete_range_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl RangeValue for f32 {}
+impl RangeValue for f64 {}
+
+/// Gets the value right after an integer, to implement RangeValue
+/// for the Integer types without an upper bound, like big integers.
+///
+/// ```
+/// use enontekio::ops::ranges::integer_successor;
+///
+/// assert_eq!(Some(10), integer_successor(&9));
+///
+/// // For a big integer type:
+/// // impl RangeValue for BigInt {
+/// //     fn successor(&self) -> Option<Self> {
+/// //         integer_successor(self)
+/// //     }
+/// // }
+/// ```
+pub fn integer_successor<T: Integer + Clone>(value: &T) -> Option<T> {
+    Some(value.clone() + T::one())
+}
+
+/// Describes the intersection between any two ranges, by comparing
+/// their start and end bounds, whatever they are included, excluded or unbounded.
+///
+/// Contrary to the Intersect implementations, bounds are compared
+/// as they are, even for integers: 3..=9 and 3..10 have different ends,
+/// so the first one is described as within the second one.
+///
+/// ```
+/// use std::ops::Bound;
+/// use enontekio::ops::ranges::{describe_intersection_bounds, IntersectionDescription};
+///
+/// assert_eq!(IntersectionDescription::BelowOverlap, describe_intersection_bounds(&(..5.0), &(2.5..)));
+/// assert_eq!(IntersectionDescription::Below, describe_intersection_bounds(&(..=2.0), &(Bound::Excluded(2.0), Bound::Unbounded)));
+/// assert_eq!(IntersectionDescription::Within, describe_intersection_bounds(&(3..=9), &(3..10)));
+/// ```
+pub fn describe_intersection_bounds<T, A, B>(range: &A, other: &B) -> IntersectionDescription
+where T: PartialOrd, A: RangeBounds<T> + ?Sized, B: RangeBounds<T> + ?Sized {
+    describe_bounds(range, other, &|_| None)
+}
+
+/// Describes the intersection between two ranges,
+/// an included bound being the same as its successor excluded.
+fn describe_discrete_bounds<T, A, B>(range: &A, other: &B) -> IntersectionDescription
+where T: RangeValue, A: RangeBounds<T> + ?Sized, B: RangeBounds<T> + ?Sized {
+    describe_bounds(range, other, &T::successor)
+}
+
+fn describe_bounds<T, A, B>(range: &A, other: &B, successor: &dyn Fn(&T) -> Option<T>) -> IntersectionDescription
+where T: PartialOrd, A: RangeBounds<T> + ?Sized, B: RangeBounds<T> + ?Sized {
+    let (start, end) = (range.start_bound(), range.end_bound());
+    let (other_start, other_end) = (other.start_bound(), other.end_bound());
+
+    match compare_end_bounds(end, other_end, successor) {
+        Ordering::Equal => match compare_start_bounds(start, other_start, successor) {
+            Ordering::Less => IntersectionDescription::Over,
+            Ordering::Greater => IntersectionDescription::Within,
+            Ordering::Equal => IntersectionDescription::Same,
+        },
+        Ordering::Less => {
+            if ends_before(end, other_start, successor) {
+                IntersectionDescription::Below
+            } else if compare_start_bounds(start, other_start, successor) == Ordering::Less {
+                IntersectionDescription::BelowOverlap
+            } else {
+                IntersectionDescription::Within
+            }
+        }
+        Ordering::Greater => {
+            if ends_before(other_end, start, successor) {
+                IntersectionDescription::Above
+            } else if compare_start_bounds(other_start, start, successor) == Ordering::Less {
+                IntersectionDescription::AboveOverlap
+            } else {
+                IntersectionDescription::Over
+            }
+        }
     }
 }
 
-impl<T: PartialOrd> Intersect<T, RangeFull> for RangeInclusive<T> {
-    fn describe_intersection(&self, _: &RangeFull) -> IntersectionDescription {
-        IntersectionDescription::Within
+/// Checks if b is the value right after a.
+fn is_successor<T: PartialOrd>(a: &T, b: &T, successor: &dyn Fn(&T) -> Option<T>) -> bool {
+    successor(a).is_some_and(|next| next == *b)
+}
+
+/// Compares two start bounds: unbounded is the lowest,
+/// then a value included starts before the same value excluded.
+fn compare_start_bounds<T: PartialOrd>(a: Bound<&T>, b: Bound<&T>, successor: &dyn Fn(&T) -> Option<T>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(a), Bound::Excluded(b)) if is_successor(b, a, successor) => Ordering::Equal,
+        (Bound::Excluded(a), Bound::Included(b)) if is_successor(a, b, successor) => Ordering::Equal,
+        (Bound::Included(a), Bound::Excluded(b)) if a == b => Ordering::Less,
+        (Bound::Excluded(a), Bound::Included(b)) if a == b => Ordering::Greater,
+        (Bound::Included(a) | Bound::Excluded(a), Bound::Included(b) | Bound::Excluded(b)) => {
+            a.partial_cmp(b).unwrap_or(Ordering::Equal)
+        }
     }
 }
 
-impl<T: PartialOrd + Integer + Copy> Intersect<T, RangeTo<T>> for RangeInclusive<T> {
-    fn describe_intersection(&self, other: &RangeTo<T>) -> IntersectionDescription {
-        let end = *self.end() + T::one();
-        (*self.start()..end).describe_intersection(other)
+/// Compares two end bounds: unbounded is the highest,
+/// then a value included ends after the same value excluded.
+fn compare_end_bounds<T: PartialOrd>(a: Bound<&T>, b: Bound<&T>, successor: &dyn Fn(&T) -> Option<T>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(a), Bound::Excluded(b)) if is_successor(a, b, successor) => Ordering::Equal,
+        (Bound::Excluded(a), Bound::Included(b)) if is_successor(b, a, successor) => Ordering::Equal,
+        (Bound::Included(a), Bound::Excluded(b)) if a == b => Ordering::Greater,
+        (Bound::Excluded(a), Bound::Included(b)) if a == b => Ordering::Less,
+        (Bound::Included(a) | Bound::Excluded(a), Bound::Included(b) | Bound::Excluded(b)) => {
+            a.partial_cmp(b).unwrap_or(Ordering::Equal)
+        }
     }
 }
 
-impl<T: PartialOrd + Copy + Integer> Intersect<T, RangeInclusive<T>> for Range<T> {
-    fn describe_intersection(&self, other: &RangeInclusive<T>) -> IntersectionDescription {
-       let other_end = *other.end() + T::one();
-        self.describe_intersection(&(*other.start()..other_end))
+/// Checks if a range ending at the end bound has no value
+/// in common with a range starting at the start bound.
+fn ends_before<T: PartialOrd>(end: Bound<&T>, start: Bound<&T>, successor: &dyn Fn(&T) -> Option<T>) -> bool {
+    match (end, start) {
+        (Bound::Included(end), Bound::Included(start)) => end < start,
+        (Bound::Excluded(end), Bound::Excluded(start)) => end <= start || is_successor(start, end, successor),
+        (Bound::Included(end) | Bound::Excluded(end), Bound::Included(start) | Bound::Excluded(start)) => end <= start,
+        _ => false,
+    }
+}
+
+/// Implements Intersect by comparing the bounds of both ranges.
+///
+/// Ranges with an inclusive bound need a RangeValue to compare it
+/// with an exclusive bound, like 3..=9 with 3..10.
+macro_rules! impl_intersect_through_bounds {
+    ($self_type:ty, $other_type:ty, PartialOrd) => {
+        impl<T: PartialOrd> Intersect<T, $other_type> for $self_type {
+            fn describe_intersection(&self, other: &$other_type) -> IntersectionDescription {
+                describe_intersection_bounds::<T, _, _>(self, other)
+            }
+        }
+    };
+    ($self_type:ty, $other_type:ty, RangeValue) => {
+        impl<T: RangeValue> Intersect<T, $other_type> for $self_type {
+            fn describe_intersection(&self, other: &$other_type) -> IntersectionDescription {
+                describe_discrete_bounds::<T, _, _>(self, other)
+            }
+        }
+    };
+}
+
+impl_intersect_through_bounds!(Range<T>, RangeInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(Range<T>, RangeToInclusive<T>, RangeValue);
+
+impl_intersect_through_bounds!(RangeInclusive<T>, Range<T>, RangeValue);
+impl_intersect_through_bounds!(RangeInclusive<T>, RangeInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeInclusive<T>, RangeFrom<T>, RangeValue);
+impl_intersect_through_bounds!(RangeInclusive<T>, RangeTo<T>, RangeValue);
+impl_intersect_through_bounds!(RangeInclusive<T>, RangeToInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeInclusive<T>, RangeFull, PartialOrd);
+
+impl_intersect_through_bounds!(RangeFrom<T>, Range<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeFrom<T>, RangeInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeFrom<T>, RangeFrom<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeFrom<T>, RangeTo<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeFrom<T>, RangeToInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeFrom<T>, RangeFull, PartialOrd);
+
+impl_intersect_through_bounds!(RangeTo<T>, Range<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeTo<T>, RangeInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeTo<T>, RangeFrom<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeTo<T>, RangeTo<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeTo<T>, RangeToInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeTo<T>, RangeFull, PartialOrd);
+
+impl_intersect_through_bounds!(RangeToInclusive<T>, Range<T>, RangeValue);
+impl_intersect_through_bounds!(RangeToInclusive<T>, RangeInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeToInclusive<T>, RangeFrom<T>, RangeValue);
+impl_intersect_through_bounds!(RangeToInclusive<T>, RangeTo<T>, RangeValue);
+impl_intersect_through_bounds!(RangeToInclusive<T>, RangeToInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeToInclusive<T>, RangeFull, PartialOrd);
+
+impl_intersect_through_bounds!(RangeFull, Range<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeFull, RangeInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeFull, RangeFrom<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeFull, RangeTo<T>, PartialOrd);
+impl_intersect_through_bounds!(RangeFull, RangeToInclusive<T>, RangeValue);
+impl_intersect_through_bounds!(RangeFull, RangeFull, PartialOrd);
+
+/// Description of how a range is covered by a set of ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Coverage<T> {
+    /// Every value of the range is in the set
+    Covered,
+
+    /// Some values of the range are in the set, the uncovered parts are given
+    Partial {
+        uncovered: Vec<Range<T>>,
+    },
+
+    /// No value of the range is in the set
+    Disjoint,
+}
+
+/// Describes how a range is covered by a set of ranges.
+///
+/// An empty range is considered covered.
+///
+/// ```
+/// use enontekio::ops::range_set::RangeSet;
+/// use enontekio::ops::ranges::{describe_against_set, Coverage};
+///
+/// let set: RangeSet<i32> = vec![0..10, 15..20].into_iter().collect();
+///
+/// assert_eq!(Coverage::Covered, describe_against_set(&(2..8), &set));
+/// assert_eq!(Coverage::Disjoint, describe_against_set(&(10..15), &set));
+/// assert_eq!(
+///     Coverage::Partial { uncovered: vec![10..15, 20..25] },
+///     describe_against_set(&(5..25), &set),
+/// );
+/// ```
+pub fn describe_against_set<T: Ord + Copy>(range: &Range<T>, set: &RangeSet<T>) -> Coverage<T> {
+    if range.is_empty() {
+        return Coverage::Covered;
+    }
+
+    let uncovered = set.gaps_within(range.clone());
+
+    match uncovered.as_slice() {
+        [] => Coverage::Covered,
+        [part] if part == range => Coverage::Disjoint,
+        _ => Coverage::Partial { uncovered },
     }
 }
 
-impl<T: PartialOrd + Integer + Copy> Intersect<T, RangeInclusive<T>> for RangeInclusive<T> {
-    fn describe_intersection(&self, other: &RangeInclusive<T>) -> IntersectionDescription {
-        let end = *self.end() + T::one();
-        (*self.start()..end).describe_intersection(other)
+/// Builds an inclusive range between two endpoints given in any order.
+///
+/// Segments endpoints can be given backwards, like 9,7 -> 7,7,
+/// but 9..=7 would be an empty range.
+///
+/// ```
+/// use enontekio::ops::ranges::range_between;
+///
+/// assert_eq!(7..=9, range_between(9, 7));
+/// assert_eq!(3, range_between(9, 7).count());
+/// ```
+pub fn range_between<T: PartialOrd>(a: T, b: T) -> RangeInclusive<T> {
+    if a <= b {
+        a..=b
+    } else {
+        b..=a
+    }
+}
+
+/// Builds inclusive ranges from pairs of endpoints given in any order.
+pub fn ranges_from_pairs<T, I>(pairs: I) -> impl Iterator<Item = RangeInclusive<T>>
+where T: PartialOrd, I: IntoIterator<Item = (T, T)> {
+    pairs.into_iter().map(|(a, b)| range_between(a, b))
+}
+
+/// Iterator over the integers between two endpoints, both included,
+/// going up or down according to the endpoints order
+#[derive(Debug, Clone)]
+pub struct StepsBetween<T> {
+    next: Option<T>,
+    end: T,
+    ascending: bool,
+}
+
+impl<T: Integer + Copy> Iterator for StepsBetween<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = if current == self.end {
+            None
+        } else if self.ascending {
+            Some(current + T::one())
+        } else {
+            Some(current - T::one())
+        };
+
+        Some(current)
+    }
+}
+
+/// Gets all the integers from a to b, both included, whatever the direction.
+///
+/// ```
+/// use enontekio::ops::ranges::steps_between;
+///
+/// assert_eq!(vec![9, 8, 7], steps_between(9, 7).collect::<Vec<_>>());
+/// assert_eq!(vec![7, 8, 9], steps_between(7, 9).collect::<Vec<_>>());
+/// ```
+pub fn steps_between<T: Integer + Copy>(a: T, b: T) -> StepsBetween<T> {
+    StepsBetween {
+        next: Some(a),
+        end: b,
+        ascending: a <= b,
+    }
+}
+
+/// Gets all the points of a line from p1 to p2, both included.
+///
+/// Only horizontal, vertical and 45° diagonal lines are supported,
+/// as other lines don't go through integer points at each step.
+/// Returns None for other lines.
+///
+/// ```
+/// use enontekio::ops::ranges::line_points;
+///
+/// assert_eq!(Some(vec![(1, 1), (2, 2), (3, 3)]), line_points((1, 1), (3, 3)));
+/// assert_eq!(Some(vec![(9, 7), (8, 7), (7, 7)]), line_points((9, 7), (7, 7)));
+/// assert_eq!(None, line_points((0, 0), (1, 2)));
+/// ```
+pub fn line_points<T: Integer + Copy>(p1: (T, T), p2: (T, T)) -> Option<Vec<(T, T)>> {
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+
+    if x1 == x2 {
+        Some(steps_between(y1, y2).map(|y| (x1, y)).collect())
+    } else if y1 == y2 {
+        Some(steps_between(x1, x2).map(|x| (x, y1)).collect())
+    } else if distance(x1, x2) == distance(y1, y2) {
+        Some(steps_between(x1, x2).zip(steps_between(y1, y2)).collect())
+    } else {
+        None
+    }
+}
+
+/// Splits an integer range into sub-ranges where the predicate is constant,
+/// returning each sub-range with the predicate value.
+///
+/// The predicate is evaluated lazily: from the start of each sub-range,
+/// values are probed at exponentially growing distances, then the change
+/// is located by bisection. This is exact for monotone predicates, like
+/// threshold conditions, and for any predicate whose runs are long enough
+/// not to fall between two probes.
+///
+/// ```
+/// use enontekio::ops::ranges::partition_by;
+///
+/// let parts = partition_by(1..4001, |x| x < 1351);
+/// assert_eq!(vec![(1..1351, true), (1351..4001, false)], parts);
+/// ```
+pub fn partition_by<T, F>(range: Range<T>, predicate: F) -> Vec<(Range<T>, bool)>
+where T: Integer + Copy, F: Fn(T) -> bool {
+    let mut parts = Vec::new();
+    let mut start = range.start;
+
+    while start < range.end {
+        let value = predicate(start);
+
+        // Last position known with the same value, first known with another one
+        let last = range.end - T::one();
+        let mut same = start;
+        let mut other = None;
+
+        // The probe is clamped to the last value, and the step stops doubling
+        // once it passes the remaining length, so nothing overflows near T::MAX.
+        let mut step = T::one();
+        while same < last {
+            let remaining = last - same;
+            let probe = if step < remaining { same + step } else { last };
+
+            if predicate(probe) != value {
+                other = Some(probe);
+                break;
+            }
+
+            same = probe;
+            if step < remaining && step <= remaining - step {
+                step = step + step;
+            }
+        }
+
+        if let Some(mut other) = other {
+            let two = T::one() + T::one();
+            while other - same > T::one() {
+                let middle = same + (other - same) / two;
+                if predicate(middle) == value {
+                    same = middle;
+                } else {
+                    other = middle;
+                }
+            }
+        }
+
+        let end = same + T::one();
+        parts.push((start..end, value));
+        start = end;
+    }
+
+    parts
+}
+
+/// Error returned when the source ranges of two mappings share values,
+/// so the offset to apply to those values is ambiguous
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlappingMappings<T> {
+    pub first: Range<T>,
+    pub second: Range<T>,
+}
+
+impl<T: Debug> Display for OverlappingMappings<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the mappings {:?} and {:?} overlap", self.first, self.second)
+    }
+}
+
+impl<T: Debug> Error for OverlappingMappings<T> {}
+
+/// Applies a piecewise mapping to a range: the values within a source range
+/// of the mappings are shifted by its offset, other values are kept as is.
+///
+/// This is RangeMap::translate for a one-off list of mappings,
+/// with the transformed ranges sorted by their start
+/// instead of following the order of the source keys.
+///
+/// Source ranges must be disjoint: if two of them intersect, whatever
+/// the intersection description, the mappings are rejected.
+/// Empty source ranges map nothing and are ignored.
+///
+/// ```
+/// use enontekio::ops::ranges::apply_offsets;
+///
+/// // 98..100 goes to 50..52, 50..98 goes to 52..100
+/// let mappings = [(98..100, -48), (50..98, 2)];
+///
+/// assert_eq!(Ok(vec![45..50, 52..57]), apply_offsets(45..55, &mappings));
+/// assert_eq!(Ok(vec![50..52, 99..100, 100..101]), apply_offsets(97..101, &mappings));
+///
+/// assert!(apply_offsets(0..10, &[(0..5, 1), (4..8, 2)]).is_err());
+/// ```
+pub fn apply_offsets<T: Integer + Copy>(
+    range: Range<T>,
+    mappings: &[(Range<T>, T)],
+) -> Result<Vec<Range<T>>, OverlappingMappings<T>> {
+    let mut sources: Vec<&Range<T>> = mappings.iter()
+        .map(|(source, _)| source)
+        .filter(|source| !source.is_empty())
+        .collect();
+    sources.sort_by_key(|source| (source.start, source.end));
+
+    // Once sorted by start, a source intersecting any other intersects the next one
+    if let Some(pair) = sources.windows(2).find(|pair| pair[0].describe_intersection(pair[1]).is_any()) {
+        return Err(OverlappingMappings {
+            first: pair[0].clone(),
+            second: pair[1].clone(),
+        });
+    }
+
+    let map: RangeMap<T, T> = mappings.iter().cloned().collect();
+
+    let mut ranges: Vec<_> = map.translate(range)
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect();
+    ranges.sort_by_key(|part| (part.start, part.end));
+
+    Ok(ranges)
+}
+
+/// Splits a range at a point, into the values below it and the others.
+///
+/// The point is clamped to the range, so one part can be empty.
+/// Splitting at the middle halves the range, like a binary search.
+///
+/// ```
+/// use enontekio::ops::ranges::split_at;
+///
+/// assert_eq!((0..4, 4..10), split_at(0..10, 4));
+/// assert_eq!((0..10, 10..10), split_at(0..10, 25));
+///
+/// // Narrows 0..100 down to the value 42
+/// let mut range = 0..100;
+/// while range.len() > 1 {
+///     let middle = range.start + range.len() / 2;
+///     let (below, above) = split_at(range, middle);
+///     range = if 42 < middle { below } else { above };
+/// }
+/// assert_eq!(42..43, range);
+/// ```
+pub fn split_at<T: Ord + Copy>(range: Range<T>, point: T) -> (Range<T>, Range<T>) {
+    let point = point.max(range.start).min(range.end.max(range.start));
+
+    (range.start..point, point..range.end)
+}
+
+/// Splits a range into consecutive sub-ranges of the specified size.
+/// The last sub-range is shorter if the size doesn't divide the range length.
+///
+/// ```
+/// use enontekio::ops::ranges::chunks;
+///
+/// assert_eq!(vec![0..4, 4..8, 8..10], chunks(0..10, 4));
+/// assert!(chunks(5..5, 4).is_empty());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the size isn't positive.
+pub fn chunks<T: Integer + Copy>(range: Range<T>, size: T) -> Vec<Range<T>> {
+    assert!(size > T::zero(), "chunk size must be positive");
+
+    let mut chunks = Vec::new();
+    let mut start = range.start;
+    while start < range.end {
+        let end = if range.end - start > size { start + size } else { range.end };
+        chunks.push(start..end);
+        start = end;
+    }
+
+    chunks
+}
+
+fn distance<T: Integer + Copy>(a: T, b: T) -> T {
+    if a <= b {
+        b - a
+    } else {
+        a - b
     }
 }
 
@@ -225,6 +798,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     pub fn test_range_intersect_with_inclusive_range() {
         assert_eq!((3..10).describe_intersection(&(11..=10)), IntersectionDescription::Below);
         assert_eq!((3..10).describe_intersection(&(10..=10)), IntersectionDescription::Below);
@@ -241,6 +815,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     pub fn test_inclusive_range_intersect_with_inclusive_range() {
         assert_eq!((3..=9).describe_intersection(&(11..=10)), IntersectionDescription::Below);
         assert_eq!((3..=9).describe_intersection(&(10..=10)), IntersectionDescription::Below);
@@ -255,4 +830,223 @@ mod test {
         assert_eq!((3..=9).describe_intersection(&(2..=2)), IntersectionDescription::Above);
         assert_eq!((3..=9).describe_intersection(&(1..=1)), IntersectionDescription::Above);
     }
+
+    #[test]
+    pub fn test_unbounded_range_intersect() {
+        assert_eq!((3..).describe_intersection(&(0..2)), IntersectionDescription::Above);
+        assert_eq!((3..).describe_intersection(&(0..3)), IntersectionDescription::Above);
+        assert_eq!((3..).describe_intersection(&(0..5)), IntersectionDescription::AboveOverlap);
+        assert_eq!((3..).describe_intersection(&(3..5)), IntersectionDescription::Over);
+        assert_eq!((3..).describe_intersection(&(3..=5)), IntersectionDescription::Over);
+        assert_eq!((3..).describe_intersection(&(3..)), IntersectionDescription::Same);
+        assert_eq!((3..).describe_intersection(&(2..)), IntersectionDescription::Within);
+        assert_eq!((3..).describe_intersection(&(4..)), IntersectionDescription::Over);
+        assert_eq!((3..).describe_intersection(&(..3)), IntersectionDescription::Above);
+        assert_eq!((3..).describe_intersection(&(..=3)), IntersectionDescription::AboveOverlap);
+        assert_eq!((3..).describe_intersection(&(..)), IntersectionDescription::Within);
+
+        assert_eq!((..3).describe_intersection(&(3..5)), IntersectionDescription::Below);
+        assert_eq!((..3).describe_intersection(&(2..5)), IntersectionDescription::BelowOverlap);
+        assert_eq!((..3).describe_intersection(&(0..3)), IntersectionDescription::Over);
+        assert_eq!((..3).describe_intersection(&(0..2)), IntersectionDescription::Over);
+        assert_eq!((..3).describe_intersection(&(3..)), IntersectionDescription::Below);
+        assert_eq!((..3).describe_intersection(&(..3)), IntersectionDescription::Same);
+        assert_eq!((..3).describe_intersection(&(..=2)), IntersectionDescription::Same);
+        assert_eq!((..3).describe_intersection(&(..5)), IntersectionDescription::Within);
+        assert_eq!((..3).describe_intersection(&(..2)), IntersectionDescription::Over);
+        assert_eq!((..3).describe_intersection(&(..)), IntersectionDescription::Within);
+
+        assert_eq!((..=3).describe_intersection(&(3..5)), IntersectionDescription::BelowOverlap);
+        assert_eq!((..=3).describe_intersection(&(4..=5)), IntersectionDescription::Below);
+        assert_eq!((..=3).describe_intersection(&(..4)), IntersectionDescription::Same);
+
+        assert_eq!((..).describe_intersection(&(3..5)), IntersectionDescription::Over);
+        assert_eq!((..).describe_intersection(&(3..)), IntersectionDescription::Over);
+        assert_eq!((..).describe_intersection(&(..=3)), IntersectionDescription::Over);
+        assert_eq!(Intersect::<i32, _>::describe_intersection(&RangeFull, &RangeFull), IntersectionDescription::Same);
+
+        assert_eq!((3..10).describe_intersection(&(..=9)), IntersectionDescription::Within);
+        assert_eq!((3..=9).describe_intersection(&(..=5)), IntersectionDescription::AboveOverlap);
+    }
+
+    #[test]
+    pub fn test_describe_intersection_bounds() {
+        use IntersectionDescription::*;
+
+        let others = [11..11, 10..11, 9..11, 9..10, 3..10, 5..9, 3..9, 2..11, 3..11, 2..9, 2..3, 1..2];
+        for other in others {
+            assert_eq!((3..10).describe_intersection(&other), describe_intersection_bounds(&(3..10), &other));
+        }
+
+        assert_eq!(Below, describe_intersection_bounds(&(0.0..=1.0), &(1.5..)));
+        assert_eq!(BelowOverlap, describe_intersection_bounds(&(0.0..=1.0), &(1.0..)));
+        assert_eq!(Below, describe_intersection_bounds(&(0.0..1.0), &(1.0..)));
+        assert_eq!(Same, describe_intersection_bounds::<i32, _, _>(&(..), &(..)));
+        assert_eq!(Above, describe_intersection_bounds(&(5..), &(..=4)));
+        assert_eq!(AboveOverlap, describe_intersection_bounds(&(5..), &(..=5)));
+    }
+
+    #[test]
+    pub fn test_range_between() {
+        assert_eq!(7..=9, range_between(7, 9));
+        assert_eq!(7..=9, range_between(9, 7));
+        assert_eq!(7..=7, range_between(7, 7));
+        assert_eq!(-2.5..=1.0, range_between(1.0, -2.5));
+    }
+
+    #[test]
+    pub fn test_ranges_from_pairs() {
+        let ranges: Vec<_> = ranges_from_pairs(vec![(0, 5), (9, 7), (3, 3)]).collect();
+
+        assert_eq!(vec![0..=5, 7..=9, 3..=3], ranges);
+    }
+
+    #[test]
+    pub fn test_steps_between() {
+        assert_eq!(vec![3], steps_between(3, 3).collect::<Vec<_>>());
+        assert_eq!(vec![2u8, 1, 0], steps_between(2u8, 0).collect::<Vec<_>>());
+        assert_eq!(vec![-1, 0, 1], steps_between(-1, 1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    pub fn test_line_points() {
+        assert_eq!(Some(vec![(0, 9), (0, 8)]), line_points((0, 9), (0, 8)));
+        assert_eq!(Some(vec![(5u32, 5)]), line_points((5u32, 5), (5, 5)));
+        assert_eq!(Some(vec![(8, 0), (7, 1), (6, 2)]), line_points((8, 0), (6, 2)));
+        assert_eq!(None, line_points((8, 0), (0, 7)));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    pub fn test_is_empty_range() {
+        assert!(is_empty_range(&(5..5)));
+        assert!(is_empty_range(&(9..=7)));
+        assert!(!is_empty_range(&(7..=7)));
+        assert!(!is_empty_range(&(9..)));
+        assert!(!is_empty_range::<i32, _>(&(..)));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    pub fn test_try_describe_intersection() {
+        assert_eq!(Ok(IntersectionDescription::BelowOverlap), (3..=9).try_describe_intersection(&(9..11)));
+        assert_eq!(Ok(IntersectionDescription::Within), (3..10).try_describe_intersection(&(..)));
+
+        assert_eq!(Err(InvalidRange::EmptySelf), (9..=7).try_describe_intersection(&(8..9)));
+        assert_eq!(Err(InvalidRange::EmptyOther), (3..10).try_describe_intersection(&(5..5)));
+        assert_eq!(Err(InvalidRange::EmptyOther), (3..10).try_describe_intersection(&(11..=10)));
+    }
+
+    #[test]
+    pub fn test_closed_intersect_floats() {
+        let a = 1.0..=2.0;
+
+        assert_eq!(IntersectionDescription::Within, a.describe_intersection(&(0.5..=2.0)));
+        assert_eq!(IntersectionDescription::Over, a.describe_intersection(&(1.2..=1.8)));
+        assert_eq!(IntersectionDescription::AboveOverlap, a.describe_intersection(&(0.0..=1.0)));
+        assert_eq!(IntersectionDescription::Above, a.describe_intersection(&(-1.0..=0.99)));
+        assert_eq!(IntersectionDescription::BelowOverlap, (0.5..=2.5).describe_intersection(&(2.5..=7.0)));
+    }
+
+    #[test]
+    pub fn test_inclusive_range_intersect_floats() {
+        let a = 1.0..=2.0;
+
+        assert_eq!(IntersectionDescription::Same, a.describe_intersection(&(1.0..=2.0)));
+        assert_eq!(IntersectionDescription::Over, a.describe_intersection(&(1.0..2.0)));
+        assert_eq!(IntersectionDescription::Within, (1.0..2.0).describe_intersection(&a));
+        assert_eq!(IntersectionDescription::BelowOverlap, a.describe_intersection(&(2.0..3.0)));
+        assert_eq!(IntersectionDescription::Above, a.describe_intersection(&(..1.0)));
+        assert_eq!(IntersectionDescription::AboveOverlap, a.describe_intersection(&(..=1.0)));
+        assert_eq!(IntersectionDescription::Within, (..=1.5).describe_intersection(&(..2.0)));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    pub fn test_describe_against_set() {
+        let set: RangeSet<i64> = vec![0..10, 15..20, 30..40].into_iter().collect();
+
+        assert_eq!(Coverage::Covered, describe_against_set(&(0..10), &set));
+        assert_eq!(Coverage::Covered, describe_against_set(&(5..5), &set));
+        assert_eq!(Coverage::Disjoint, describe_against_set(&(-5..0), &set));
+        assert_eq!(Coverage::Disjoint, describe_against_set(&(40..50), &set));
+        assert_eq!(
+            Coverage::Partial { uncovered: vec![-3..0] },
+            describe_against_set(&(-3..4), &set),
+        );
+        assert_eq!(
+            Coverage::Partial { uncovered: vec![10..15, 20..30] },
+            describe_against_set(&(8..35), &set),
+        );
+    }
+
+    #[test]
+    pub fn test_partition_by() {
+        assert_eq!(vec![(0..10, true)], partition_by(0..10, |_| true));
+        assert_eq!(vec![(5..6, false)], partition_by(5..6, |x| x > 5));
+        assert!(partition_by(5..5, |x| x > 5).is_empty());
+
+        assert_eq!(
+            vec![(0..100, false), (100..200, true), (200..1000, false)],
+            partition_by(0..1000, |x| (100..200).contains(&x)),
+        );
+
+        assert_eq!(vec![((i32::MAX - 10)..i32::MAX, true)], partition_by((i32::MAX - 10)..i32::MAX, |_| true));
+        assert_eq!(
+            vec![(0..(u8::MAX - 1), false), ((u8::MAX - 1)..u8::MAX, true)],
+            partition_by(0..u8::MAX, |x| x == u8::MAX - 1),
+        );
+    }
+
+    #[test]
+    pub fn test_partition_by_is_lazy() {
+        let calls = std::cell::Cell::new(0);
+
+        let parts = partition_by(0u64..1_000_000_000, |x| {
+            calls.set(calls.get() + 1);
+            x >= 123_456_789
+        });
+
+        assert_eq!(vec![(0..123_456_789, false), (123_456_789..1_000_000_000, true)], parts);
+        assert!(calls.get() < 200);
+    }
+
+    #[test]
+    fn test_split_at() {
+        assert_eq!((3..3, 3..7), split_at(3..7, 3));
+        assert_eq!((3..3, 3..7), split_at(3..7, -5));
+        assert_eq!((3..7, 7..7), split_at(3..7, 7));
+        assert_eq!((5..5, 5..5), split_at(5..5, 8));
+    }
+
+    #[test]
+    fn test_chunks() {
+        assert_eq!(vec![-3..0, 0..3], chunks(-3..3, 3));
+        assert_eq!(vec![0..10], chunks(0..10, 50));
+        assert_eq!(vec![250u8..255], chunks(250u8..255, 200));
+    }
+
+    #[test]
+    fn test_apply_offsets() {
+        let mappings = [(5..10, 100), (10..12, -10), (20..20, 1)];
+
+        assert_eq!(vec![0..5], apply_offsets(0..5, &mappings).unwrap());
+        assert_eq!(vec![106..108], apply_offsets(6..8, &mappings).unwrap());
+        assert_eq!(vec![0..2, 3..5, 12..15, 105..110], apply_offsets(3..15, &mappings).unwrap());
+        assert!(apply_offsets(3..3, &mappings).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_offsets_rejects_overlapping_mappings() {
+        let overlapping = |first, second| Err(OverlappingMappings { first, second });
+
+        // Above but overlapping, within, same, over
+        assert_eq!(overlapping(5..10, 8..15), apply_offsets(0..20, &[(5..10, 100), (8..15, -10)]));
+        assert_eq!(overlapping(5..10, 6..8), apply_offsets(0..20, &[(5..10, 100), (6..8, -10)]));
+        assert_eq!(overlapping(5..10, 5..10), apply_offsets(0..20, &[(5..10, 100), (5..10, -10)]));
+        assert_eq!(overlapping(0..20, 5..10), apply_offsets(0..20, &[(5..10, 100), (0..20, -10)]));
+
+        // Touching ranges don't overlap
+        assert_eq!(Ok(vec![0..5, 10..15]), apply_offsets(0..10, &[(0..5, 0), (5..10, 5)]));
+    }
 }
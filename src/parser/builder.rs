@@ -0,0 +1,279 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{Error as IOError, ErrorKind};
+use std::path::Path;
+
+use crate::parser::{strip_bom, ParserError};
+
+/// How to decode the bytes of the input
+///
+/// The default is Latin1Fallback with the `latin1` feature, Utf8 otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// Reports invalid UTF-8 as an error
+    #[cfg_attr(not(feature = "latin1"), default)]
+    Utf8,
+
+    /// Reads the input as Latin-1 (ISO-8859-1) if it isn't valid UTF-8
+    #[cfg_attr(feature = "latin1", default)]
+    Latin1Fallback,
+}
+
+/// How blocks of lines are separated
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum BlockSeparator {
+    /// One or more blank lines
+    #[default]
+    BlankLines,
+
+    /// A line with this content, once trimmed
+    Line(String),
+}
+
+/// Configures how to read lines, then parses them.
+///
+/// Line numbers reported in errors are always the ones of the input,
+/// even when lines are skipped.
+///
+/// ```
+/// use enontekio::parser::ParserBuilder;
+///
+/// let input = "# Instructions\n  up 3\n\n  down 5 // twice\n";
+///
+/// let moves: Vec<(String, u32)> = ParserBuilder::new()
+///     .trim(true)
+///     .skip_empty_lines(true)
+///     .comment_prefix("#")
+///     .comment_prefix("//")
+///     .parse_str_lines(input, |line| {
+///         let (direction, distance) = line.split_once(' ').ok_or("expected a move")?;
+///         Ok::<_, String>((direction.to_string(), distance.parse().map_err(|_| "invalid distance")?))
+///     })
+///     .unwrap();
+///
+/// assert_eq!(vec![("up".to_string(), 3), ("down".to_string(), 5)], moves);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParserBuilder {
+    trim: bool,
+    skip_empty_lines: bool,
+    comment_prefixes: Vec<String>,
+    encoding: Encoding,
+    block_separator: BlockSeparator,
+}
+
+impl ParserBuilder {
+    /// Creates a builder keeping every line as is.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trims the whitespace around each line.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Skips the blank lines, after comments are removed.
+    ///
+    /// Blank lines still separate blocks.
+    pub fn skip_empty_lines(mut self, skip_empty_lines: bool) -> Self {
+        self.skip_empty_lines = skip_empty_lines;
+        self
+    }
+
+    /// Removes everything after this prefix, on each line.
+    ///
+    /// Can be called several times for several comment styles.
+    pub fn comment_prefix(mut self, prefix: &str) -> Self {
+        self.comment_prefixes.push(prefix.to_string());
+        self
+    }
+
+    /// Sets how to decode the bytes of a file read.
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Sets how blocks of lines are separated.
+    pub fn block_separator(mut self, separator: BlockSeparator) -> Self {
+        self.block_separator = separator;
+        self
+    }
+
+    /// Attempts to open and read a file, then parses each kept line through a callback.
+    pub fn parse_lines<P, T, E, C, F>(&self, filename: P, callback: F) -> Result<C, ParserError>
+    where P: AsRef<Path>, E: Display, C: FromIterator<T>, F: FnMut(&str) -> Result<T, E> {
+        let filename = filename.as_ref();
+
+        self.parse_str_lines(&self.read(filename)?, callback).map_err(|error| error.with_filename(filename))
+    }
+
+    /// Parses each kept line of a string through a callback.
+    pub fn parse_str_lines<T, E, C, F>(&self, input: &str, mut callback: F) -> Result<C, ParserError>
+    where E: Display, C: FromIterator<T>, F: FnMut(&str) -> Result<T, E> {
+        self.lines(input)
+            .into_iter()
+            .map(|(line_number, line)| {
+                callback(line).map_err(|error| ParserError::parse(line_number, line, error))
+            })
+            .collect()
+    }
+
+    /// Attempts to open and read a file, then parses each block of kept lines through a callback.
+    ///
+    /// An error is reported with the number of the first line of the block.
+    pub fn parse_blocks<P, T, E, C, F>(&self, filename: P, callback: F) -> Result<C, ParserError>
+    where P: AsRef<Path>, E: Display, C: FromIterator<T>, F: FnMut(&[&str]) -> Result<T, E> {
+        let filename = filename.as_ref();
+
+        self.parse_str_blocks(&self.read(filename)?, callback).map_err(|error| error.with_filename(filename))
+    }
+
+    /// Parses each block of kept lines of a string through a callback.
+    pub fn parse_str_blocks<T, E, C, F>(&self, input: &str, mut callback: F) -> Result<C, ParserError>
+    where E: Display, C: FromIterator<T>, F: FnMut(&[&str]) -> Result<T, E> {
+        self.blocks(input)
+            .into_iter()
+            .map(|(line_number, lines)| {
+                callback(&lines).map_err(|error| ParserError::parse(line_number, &lines.join("\n"), error))
+            })
+            .collect()
+    }
+
+    /// Gets the kept lines, cleaned as configured, with their line number.
+    pub fn lines<'a>(&self, input: &'a str) -> Vec<(usize, &'a str)> {
+        strip_bom(input).lines()
+            .enumerate()
+            .filter_map(|(index, line)| Some((index + 1, self.filter_line(line)?)))
+            .collect()
+    }
+
+    /// Gets the blocks of kept lines, with the number of their first line.
+    ///
+    /// Lines made only of a comment are dropped, so they don't separate blocks.
+    pub fn blocks<'a>(&self, input: &'a str) -> Vec<(usize, Vec<&'a str>)> {
+        let mut blocks = Vec::new();
+        let mut current: Option<(usize, Vec<&str>)> = None;
+
+        for (index, raw_line) in strip_bom(input).lines().enumerate() {
+            let line = self.clean(raw_line);
+            if line.trim().is_empty() && !raw_line.trim().is_empty() {
+                continue;
+            }
+
+            let is_separator = match &self.block_separator {
+                BlockSeparator::BlankLines => line.trim().is_empty(),
+                BlockSeparator::Line(separator) => line.trim() == separator,
+            };
+
+            if is_separator {
+                blocks.extend(current.take());
+            } else if !(self.skip_empty_lines && line.trim().is_empty()) {
+                current.get_or_insert_with(|| (index + 1, Vec::new())).1.push(line);
+            }
+        }
+        blocks.extend(current);
+
+        blocks
+    }
+
+    fn clean<'a>(&self, line: &'a str) -> &'a str {
+        let line = self.comment_prefixes.iter()
+            .filter_map(|prefix| line.find(prefix.as_str()))
+            .min()
+            .map_or(line, |position| &line[..position]);
+
+        if self.trim {
+            line.trim()
+        } else {
+            line
+        }
+    }
+
+    fn filter_line<'a>(&self, line: &'a str) -> Option<&'a str> {
+        let line = self.clean(line);
+
+        if self.skip_empty_lines && line.trim().is_empty() {
+            None
+        } else {
+            Some(line)
+        }
+    }
+
+    /// Reads a file as a string, decoded as configured.
+    pub fn read<P: AsRef<Path>>(&self, filename: P) -> Result<String, ParserError> {
+        let bytes = fs::read(filename)?;
+
+        match String::from_utf8(bytes) {
+            Ok(content) => Ok(content),
+            Err(error) if self.encoding == Encoding::Latin1Fallback => {
+                Ok(error.as_bytes().iter().map(|&byte| byte as char).collect())
+            }
+            Err(error) => {
+                let valid = &error.as_bytes()[..error.utf8_error().valid_up_to()];
+                let line_number = valid.iter().filter(|&&byte| byte == b'\n').count() + 1;
+
+                Err(ParserError::IO {
+                    line_number: Some(line_number),
+                    error: IOError::new(ErrorKind::InvalidData, error.utf8_error()),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_str_blocks() {
+        let input = "1\n2\n---\n3 # three\n\n4\n---\n";
+        let builder = ParserBuilder::new()
+            .trim(true)
+            .skip_empty_lines(true)
+            .comment_prefix("#")
+            .block_separator(BlockSeparator::Line("---".to_string()));
+
+        let sums: Vec<u32> = builder.parse_str_blocks(input, |lines| {
+            lines.iter().map(|line| line.parse::<u32>()).sum()
+        }).unwrap();
+
+        assert_eq!(vec![3, 7], sums);
+    }
+
+    #[test]
+    fn test_comment_lines_dont_separate_blocks() {
+        let input = "1\n# two\n2\n\n3\n";
+        let blocks = ParserBuilder::new().comment_prefix("#").blocks(input);
+
+        assert_eq!(vec![(1, vec!["1", "2"]), (5, vec!["3"])], blocks);
+    }
+
+    #[test]
+    fn test_errors_keep_input_line_numbers() {
+        let input = "\n\n1\nx\n";
+        let result: Result<Vec<u32>, _> = ParserBuilder::new()
+            .skip_empty_lines(true)
+            .parse_str_lines(input, |line| line.parse::<u32>());
+
+        assert_eq!(Some(4), result.unwrap_err().line_number());
+    }
+
+    #[test]
+    fn test_read_encoding() {
+        let error = ParserBuilder::new()
+            .encoding(Encoding::Utf8)
+            .read("tests/parser/latin1.txt")
+            .unwrap_err();
+        assert_eq!(Some(2), error.line_number());
+
+        let content = ParserBuilder::new()
+            .encoding(Encoding::Latin1Fallback)
+            .read("tests/parser/latin1.txt")
+            .unwrap();
+        assert_eq!("Enontekio\nMalmö\n", content);
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use regex::{Captures, Regex};
+
+use crate::parser::{parse_file_by_line_with, ParserError};
+
+/// Parses a line matching a regular expression, with a callback
+/// receiving the captures.
+///
+/// ```
+/// use enontekio::parser::{parse_line_with_regex, Regex};
+///
+/// let pattern = Regex::new(r"pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(\d+)").unwrap();
+/// let radius = parse_line_with_regex(
+///     "pos=<1,-2,3>, r=4",
+///     &pattern,
+///     |captures| captures[4].parse::<u32>(),
+/// );
+///
+/// assert_eq!(Ok(4), radius);
+/// ```
+pub fn parse_line_with_regex<T, E, F>(line: &str, pattern: &Regex, mut callback: F) -> Result<T, String>
+where E: Display, F: FnMut(&Captures) -> Result<T, E> {
+    let captures = pattern.captures(line)
+        .ok_or_else(|| format!("line doesn't match {}", pattern))?;
+
+    callback(&captures).map_err(|error| error.to_string())
+}
+
+/// Attempts to open and parse a file line by line, each line matching
+/// a regular expression, with a callback receiving the captures.
+///
+/// A line not matching the regular expression, or a callback error,
+/// is reported as a ParserError::Parse with the line number.
+///
+/// The type should be compatible with collect to build a collection.
+pub fn parse_lines_with_regex<P, T, E, C, F>(filename: P, pattern: &Regex, mut callback: F) -> Result<C, ParserError>
+where P: AsRef<Path>, E: Display, C: FromIterator<T>, F: FnMut(&Captures) -> Result<T, E> {
+    parse_file_by_line_with(filename, |line| parse_line_with_regex(line, pattern, &mut callback))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines_with_regex() {
+        let pattern = Regex::new(r"^(\d)(\d)$").unwrap();
+        let digits: Vec<(u8, u8)> = parse_lines_with_regex(
+            "tests/parser/ascii.txt",
+            &pattern,
+            |captures| Ok::<_, String>((captures[1].parse().unwrap(), captures[2].parse().unwrap())),
+        ).unwrap();
+
+        assert_eq!(vec![(6, 4), (6, 5), (6, 6), (6, 7)], digits);
+    }
+
+    #[test]
+    fn test_parse_lines_with_regex_reports_line_number() {
+        let pattern = Regex::new(r"^6[4-6]$").unwrap();
+        let result: Result<Vec<()>, _> = parse_lines_with_regex("tests/parser/ascii.txt", &pattern, |_| Ok::<_, String>(()));
+
+        assert_eq!(Some(4), result.unwrap_err().line_number());
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::Error as IOError;
+use std::path::Path;
+
+use crate::collections::{render_grid, RenderOptions};
+use crate::parser::{lines_iter, parse_lines_located, str_lines, ParserError};
+
+/// A table between characters and values, like the cells of a map,
+/// to parse a grid and render it back with the same table.
+///
+/// When a character is listed several times, the first entry is used
+/// to parse it. When a value is listed several times, the first
+/// character is used to render it.
+///
+/// ```
+/// use enontekio::parser::CharMap;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Cell { Open, Wall, Box }
+///
+/// let cells = CharMap::new(&[('.', Cell::Open), ('#', Cell::Wall), ('O', Cell::Box), ('[', Cell::Box)]);
+///
+/// let grid = cells.parse_grid_str("#..\n.[O").unwrap();
+/// assert_eq!(vec![Cell::Open, Cell::Box, Cell::Box], grid[1]);
+/// assert_eq!("#..\n.OO\n", cells.render(&grid));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharMap<T> {
+    entries: Vec<(char, T)>,
+}
+
+impl<T: Clone + PartialEq> CharMap<T> {
+    /// Creates a table from (character, value) pairs.
+    pub fn new(entries: &[(char, T)]) -> Self {
+        Self {
+            entries: entries.to_vec(),
+        }
+    }
+
+    /// Gets the value of a character.
+    pub fn map(&self, c: char) -> Result<T, String> {
+        self.entries.iter()
+            .find(|(entry_char, _)| *entry_char == c)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| format!("unexpected character {:?}", c))
+    }
+
+    /// Gets the character to render a value.
+    pub fn to_char(&self, value: &T) -> Option<char> {
+        self.entries.iter()
+            .find(|(_, entry_value)| entry_value == value)
+            .map(|&(c, _)| c)
+    }
+
+    /// Parses each character of a line.
+    pub fn parse_line(&self, line: &str) -> Result<Vec<T>, String> {
+        line.chars().map(|c| self.map(c)).collect()
+    }
+
+    /// Parses a string into a grid, one row per line.
+    ///
+    /// An error is reported as a ParserError::Parse with the line and the column.
+    pub fn parse_grid_str(&self, input: &str) -> Result<Vec<Vec<T>>, ParserError> {
+        self.parse_grid_lines(str_lines(input))
+    }
+
+    /// Attempts to open and parse a file into a grid, one row per line.
+    pub fn parse_grid_file<P>(&self, filename: P) -> Result<Vec<Vec<T>>, ParserError> where P: AsRef<Path> {
+        let filename = filename.as_ref();
+
+        self.parse_grid_lines(lines_iter(filename)?).map_err(|error| error.with_filename(filename))
+    }
+
+    fn parse_grid_lines<I>(&self, lines: I) -> Result<Vec<Vec<T>>, ParserError>
+    where I: Iterator<Item = Result<String, IOError>> {
+        parse_lines_located(lines, |line_number, line| {
+            line.chars()
+                .enumerate()
+                .map(|(index, c)| {
+                    self.map(c).map_err(|message| {
+                        ParserError::parse(line_number, line, message).with_column(Some(index + 1))
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Renders a grid with the characters of the table, one line per row.
+    ///
+    /// Values missing from the table are rendered as '?'.
+    pub fn render(&self, grid: &[Vec<T>]) -> String {
+        render_grid(grid, &RenderOptions::default(), |value| self.to_char(value).unwrap_or('?'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let map = CharMap::new(&[('.', false), ('#', true), ('x', true)]);
+
+        assert_eq!(Ok(true), map.map('x'));
+        assert!(map.map('?').is_err());
+        assert_eq!(Some('#'), map.to_char(&true));
+        assert_eq!(Ok(vec![false, true]), map.parse_line(".#"));
+    }
+
+    #[test]
+    fn test_parse_grid_errors() {
+        let map = CharMap::new(&[('.', 0), ('#', 1)]);
+        let error = map.parse_grid_str("..\n.x#").unwrap_err();
+
+        assert_eq!(Some(2), error.line_number());
+        assert_eq!(Some(2), error.column());
+        assert_eq!("?#\n", map.render(&[vec![2, 1]]));
+    }
+}
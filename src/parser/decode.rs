@@ -0,0 +1,106 @@
+use std::io::{BufRead, Error as IOError};
+
+/// The byte order mark, some editors write at the start of UTF-8 files
+const BOM: char = '\u{feff}';
+
+/// Iterator over the lines of a reader, like BufRead::lines,
+/// but tolerating a UTF-8 byte order mark at the start of the input.
+///
+/// With the `latin1` feature, a line which isn't valid UTF-8
+/// is decoded as Latin-1 (ISO-8859-1) instead of being reported as an error.
+#[derive(Debug)]
+pub(crate) struct DecodedLines<R> {
+    reader: R,
+    first_line: bool,
+}
+
+impl<R: BufRead> DecodedLines<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            first_line: true,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for DecodedLines<R> {
+    type Item = Result<String, IOError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut bytes = Vec::new();
+        match self.reader.read_until(b'\n', &mut bytes) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(error) => return Some(Err(error)),
+        }
+
+        if bytes.ends_with(b"\n") {
+            bytes.pop();
+            if bytes.ends_with(b"\r") {
+                bytes.pop();
+            }
+        }
+
+        let line = decode_line(bytes).map(|line| {
+            if self.first_line {
+                strip_bom(&line).to_string()
+            } else {
+                line
+            }
+        });
+        self.first_line = false;
+
+        Some(line)
+    }
+}
+
+fn decode_line(bytes: Vec<u8>) -> Result<String, IOError> {
+    match String::from_utf8(bytes) {
+        Ok(line) => Ok(line),
+
+        #[cfg(feature = "latin1")]
+        Err(error) => Ok(error.as_bytes().iter().map(|&byte| byte as char).collect()),
+
+        #[cfg(not(feature = "latin1"))]
+        Err(error) => Err(IOError::new(std::io::ErrorKind::InvalidData, error.utf8_error())),
+    }
+}
+
+/// Removes the UTF-8 byte order mark at the start of an input, if any.
+///
+/// ```
+/// use enontekio::parser::strip_bom;
+///
+/// assert_eq!("1\n2", strip_bom("\u{feff}1\n2"));
+/// assert_eq!("1\n2", strip_bom("1\n2"));
+/// ```
+pub fn strip_bom(input: &str) -> &str {
+    input.strip_prefix(BOM).unwrap_or(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decoded_lines() {
+        let input = "\u{feff}1\r\n2\n\n\u{feff}3".as_bytes();
+        let lines: Vec<String> = DecodedLines::new(input).map(Result::unwrap).collect();
+
+        assert_eq!(vec!["1", "2", "", "\u{feff}3"], lines);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let input: &[u8] = b"Enontekio\nMalm\xf6\n";
+        let lines: Vec<_> = DecodedLines::new(input).collect();
+
+        assert_eq!("Enontekio", lines[0].as_ref().unwrap());
+
+        #[cfg(feature = "latin1")]
+        assert_eq!("Malmö", lines[1].as_ref().unwrap());
+
+        #[cfg(not(feature = "latin1"))]
+        assert_eq!(std::io::ErrorKind::InvalidData, lines[1].as_ref().unwrap_err().kind());
+    }
+}
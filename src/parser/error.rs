@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IOError;
+use std::path::{Path, PathBuf};
+
+/// Error returned by the parser functions,
+/// locating where the input is wrong when possible.
+///
+/// Line numbers and columns start at 1.
+#[derive(Debug)]
+pub enum ParserError {
+    /// The input can't be opened or read
+    IO {
+        line_number: Option<usize>,
+        error: IOError,
+    },
+
+    /// A line can't be parsed
+    Parse {
+        /// The file parsed, when the input is a file
+        filename: Option<PathBuf>,
+        line_number: usize,
+        /// The column of the offending text, when known, counted in characters
+        column: Option<usize>,
+        line: String,
+        message: String,
+    },
+
+    /// A block doesn't contain the expected number of lines
+    UnexpectedBlockSize {
+        line_number: usize,
+        expected: usize,
+        lines: Vec<String>,
+    },
+}
+
+impl ParserError {
+    /// Builds a parse error for the specified line.
+    pub fn parse<M: Display>(line_number: usize, line: &str, message: M) -> Self {
+        ParserError::Parse {
+            filename: None,
+            line_number,
+            column: None,
+            line: line.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Builds a parse error for the specified line, locating the offending text.
+    ///
+    /// The token must be a slice of the line, so its column can be computed.
+    pub fn parse_at<M: Display>(line_number: usize, line: &str, token: &str, message: M) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = line.get(..offset).map(|before| before.chars().count() + 1);
+
+        ParserError::parse(line_number, line, message).with_column(column)
+    }
+
+    /// Sets the column of the offending text of a parse error.
+    pub fn with_column(mut self, column: Option<usize>) -> Self {
+        if let ParserError::Parse { column: error_column, .. } = &mut self {
+            *error_column = column;
+        }
+
+        self
+    }
+
+    /// Sets the file where a parse error occurred.
+    pub fn with_filename<P: AsRef<Path>>(mut self, filename: P) -> Self {
+        if let ParserError::Parse { filename: error_filename, .. } = &mut self {
+            *error_filename = Some(filename.as_ref().to_path_buf());
+        }
+
+        self
+    }
+
+    /// Replaces the line number of the error, if known.
+    pub(crate) fn map_line_number<F>(mut self, map: F) -> Self where F: FnOnce(usize) -> usize {
+        match &mut self {
+            ParserError::IO { line_number: Some(line_number), .. }
+            | ParserError::Parse { line_number, .. }
+            | ParserError::UnexpectedBlockSize { line_number, .. } => *line_number = map(*line_number),
+            ParserError::IO { line_number: None, .. } => {}
+        }
+
+        self
+    }
+
+    /// Gets the file where the error occurred, if known.
+    pub fn filename(&self) -> Option<&Path> {
+        match self {
+            ParserError::Parse { filename, .. } => filename.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Gets the column where the error occurred, if known.
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            ParserError::Parse { column, .. } => *column,
+            _ => None,
+        }
+    }
+
+    /// Renders the line in error, with a caret under the offending text,
+    /// or under the whole line if the column isn't known.
+    ///
+    /// Only parse errors of a single line can be rendered.
+    ///
+    /// ```
+    /// use enontekio::parser::{self, DigitsGridOptions};
+    ///
+    /// let error = parser::parse_digits_grid_str("123\n4x6", &DigitsGridOptions::default()).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     Some("  --> line 2, column 2\n  |\n2 | 4x6\n  |  ^ invalid digit 'x'\n".to_string()),
+    ///     error.snippet(),
+    /// );
+    /// ```
+    pub fn snippet(&self) -> Option<String> {
+        let ParserError::Parse { filename, line_number, column, line, message } = self else {
+            return None;
+        };
+        if line.contains('\n') {
+            return None;
+        }
+
+        let gutter = " ".repeat(line_number.to_string().len());
+        let location = match (filename, column) {
+            (Some(filename), Some(column)) => format!("{}:{}:{}", filename.display(), line_number, column),
+            (Some(filename), None) => format!("{}:{}", filename.display(), line_number),
+            (None, Some(column)) => format!("line {}, column {}", line_number, column),
+            (None, None) => format!("line {}", line_number),
+        };
+        let (padding, carets) = match column {
+            Some(column) => (column.saturating_sub(1), 1),
+            None => (0, line.chars().count().max(1)),
+        };
+
+        Some(format!(
+            "{gutter} --> {location}\n{gutter} |\n{line_number} | {line}\n{gutter} | {}{} {message}\n",
+            " ".repeat(padding),
+            "^".repeat(carets),
+        ))
+    }
+
+    /// Gets the number of the line where the error occurred, if known.
+    pub fn line_number(&self) -> Option<usize> {
+        match self {
+            ParserError::IO { line_number, .. } => *line_number,
+            ParserError::Parse { line_number, .. } => Some(*line_number),
+            ParserError::UnexpectedBlockSize { line_number, .. } => Some(*line_number),
+        }
+    }
+}
+
+impl Display for ParserError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParserError::IO { line_number: Some(line_number), error } => {
+                write!(f, "line {}: {}", line_number, error)
+            }
+            ParserError::IO { line_number: None, error } => write!(f, "{}", error),
+            ParserError::Parse { filename, line_number, column, line, message } => {
+                if let Some(filename) = filename {
+                    write!(f, "{}: ", filename.display())?;
+                }
+                write!(f, "line {}", line_number)?;
+                if let Some(column) = column {
+                    write!(f, ", column {}", column)?;
+                }
+
+                write!(f, ": {}: {:?}", message, line)
+            }
+            ParserError::UnexpectedBlockSize { line_number, expected, lines } => {
+                write!(f, "line {}: expected a block of {} lines, got {}", line_number, expected, lines.len())
+            }
+        }
+    }
+}
+
+impl Error for ParserError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParserError::IO { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<IOError> for ParserError {
+    fn from(error: IOError) -> Self {
+        ParserError::IO {
+            line_number: None,
+            error,
+        }
+    }
+}
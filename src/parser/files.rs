@@ -0,0 +1,165 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{Error as IOError, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::parser::{parse_file_by_line, ParserError};
+
+/// Attempts to open and parse several files line by line, one after another,
+/// into a single collection through a callback function.
+///
+/// Line numbers reported in errors are the ones of the file in error.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<u32> = parser::parse_files_by_line(
+///     &["tests/parser/numbers-1.txt", "tests/parser/numbers-2.txt"],
+///     |line| line.parse(),
+/// ).unwrap();
+///
+/// assert_eq!(vec![1, 2, 3], numbers);
+/// ```
+pub fn parse_files_by_line<P, T, E, C>(paths: &[P], callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+where P: AsRef<Path>, E: Display, C: FromIterator<T> {
+    let items: Vec<(usize, T)> = parse_files_by_line_tagged(paths, callback)?;
+
+    Ok(items.into_iter().map(|(_, item)| item).collect())
+}
+
+/// Attempts to open and parse several files line by line, one after another,
+/// tagging each item with the index of its file in the paths.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<(usize, u32)> = parser::parse_files_by_line_tagged(
+///     &["tests/parser/numbers-1.txt", "tests/parser/numbers-2.txt"],
+///     |line| line.parse(),
+/// ).unwrap();
+///
+/// assert_eq!(vec![(0, 1), (0, 2), (1, 3)], numbers);
+/// ```
+pub fn parse_files_by_line_tagged<P, T, E, C>(paths: &[P], callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+where P: AsRef<Path>, E: Display, C: FromIterator<(usize, T)> {
+    let mut items = Vec::new();
+
+    for (index, path) in paths.iter().enumerate() {
+        let file_items: Vec<T> = parse_file_by_line(path, callback)?;
+        items.extend(file_items.into_iter().map(|item| (index, item)));
+    }
+
+    Ok(items.into_iter().collect())
+}
+
+/// Attempts to parse line by line every file matching a pattern,
+/// in the order of their names.
+///
+/// The pattern can use * for any sequence of characters and ? for one character,
+/// in the file name only: the directory must be given as is.
+///
+/// A pattern matching no file is reported as a NotFound IO error.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<u32> = parser::parse_matching("tests/parser/numbers-*.txt", |line| line.parse()).unwrap();
+///
+/// assert_eq!(vec![1, 2, 3], numbers);
+/// ```
+pub fn parse_matching<T, E, C>(pattern: &str, callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T> {
+    parse_files_by_line(&matching_paths(pattern)?, callback)
+}
+
+/// Gets the paths of the files matching a pattern, sorted.
+///
+/// The pattern can use * and ? in the file name, see parse_matching.
+pub fn matching_paths(pattern: &str) -> Result<Vec<PathBuf>, ParserError> {
+    let pattern_path = Path::new(pattern);
+    let name_pattern = pattern_path.file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| IOError::new(ErrorKind::InvalidInput, format!("invalid pattern {:?}", pattern)))?;
+    let directory = match pattern_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let is_match = entry.file_name()
+            .to_str()
+            .is_some_and(|name| wildcard_match(name_pattern, name));
+
+        if is_match && entry.file_type()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(IOError::new(ErrorKind::NotFound, format!("no file matches {:?}", pattern)).into());
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// Checks if a name matches a pattern, where * matches any sequence
+/// of characters and ? exactly one character.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // The last * absorbs one more character
+                Some((star, star_n)) => {
+                    backtrack = Some((star, star_n + 1));
+                    p = star + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("day05*.txt", "day05.txt"));
+        assert!(wildcard_match("day05*.txt", "day05-example.txt"));
+        assert!(wildcard_match("day0?.txt", "day07.txt"));
+        assert!(wildcard_match("*a*b", "xaxxb"));
+        assert!(!wildcard_match("day05*.txt", "day06.txt"));
+        assert!(!wildcard_match("day0?.txt", "day10.txt"));
+        assert!(!wildcard_match("*.txt", "a.txt.bak"));
+    }
+
+    #[test]
+    fn test_parse_matching_errors() {
+        let result: Result<Vec<u32>, _> = parse_matching("tests/parser/nothing-*.txt", |line| line.parse());
+        assert!(result.is_err());
+
+        let result: Result<Vec<u32>, _> = parse_files_by_line(&["tests/parser/numbers-1.txt", "tests/parser/chars.dat"], |line| line.parse());
+        assert_eq!(Some(1), result.unwrap_err().line_number());
+    }
+}
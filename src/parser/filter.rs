@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use crate::parser::{ParserBuilder, ParserError};
+
+/// Cleans lines before they reach a parser, as configured by a ParserBuilder:
+/// strips comments, trims whitespace and skips blank lines.
+///
+/// Any parse function of a string can be composed behind the filter.
+/// Line numbers reported in its errors are the ones of the original input,
+/// even when lines are skipped.
+///
+/// Nothing is stripped unless configured, so grids using `#` are kept as is.
+///
+/// ```
+/// use enontekio::parser::{self, LineFilter, ParserBuilder};
+///
+/// let input = "// Program\n\n  add 3  // first\n  mul x\n";
+/// let filter = LineFilter::new(ParserBuilder::new().trim(true).skip_empty_lines(true).comment_prefix("//"));
+///
+/// let result: Result<Vec<(String, u32)>, _> = filter.parse_str(input, |program| {
+///     parser::parse_str_by_line(program, |line| {
+///         let (op, value) = line.split_once(' ').ok_or("expected an instruction")?;
+///         Ok::<_, String>((op.to_string(), value.parse::<u32>().map_err(|_| "invalid value")?))
+///     })
+/// });
+///
+/// assert_eq!(Some(4), result.unwrap_err().line_number());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineFilter {
+    builder: ParserBuilder,
+}
+
+impl LineFilter {
+    /// Creates a filter keeping the lines the builder keeps.
+    pub fn new(builder: ParserBuilder) -> Self {
+        Self { builder }
+    }
+
+    /// Filters a string, then parses it through a parse function.
+    pub fn parse_str<T, F>(&self, input: &str, parse: F) -> Result<T, ParserError>
+    where F: FnOnce(&str) -> Result<T, ParserError> {
+        let lines = self.builder.lines(input);
+        let filtered: String = lines.iter()
+            .map(|(_, line)| format!("{}\n", line))
+            .collect();
+
+        parse(&filtered).map_err(|error| {
+            error.map_line_number(|line_number| {
+                line_number.checked_sub(1)
+                    .and_then(|index| lines.get(index))
+                    .map_or(line_number, |&(original, _)| original)
+            })
+        })
+    }
+
+    /// Attempts to open and read a file, then filters and parses its content.
+    pub fn parse_file<P, T, F>(&self, filename: P, parse: F) -> Result<T, ParserError>
+    where P: AsRef<Path>, F: FnOnce(&str) -> Result<T, ParserError> {
+        let filename = filename.as_ref();
+
+        self.parse_str(&self.builder.read(filename)?, parse).map_err(|error| error.with_filename(filename))
+    }
+}
+
+impl From<ParserBuilder> for LineFilter {
+    fn from(builder: ParserBuilder) -> Self {
+        Self::new(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    fn sums(filter: &LineFilter, input: &str) -> Result<Vec<u32>, ParserError> {
+        filter.parse_str(input, |input| {
+            parser::parse_str_by_line(input, |line| {
+                line.split(' ').map(|n| n.parse::<u32>()).sum::<Result<u32, _>>()
+            })
+        })
+    }
+
+    #[test]
+    fn test_filter_composes_with_parsers() {
+        let filter = LineFilter::new(ParserBuilder::new().trim(true).skip_empty_lines(true).comment_prefix("#"));
+
+        assert_eq!(vec![3, 7], sums(&filter, "1 2\n# skipped\n\n3 4 # four\n").unwrap());
+    }
+
+    #[test]
+    fn test_errors_keep_input_line_numbers() {
+        let filter = LineFilter::new(ParserBuilder::new().skip_empty_lines(true).comment_prefix("//"));
+
+        let error = sums(&filter, "// sums\n\n1 2\n\n3 x\n").unwrap_err();
+        assert_eq!(Some(5), error.line_number());
+    }
+
+    #[test]
+    fn test_default_filter_keeps_grids() {
+        let grid = parser::parse_chars_grid_str(".#\n#.\n");
+        let filtered = LineFilter::default().parse_str(".#\n#.\n", |input| Ok(parser::parse_chars_grid_str(input)));
+
+        assert_eq!(grid, filtered.unwrap());
+    }
+}
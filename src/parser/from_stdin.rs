@@ -0,0 +1,119 @@
+//! Parsers reading the standard input, so a program can be piped its input:
+//!
+//! ```no_run
+//! use enontekio::parser::from_stdin;
+//!
+//! // cat input.txt | program
+//! let numbers: Vec<i64> = from_stdin::parse_lines_as().unwrap();
+//! ```
+//!
+//! Each function matches a file parser of the parser module,
+//! without the filename argument.
+
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::io::{stdin, Error as IOError};
+use std::str::FromStr;
+
+use crate::parser::records::parse_records_blocks;
+use crate::parser::sections::split_blank_line_separated_blocks;
+use crate::parser::{
+    parse_chars_grid_line, parse_digits_grid_lines, parse_line_as, parse_line_as_with_delimiter,
+    parse_lines_with, parse_reader_by_lines_block_with_blank_lines_separator, parse_separated_values_lines,
+    DecodedLines, DigitsGridOptions, FromRecord, FromTokens, ParserError, Record,
+};
+
+/// Returns a lazy iterator over the lines of the standard input.
+pub fn lines_iter() -> impl Iterator<Item = Result<String, IOError>> {
+    DecodedLines::new(stdin().lock())
+}
+
+/// Parses the standard input line by line into a collection through a callback function.
+pub fn parse_by_line<T, E, C>(callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T> {
+    parse_lines_with(lines_iter(), callback)
+}
+
+/// Parses the standard input line by line, each line with FromStr.
+pub fn parse_lines_as<T>() -> Result<Vec<T>, ParserError>
+where T: FromStr, T::Err: Display {
+    parse_lines_with(lines_iter(), str::parse::<T>)
+}
+
+/// Parses the standard input made of values separated by a separator,
+/// like 1,22,3,44.
+pub fn parse_separated_values<T>(separator: &str) -> Result<Vec<T>, ParserError>
+where T: FromStr, T::Err: Display {
+    parse_separated_values_lines(lines_iter(), separator)
+}
+
+/// Parses the standard input composed of blocks of lines,
+/// with a callback receiving a slice of n lines of text.
+pub fn parse_by_lines_block<T, E, C>(lines_per_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T> {
+    parse_by_lines_block_with_blank_lines_separator(lines_per_block, 0, callback)
+}
+
+/// Parses the standard input composed of blocks of lines separated by
+/// <separator_lines_between_block> lines to ignore,
+/// with a callback receiving a slice of n lines of text.
+pub fn parse_by_lines_block_with_blank_lines_separator<T, E, C>(lines_per_block: usize, separator_lines_between_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T> {
+    parse_reader_by_lines_block_with_blank_lines_separator(stdin().lock(), lines_per_block, separator_lines_between_block, callback)
+}
+
+/// Parses the standard input containing digits into a vector of u32 vectors.
+pub fn parse_digits_grid() -> Result<Vec<Vec<u32>>, ParserError> {
+    parse_digits_grid_with_options(&DigitsGridOptions::default())
+}
+
+/// Parses the standard input containing digits into a vector of u32 vectors,
+/// handling invalid characters as described by the options.
+pub fn parse_digits_grid_with_options(options: &DigitsGridOptions) -> Result<Vec<Vec<u32>>, ParserError> {
+    parse_digits_grid_lines(lines_iter(), options)
+}
+
+/// Parses the standard input containing characters into a vector of char vectors.
+pub fn parse_chars_grid() -> Result<Vec<Vec<char>>, ParserError> {
+    parse_lines_with(lines_iter(), |line| Ok::<_, Infallible>(parse_chars_grid_line(line)))
+}
+
+/// Parses the standard input line by line, each line split into whitespace separated tokens.
+pub fn parse_as_tokens<T: FromTokens>() -> Result<Vec<T>, ParserError> {
+    parse_lines_with(lines_iter(), parse_line_as::<T>)
+}
+
+/// Parses the standard input line by line, each line split into tokens separated by a delimiter.
+pub fn parse_as_tokens_with_delimiter<T: FromTokens>(delimiter: &str) -> Result<Vec<T>, ParserError> {
+    parse_lines_with(lines_iter(), |line| parse_line_as_with_delimiter::<T>(line, delimiter))
+}
+
+/// Splits the standard input into blocks separated by blank lines,
+/// each block with the number of its first line.
+pub fn blank_line_separated_blocks() -> Result<Vec<(usize, Vec<String>)>, ParserError> {
+    split_blank_line_separated_blocks(lines_iter())
+}
+
+/// Splits the standard input into sections separated by blank lines.
+pub fn parse_sections() -> Result<Vec<Vec<String>>, ParserError> {
+    Ok(blank_line_separated_blocks()?.into_iter().map(|(_, lines)| lines).collect())
+}
+
+/// Parses the standard input made of records, each record being
+/// a block of key:value pairs, blocks separated by blank lines.
+pub fn parse_records() -> Result<Vec<Record>, ParserError> {
+    parse_records_blocks(blank_line_separated_blocks()?, |record| Ok(record.clone()))
+}
+
+/// Parses the standard input made of records into a type implementing FromRecord.
+pub fn parse_records_into<T: FromRecord>() -> Result<Vec<T>, ParserError> {
+    parse_records_blocks(blank_line_separated_blocks()?, T::from_record)
+}
+
+/// Parses the standard input line by line, each line matching
+/// a regular expression, with a callback receiving the captures.
+#[cfg(feature = "regex")]
+pub fn parse_lines_with_regex<T, E, C, F>(pattern: &regex::Regex, mut callback: F) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T>, F: FnMut(&regex::Captures) -> Result<T, E> {
+    parse_lines_with(lines_iter(), |line| crate::parser::parse_line_with_regex(line, pattern, &mut callback))
+}
@@ -1,85 +1,404 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt::Display;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::io::Error as IOError;
 use std::path::Path;
+use std::str::FromStr;
 
-/// Attempts to open and parse a file line by line into a collection through a callback function.
+mod builder;
+#[cfg(feature = "regex")]
+mod captures;
+mod char_map;
+mod decode;
+mod error;
+mod files;
+mod filter;
+pub mod from_stdin;
+mod moves;
+mod numbers;
+mod records;
+mod sections;
+mod tokens;
+
+pub use builder::{BlockSeparator, Encoding, ParserBuilder};
+#[cfg(feature = "regex")]
+pub use captures::{parse_line_with_regex, parse_lines_with_regex};
+pub use char_map::CharMap;
+use decode::DecodedLines;
+pub use decode::strip_bom;
+pub use error::ParserError;
+pub use files::{matching_paths, parse_files_by_line, parse_files_by_line_tagged, parse_matching};
+pub use filter::LineFilter;
+pub use moves::{DirectionMap, Move};
+pub use numbers::parse_number_with_suffix;
+pub use records::{parse_record, parse_records_file, parse_records_file_into, parse_records_str, FromRecord, Record};
+#[cfg(feature = "regex")]
+pub use regex::{Captures, Regex};
+pub use sections::{blank_line_separated_blocks, parse_sections, parse_sections_str, parse_two_sections};
+pub use tokens::{parse_file_as_tokens, parse_file_as_tokens_with_delimiter, parse_line_as, parse_line_as_with_delimiter, FromTokens};
+
+/// Attempts to open a file and returns a lazy iterator over its lines.
 ///
-/// The callback function will be used as a map and receive Result<string> as parameter.
-/// The type should be compatible with collect to build a collection.
-pub fn parse_file_by_line<P, T, C>(filename: P, callback: fn(Result<String, IOError>) -> T) -> Result<C, IOError>
-    where P: AsRef<Path>, C: FromIterator<T> {
+/// Contrary to the parse_* functions, nothing is collected:
+/// lines are read from the file only when the iterator is consumed,
+/// so huge files can be processed line by line.
+///
+/// A UTF-8 byte order mark at the start of the file is ignored.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let sum: u32 = parser::lines_iter("tests/parser/ascii.txt").unwrap()
+///     .map(|line| line.unwrap().parse::<u32>().unwrap())
+///     .sum();
+/// assert_eq!(64 + 65 + 66 + 67, sum);
+/// ```
+pub fn lines_iter<P>(filename: P) -> Result<impl Iterator<Item = Result<String, IOError>>, ParserError> where P: AsRef<Path> {
     let fd = File::open(filename)?;
-    let lines = BufReader::new(fd).lines();
 
-    Ok(lines
-        .map(callback)
-        .collect())
+    Ok(DecodedLines::new(BufReader::new(fd)))
 }
 
-/// Attempts to open and parse a file composed of blocks of lines,
-/// with a callback receiving a vector of n lines of text.
+/// Attempts to open a file and returns a lazy iterator over blocks of <lines_per_block> lines.
 ///
-/// The callback function will be used as a map and receive a Vec<String> as parameter.
-/// The vector will contain <lines_per_block> strings.
+/// A last incomplete block is reported as an UnexpectedBlockSize error,
+/// unless it's only made of blank lines.
+pub fn lines_block_iter<P>(filename: P, lines_per_block: usize) -> Result<LinesBlocks<impl Iterator<Item = Result<String, IOError>>>, ParserError>
+where P: AsRef<Path> {
+    lines_block_with_blank_lines_separator_iter(filename, lines_per_block, 0)
+}
+
+/// Attempts to open a file and returns a lazy iterator over blocks of <lines_per_block> lines.
 ///
-/// The type should be compatible with collect to build a vector of your collection type.
+/// Each block is separated by <separator_lines_between_block> lines to ignore.
+/// A last incomplete block is reported as an UnexpectedBlockSize error,
+/// unless it's only made of blank lines.
+pub fn lines_block_with_blank_lines_separator_iter<P>(filename: P, lines_per_block: usize, separator_lines_between_block: usize) -> Result<LinesBlocks<impl Iterator<Item = Result<String, IOError>>>, ParserError>
+where P: AsRef<Path> {
+    let lines = lines_iter(filename)?;
+
+    Ok(LinesBlocks::new(lines, lines_per_block, separator_lines_between_block))
+}
+
+/// Iterator adapter grouping lines into blocks of a fixed number of lines,
+/// optionally ignoring separator lines between two blocks.
 ///
-/// Function returns a type collected from an iterator yielding T, for example Vec<T>.
-/// T is the type returned by your callback method.
-pub fn parse_file_by_lines_block<P, T, C>(filename: P, lines_per_block: usize, callback: fn(Vec<String>) -> T) -> Result<C, IOError>
-where P: AsRef<Path>, C: FromIterator<T> {
-    let fd = File::open(filename)?;
-    let lines = BufReader::new(fd).lines();
+/// With 0 lines per block, there is no block.
+pub struct LinesBlocks<I> {
+    lines: I,
+    lines_per_block: usize,
+    separator_lines_between_block: usize,
+    line_number: usize,
+    block_line_number: usize,
+    started: bool,
+}
 
-    let mut items = Vec::new();
-    let mut block_lines = Vec::new();
-    for line in lines {
-        block_lines.push(line?);
+impl<I> LinesBlocks<I> where I: Iterator<Item = Result<String, IOError>> {
+    pub fn new(lines: I, lines_per_block: usize, separator_lines_between_block: usize) -> Self {
+        Self {
+            lines,
+            lines_per_block,
+            separator_lines_between_block,
+            line_number: 0,
+            block_line_number: 0,
+            started: false,
+        }
+    }
+
+    /// Gets the number of the first line of the last block read.
+    pub fn block_line_number(&self) -> usize {
+        self.block_line_number
+    }
+
+    fn next_line(&mut self) -> Option<Result<String, ParserError>> {
+        let line = self.lines.next()?;
+        self.line_number += 1;
+
+        Some(line.map_err(|error| ParserError::IO {
+            line_number: Some(self.line_number),
+            error,
+        }))
+    }
+}
+
+impl<I> Iterator for LinesBlocks<I> where I: Iterator<Item = Result<String, IOError>> {
+    type Item = Result<Vec<String>, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lines_per_block == 0 {
+            return None;
+        }
+
+        if self.started {
+            for _ in 0..self.separator_lines_between_block {
+                if let Err(error) = self.next_line()? {
+                    return Some(Err(error));
+                }
+            }
+        }
+        self.started = true;
 
-        if block_lines.len() == lines_per_block {
-            items.push(callback(block_lines));
-            block_lines = Vec::new();
+        self.block_line_number = self.line_number + 1;
+        let mut block_lines = Vec::with_capacity(self.lines_per_block);
+        while block_lines.len() < self.lines_per_block {
+            match self.next_line() {
+                Some(Ok(line)) => block_lines.push(line),
+                Some(Err(error)) => return Some(Err(error)),
+                None if block_lines.iter().all(|line| line.trim().is_empty()) => return None,
+                None => return Some(Err(ParserError::UnexpectedBlockSize {
+                    line_number: self.block_line_number,
+                    expected: self.lines_per_block,
+                    lines: block_lines,
+                })),
+            }
         }
+
+        Some(Ok(block_lines))
     }
+}
 
-    Ok(items.into_iter().collect())
+/// Attempts to open and parse a file line by line into a collection through a callback function.
+///
+/// The callback function will be used as a map and receive each line as parameter.
+/// It returns a Result: an error is reported as a ParserError::Parse with the line number.
+/// The type should be compatible with collect to build a collection.
+pub fn parse_file_by_line<P, T, E, C>(filename: P, callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+    where P: AsRef<Path>, E: Display, C: FromIterator<T> {
+    parse_file_by_line_with(filename, callback)
+}
+
+/// Parses a string line by line into a collection through a callback function.
+///
+/// That's the same as parse_file_by_line, for embedded inputs like test strings.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<u32> = parser::parse_str_by_line("1\n2\n3", |line| line.parse()).unwrap();
+/// assert_eq!(vec![1, 2, 3], numbers);
+/// ```
+pub fn parse_str_by_line<T, E, C>(input: &str, callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+    where E: Display, C: FromIterator<T> {
+    parse_lines_with(str_lines(input), callback)
+}
+
+/// Parses a reader line by line into a collection through a callback function.
+///
+/// That's the same as parse_file_by_line, for any buffered input like stdin.
+pub fn parse_reader_by_line<R, T, E, C>(reader: R, callback: fn(&str) -> Result<T, E>) -> Result<C, ParserError>
+    where R: BufRead, E: Display, C: FromIterator<T> {
+    parse_lines_with(DecodedLines::new(reader), callback)
+}
+
+/// Same as parse_file_by_line, but accepts a closure able to capture its environment.
+fn parse_file_by_line_with<P, T, E, C, F>(filename: P, callback: F) -> Result<C, ParserError>
+    where P: AsRef<Path>, E: Display, C: FromIterator<T>, F: FnMut(&str) -> Result<T, E> {
+    let filename = filename.as_ref();
+
+    parse_lines_with(lines_iter(filename)?, callback).map_err(|error| error.with_filename(filename))
+}
+
+/// Gets the lines of a string, as a reader would yield them.
+fn str_lines(input: &str) -> impl Iterator<Item = Result<String, IOError>> + '_ {
+    strip_bom(input).lines().map(|line| Ok(line.to_string()))
+}
+
+/// Parses lines into a collection through a closure, reporting errors with the line number.
+fn parse_lines_with<I, T, E, C, F>(lines: I, mut callback: F) -> Result<C, ParserError>
+    where I: Iterator<Item = Result<String, IOError>>, E: Display, C: FromIterator<T>, F: FnMut(&str) -> Result<T, E> {
+    parse_lines_located(lines, |line_number, line| {
+        callback(line).map_err(|error| ParserError::parse(line_number, line, error))
+    })
+}
+
+/// Parses lines into a collection through a closure building its own errors,
+/// so they can locate the offending text in the line.
+fn parse_lines_located<I, T, C, F>(lines: I, mut callback: F) -> Result<C, ParserError>
+    where I: Iterator<Item = Result<String, IOError>>, C: FromIterator<T>, F: FnMut(usize, &str) -> Result<T, ParserError> {
+    lines
+        .enumerate()
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            let line = line.map_err(|error| ParserError::IO { line_number: Some(line_number), error })?;
+
+            callback(line_number, &line)
+        })
+        .collect()
+}
+
+/// Attempts to open and parse a file line by line, each line with FromStr.
+///
+/// An error is reported as a ParserError::Parse with the line number.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<u32> = parser::parse_lines_as("tests/parser/ascii.txt").unwrap();
+/// assert_eq!(vec![64, 65, 66, 67], numbers);
+/// ```
+pub fn parse_lines_as<T, P>(filename: P) -> Result<Vec<T>, ParserError>
+where T: FromStr, T::Err: Display, P: AsRef<Path> {
+    parse_file_by_line(filename, str::parse::<T>)
+}
+
+/// Attempts to open and parse a file of values separated by a separator,
+/// like 1,22,3,44, usually written on a single line.
+///
+/// Values are trimmed and empty values are ignored, so trailing
+/// new lines, spaces or separators don't matter.
+/// An error is reported as a ParserError::Parse with the line number.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<u32> = parser::parse_separated_values_file("tests/parser/ascii.txt", ",").unwrap();
+/// assert_eq!(vec![64, 65, 66, 67], numbers);
+/// ```
+pub fn parse_separated_values_file<T, P>(filename: P, separator: &str) -> Result<Vec<T>, ParserError>
+where T: FromStr, T::Err: Display, P: AsRef<Path> {
+    let filename = filename.as_ref();
+
+    parse_separated_values_lines(lines_iter(filename)?, separator).map_err(|error| error.with_filename(filename))
+}
+
+/// Parses a string of values separated by a separator, like 1,22,3,44.
+///
+/// Values are trimmed and empty values are ignored.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let numbers: Vec<i32> = parser::parse_separated_values_str(" 1, -22,3,44,\n", ",").unwrap();
+/// assert_eq!(vec![1, -22, 3, 44], numbers);
+/// ```
+pub fn parse_separated_values_str<T>(input: &str, separator: &str) -> Result<Vec<T>, ParserError>
+where T: FromStr, T::Err: Display {
+    parse_separated_values_lines(str_lines(input), separator)
+}
+
+/// Parses a line of values separated by a separator.
+///
+/// Values are trimmed and empty values are ignored.
+pub fn parse_separated_values_line<T>(line: &str, separator: &str) -> Result<Vec<T>, String>
+where T: FromStr, T::Err: Display {
+    separated_values(line, separator).map_err(|(_, message)| message)
+}
+
+/// Parses a line of values separated by a separator,
+/// giving the offending value on failure.
+fn separated_values<'a, T>(line: &'a str, separator: &str) -> Result<Vec<T>, (&'a str, String)>
+where T: FromStr, T::Err: Display {
+    line.split(separator)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().map_err(|error| (value, format!("invalid value {:?}: {}", value, error))))
+        .collect()
+}
+
+fn parse_separated_values_lines<I, T>(lines: I, separator: &str) -> Result<Vec<T>, ParserError>
+where I: Iterator<Item = Result<String, IOError>>, T: FromStr, T::Err: Display {
+    let values: Vec<Vec<T>> = parse_lines_located(lines, |line_number, line| {
+        separated_values(line, separator)
+            .map_err(|(value, message)| ParserError::parse_at(line_number, line, value, message))
+    })?;
+
+    Ok(values.into_iter().flatten().collect())
+}
+
+/// Attempts to open and parse a file composed of blocks of lines,
+/// with a callback receiving a slice of n lines of text.
+///
+/// The callback function will be used as a map and receive a &[String] as parameter.
+/// The slice will contain <lines_per_block> strings.
+/// It returns a Result: an error is reported as a ParserError::Parse
+/// with the number of the first line of the block.
+///
+/// The type should be compatible with collect to build a vector of your collection type.
+///
+/// Function returns a type collected from an iterator yielding T, for example Vec<T>.
+/// T is the type returned by your callback method.
+pub fn parse_file_by_lines_block<P, T, E, C>(filename: P, lines_per_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where P: AsRef<Path>, E: Display, C: FromIterator<T> {
+    parse_file_by_lines_block_with_blank_lines_separator(filename, lines_per_block, 0, callback)
 }
 
 /// Attempts to open and parse a file composed of blocks of lines,
-/// with a callback receiving a vector of n lines of text.
+/// with a callback receiving a slice of n lines of text.
 ///
 /// Each block is separated by <separator_lines_between_block> lines to ignore.
 ///
-/// The callback function will be used as a map and receive a Vec<String> as parameter.
-/// The vector will contain <lines_per_block> strings.
+/// The callback function will be used as a map and receive a &[String] as parameter.
+/// The slice will contain <lines_per_block> strings.
+/// It returns a Result: an error is reported as a ParserError::Parse
+/// with the number of the first line of the block.
 ///
 /// The type should be compatible with collect to build a vector of your collection type.
 ///
 /// Function returns a type collected from an iterator yielding T, for example Vec<T>.
 /// T is the type returned by your callback method.
-pub fn parse_file_by_lines_block_with_blank_lines_separator<P, T, C>(filename: P, lines_per_block: usize, separator_lines_between_block: usize, callback: fn(Vec<String>) -> T) -> Result<C, IOError>
-where P: AsRef<Path>, C: FromIterator<T> {
-    let fd = File::open(filename)?;
-    let lines = BufReader::new(fd).lines();
+pub fn parse_file_by_lines_block_with_blank_lines_separator<P, T, E, C>(filename: P, lines_per_block: usize, separator_lines_between_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where P: AsRef<Path>, E: Display, C: FromIterator<T> {
+    let filename = filename.as_ref();
+    let blocks = lines_block_with_blank_lines_separator_iter(filename, lines_per_block, separator_lines_between_block)?;
 
-    let mut items = Vec::new();
-    let mut block_lines = Vec::new();
-    let mut lines_to_ignore = 0;
+    parse_blocks(blocks, callback).map_err(|error| error.with_filename(filename))
+}
 
-    for line in lines {
-        if lines_to_ignore > 0 {
-            lines_to_ignore -= 1;
-            continue;
-        }
+/// Parses a string composed of blocks of lines,
+/// with a callback receiving a slice of n lines of text.
+///
+/// That's the same as parse_file_by_lines_block, for embedded inputs like test strings.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let pairs: Vec<String> = parser::parse_str_by_lines_block("a\nb\nc\nd", 2, |lines| Ok::<_, String>(lines.concat())).unwrap();
+/// assert_eq!(vec!["ab", "cd"], pairs);
+/// ```
+pub fn parse_str_by_lines_block<T, E, C>(input: &str, lines_per_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T> {
+    parse_str_by_lines_block_with_blank_lines_separator(input, lines_per_block, 0, callback)
+}
 
-        block_lines.push(line?);
+/// Parses a string composed of blocks of lines separated by
+/// <separator_lines_between_block> lines to ignore,
+/// with a callback receiving a slice of n lines of text.
+pub fn parse_str_by_lines_block_with_blank_lines_separator<T, E, C>(input: &str, lines_per_block: usize, separator_lines_between_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where E: Display, C: FromIterator<T> {
+    parse_blocks(LinesBlocks::new(str_lines(input), lines_per_block, separator_lines_between_block), callback)
+}
 
-        if block_lines.len() == lines_per_block {
-            items.push(callback(block_lines));
-            block_lines = Vec::new();
-            lines_to_ignore = separator_lines_between_block;
-        }
+/// Parses a reader composed of blocks of lines,
+/// with a callback receiving a slice of n lines of text.
+///
+/// That's the same as parse_file_by_lines_block, for any buffered input like stdin.
+pub fn parse_reader_by_lines_block<R, T, E, C>(reader: R, lines_per_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where R: BufRead, E: Display, C: FromIterator<T> {
+    parse_reader_by_lines_block_with_blank_lines_separator(reader, lines_per_block, 0, callback)
+}
+
+/// Parses a reader composed of blocks of lines separated by
+/// <separator_lines_between_block> lines to ignore,
+/// with a callback receiving a slice of n lines of text.
+pub fn parse_reader_by_lines_block_with_blank_lines_separator<R, T, E, C>(reader: R, lines_per_block: usize, separator_lines_between_block: usize, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where R: BufRead, E: Display, C: FromIterator<T> {
+    parse_blocks(LinesBlocks::new(DecodedLines::new(reader), lines_per_block, separator_lines_between_block), callback)
+}
+
+/// Parses blocks of lines through a callback, reporting errors
+/// with the number of the first line of the block.
+fn parse_blocks<I, T, E, C>(mut blocks: LinesBlocks<I>, callback: fn(&[String]) -> Result<T, E>) -> Result<C, ParserError>
+where I: Iterator<Item = Result<String, IOError>>, E: Display, C: FromIterator<T> {
+    let mut items = Vec::new();
+
+    while let Some(block_lines) = blocks.next() {
+        let block_lines = block_lines?;
+
+        let item = callback(&block_lines)
+            .map_err(|error| ParserError::parse(blocks.block_line_number(), &block_lines.join("\n"), error))?;
+        items.push(item);
     }
 
     Ok(items.into_iter().collect())
@@ -98,8 +417,82 @@ where P: AsRef<Path>, C: FromIterator<T> {
 /// let actual_digits = parser::parse_digits_grid_file("tests/parser/digits.dat").unwrap();
 /// assert_eq!(actual_digits, expected_digits);
 /// ```
-pub fn parse_digits_grid_file<P>(filename: P) -> Result<Vec<Vec<u32>>, IOError> where P: AsRef<Path> {
-    parse_file_by_line(filename, |line| parse_digits_grid_line(&line.unwrap()).unwrap())
+pub fn parse_digits_grid_file<P>(filename: P) -> Result<Vec<Vec<u32>>, ParserError> where P: AsRef<Path> {
+    parse_digits_grid_file_with_options(filename, &DigitsGridOptions::default())
+}
+
+/// Describes what to do when a character of a digits grid isn't a digit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvalidDigitPolicy {
+    /// Reports a parse error
+    #[default]
+    Fail,
+
+    /// Ignores the character, so the row is shorter
+    Skip,
+
+    /// Replaces the character by the specified value
+    Substitute(u32),
+}
+
+/// Options to parse a digits grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitsGridOptions {
+    /// The radix of the digits, 10 by default, from 2 to 36
+    pub radix: u32,
+
+    /// What to do with characters that aren't digits in that radix
+    pub invalid_digit: InvalidDigitPolicy,
+}
+
+impl Default for DigitsGridOptions {
+    fn default() -> Self {
+        Self {
+            radix: 10,
+            invalid_digit: InvalidDigitPolicy::Fail,
+        }
+    }
+}
+
+/// Attempts to open and parse a file containing digits into a vector of u32 vectors,
+/// handling invalid characters as described by the options.
+///
+/// ```
+/// use enontekio::parser::{self, DigitsGridOptions, InvalidDigitPolicy};
+///
+/// let options = DigitsGridOptions {
+///     invalid_digit: InvalidDigitPolicy::Substitute(0),
+///     ..Default::default()
+/// };
+/// let digits = parser::parse_digits_grid_file_with_options("tests/parser/chars.dat", &options).unwrap();
+/// assert_eq!(vec![0, 0, 0, 0, 0], digits[0]);
+/// ```
+pub fn parse_digits_grid_file_with_options<P>(filename: P, options: &DigitsGridOptions) -> Result<Vec<Vec<u32>>, ParserError> where P: AsRef<Path> {
+    let filename = filename.as_ref();
+
+    parse_digits_grid_lines(lines_iter(filename)?, options).map_err(|error| error.with_filename(filename))
+}
+
+/// Parses a string containing digits into a vector of u32 vectors,
+/// handling invalid characters as described by the options.
+///
+/// ```
+/// use enontekio::parser::{self, DigitsGridOptions};
+///
+/// let digits = parser::parse_digits_grid_str("12\n34", &DigitsGridOptions::default()).unwrap();
+/// assert_eq!(vec![vec![1, 2], vec![3, 4]], digits);
+/// ```
+pub fn parse_digits_grid_str(input: &str, options: &DigitsGridOptions) -> Result<Vec<Vec<u32>>, ParserError> {
+    parse_digits_grid_lines(str_lines(input), options)
+}
+
+fn parse_digits_grid_lines<I>(lines: I, options: &DigitsGridOptions) -> Result<Vec<Vec<u32>>, ParserError>
+where I: Iterator<Item = Result<String, IOError>> {
+    parse_lines_located(lines, |line_number, line| {
+        digits(line, options).map_err(|(column, message)| {
+            ParserError::parse(line_number, line, message).with_column(column)
+        })
+    })
 }
 
 /// Parses a string into a vector of digits
@@ -112,10 +505,40 @@ pub fn parse_digits_grid_file<P>(filename: P) -> Result<Vec<Vec<u32>>, IOError>
 /// assert_eq!(actual_digits, expected_digits);
 /// ```
 pub fn parse_digits_grid_line(line: &str) -> Option<Vec<u32>> {
-    line
-        .chars()
-        .map(|c| c.to_digit(10))
-        .collect()
+    parse_digits_grid_line_with_options(line, &DigitsGridOptions::default()).ok()
+}
+
+/// Parses a string into a vector of digits,
+/// handling invalid characters as described by the options.
+///
+/// On failure, returns a message describing the first invalid character and its column,
+/// starting at 1, or the invalid radix.
+pub fn parse_digits_grid_line_with_options(line: &str, options: &DigitsGridOptions) -> Result<Vec<u32>, String> {
+    digits(line, options).map_err(|(column, message)| match column {
+        Some(column) => format!("{} at column {}", message, column),
+        None => message,
+    })
+}
+
+/// Parses a string into a vector of digits,
+/// giving the column, if any, and a message on failure.
+fn digits(line: &str, options: &DigitsGridOptions) -> Result<Vec<u32>, (Option<usize>, String)> {
+    if !(2..=36).contains(&options.radix) {
+        return Err((None, format!("invalid radix {}, expected 2 to 36", options.radix)));
+    }
+
+    let mut digits = Vec::with_capacity(line.len());
+
+    for (index, c) in line.chars().enumerate() {
+        match (c.to_digit(options.radix), options.invalid_digit) {
+            (Some(digit), _) => digits.push(digit),
+            (None, InvalidDigitPolicy::Fail) => return Err((Some(index + 1), format!("invalid digit {:?}", c))),
+            (None, InvalidDigitPolicy::Skip) => {}
+            (None, InvalidDigitPolicy::Substitute(digit)) => digits.push(digit),
+        }
+    }
+
+    Ok(digits)
 }
 
 /// Attempts to open and parse a file containing characters into a vector of char vectors
@@ -134,8 +557,19 @@ pub fn parse_digits_grid_line(line: &str) -> Option<Vec<u32>> {
 /// let actual_chars = parser::parse_chars_grid_file("tests/parser/chars.dat").unwrap();
 /// assert_eq!(expected_chars, actual_chars);
 /// ```
-pub fn parse_chars_grid_file<P>(filename: P) -> Result<Vec<Vec<char>>, IOError> where P: AsRef<Path> {
-    parse_file_by_line(filename, |line| parse_chars_grid_line(&line.unwrap()))
+pub fn parse_chars_grid_file<P>(filename: P) -> Result<Vec<Vec<char>>, ParserError> where P: AsRef<Path> {
+    parse_file_by_line(filename, |line| Ok::<_, Infallible>(parse_chars_grid_line(line)))
+}
+
+/// Parses a string containing characters into a vector of char vectors.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// assert_eq!(vec![vec!['a', 'b'], vec!['c', 'd']], parser::parse_chars_grid_str("ab\ncd"));
+/// ```
+pub fn parse_chars_grid_str(input: &str) -> Vec<Vec<char>> {
+    strip_bom(input).lines().map(parse_chars_grid_line).collect()
 }
 
 /// Parses a string into a vector of digits
@@ -153,6 +587,59 @@ pub fn parse_chars_grid_line(line: &str) -> Vec<char> {
         .collect()
 }
 
+/// Attempts to open and parse a map, keeping only the coordinates of the cells
+/// containing the marker character.
+///
+/// Coordinates follow the convention of grids and SparseGrid: (i, j) where
+/// i is the row, j the column, the origin (0, 0) being the first character
+/// of the first line. Lines can have different lengths.
+///
+/// For example, with the file `chars.dat`:
+///     abcde
+///     xizzy
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let cells = parser::parse_sparse_map_file("tests/parser/chars.dat", 'z').unwrap();
+///
+/// assert_eq!(2, cells.len());
+/// assert!(cells.contains(&(1, 2)));
+/// assert!(cells.contains(&(1, 3)));
+/// ```
+pub fn parse_sparse_map_file<P>(filename: P, marker: char) -> Result<HashSet<(i64, i64)>, ParserError> where P: AsRef<Path> {
+    let lines: Vec<String> = parse_file_by_line(filename, |line| Ok::<_, Infallible>(line.to_string()))?;
+
+    Ok(sparse_map_cells(lines.iter().map(String::as_str), marker))
+}
+
+/// Parses a map, keeping only the coordinates of the cells containing the marker character.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let cells = parser::parse_sparse_map_str("..#\n#..", '#');
+///
+/// assert_eq!(2, cells.len());
+/// assert!(cells.contains(&(0, 2)));
+/// assert!(cells.contains(&(1, 0)));
+/// ```
+pub fn parse_sparse_map_str(input: &str, marker: char) -> HashSet<(i64, i64)> {
+    sparse_map_cells(strip_bom(input).lines(), marker)
+}
+
+fn sparse_map_cells<'a, I>(lines: I, marker: char) -> HashSet<(i64, i64)> where I: Iterator<Item = &'a str> {
+    lines
+        .enumerate()
+        .flat_map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .filter(move |&(_, c)| c == marker)
+                .map(move |(j, _)| (i as i64, j as i64))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -163,7 +650,7 @@ mod tests {
         let expected = vec!['@', 'A', 'B', 'C'];
         let actual: Vec<_> = parse_file_by_line(
             "tests/parser/ascii.txt",
-            |line| line.unwrap().parse::<u8>().unwrap() as char
+            |line| line.parse::<u8>().map(|n| n as char)
         ).unwrap();
 
         assert_eq!(actual, expected);
@@ -171,26 +658,235 @@ mod tests {
 
     #[test]
     fn test_parse_file_by_line_with_non_existing_file() {
-        let result: Result<Vec<_>, _> = parse_file_by_line("/nonexisting", |_| ());
+        let result: Result<Vec<_>, _> = parse_file_by_line("/nonexisting", |_| Ok::<_, Infallible>(()));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lines_iter_is_lazy() {
+        let mut lines = lines_iter("tests/parser/ascii.txt").unwrap();
+
+        assert_eq!("64", lines.next().unwrap().unwrap());
+        assert_eq!(3, lines.count());
+    }
+
+    #[test]
+    fn test_lines_block_iter() {
+        let blocks: Vec<_> = lines_block_iter("tests/parser/ascii.txt", 2).unwrap()
+            .map(|block| block.unwrap())
+            .collect();
 
-        assert_eq!(true, result.is_err());
+        assert_eq!(vec![vec!["64", "65"], vec!["66", "67"]], blocks);
+    }
+
+    #[test]
+    fn test_lines_block_iter_without_lines_per_block() {
+        assert_eq!(0, lines_block_iter("tests/parser/ascii.txt", 0).unwrap().count());
+    }
+
+    #[test]
+    fn test_lines_block_with_blank_lines_separator_iter() {
+        let blocks: Vec<_> = lines_block_with_blank_lines_separator_iter("tests/parser/ascii.txt", 1, 1).unwrap()
+            .map(|block| block.unwrap())
+            .collect();
+
+        assert_eq!(vec![vec!["64"], vec!["66"]], blocks);
+    }
+
+    #[test]
+    fn test_parse_file_by_line_reports_line_number() {
+        let result: Result<Vec<_>, _> = parse_file_by_line(
+            "tests/parser/ascii.txt",
+            |line| if line == "66" { Err("unexpected B") } else { Ok(()) }
+        );
+
+        match result {
+            Err(ParserError::Parse { line_number, line, message, .. }) => {
+                assert_eq!(3, line_number);
+                assert_eq!("66", line);
+                assert_eq!("unexpected B", message);
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_as_reports_line_number() {
+        let numbers: Vec<u8> = parse_lines_as("tests/parser/ascii.txt").unwrap();
+        assert_eq!(4, numbers.len());
+
+        let error = parse_lines_as::<u8, _>("tests/parser/chars.dat").unwrap_err();
+        assert_eq!(Some(1), error.line_number());
+    }
+
+    #[test]
+    fn test_parse_reader_by_line_reports_line_number() {
+        let reader = std::io::Cursor::new("1\n2\nthree\n");
+        let error = parse_reader_by_line::<_, u32, _, Vec<_>>(reader, |line| line.parse()).unwrap_err();
+
+        assert_eq!(Some(3), error.line_number());
+    }
+
+    #[test]
+    fn test_parse_reader_by_lines_block_with_blank_lines_separator() {
+        let reader = std::io::Cursor::new("64\n65\n\n66\n67\n");
+        let blocks: Vec<String> = parse_reader_by_lines_block_with_blank_lines_separator(
+            reader,
+            2,
+            1,
+            |lines| Ok::<_, Infallible>(lines.join(","))
+        ).unwrap();
+
+        assert_eq!(vec!["64,65", "66,67"], blocks);
+    }
+
+    #[test]
+    fn test_parse_separated_values() {
+        let numbers: Vec<u8> = parse_separated_values_str("1 2  3\n\n4 ", " ").unwrap();
+        assert_eq!(vec![1, 2, 3, 4], numbers);
+
+        let error = parse_separated_values_str::<u8>("1,2\n3,x", ",").unwrap_err();
+        assert_eq!(Some(2), error.line_number());
+    }
+
+    #[test]
+    fn test_parse_file_by_lines_block() {
+        let blocks: Vec<String> = parse_file_by_lines_block(
+            "tests/parser/ascii.txt",
+            2,
+            |lines| Ok::<_, Infallible>(lines.join(","))
+        ).unwrap();
+
+        assert_eq!(vec!["64,65", "66,67"], blocks);
+    }
+
+    #[test]
+    fn test_parse_file_by_lines_block_ignores_trailing_blank_lines() {
+        let reader = std::io::Cursor::new("64\n65\n66\n\n");
+        let blocks: Vec<String> = parse_reader_by_lines_block(
+            reader,
+            3,
+            |lines| Ok::<_, Infallible>(lines.join(","))
+        ).unwrap();
+
+        assert_eq!(vec!["64,65,66"], blocks);
+    }
+
+    #[test]
+    fn test_parse_file_by_lines_block_reports_incomplete_block() {
+        let result: Result<Vec<String>, _> = parse_file_by_lines_block(
+            "tests/parser/ascii.txt",
+            3,
+            |lines| Ok::<_, Infallible>(lines.join(","))
+        );
+
+        match result {
+            Err(ParserError::UnexpectedBlockSize { line_number, expected, lines }) => {
+                assert_eq!(4, line_number);
+                assert_eq!(3, expected);
+                assert_eq!(vec!["67"], lines);
+            }
+            _ => panic!("Expected an unexpected block size error"),
+        }
     }
 
     #[test]
     fn test_parse_digits_grid_file() {
-        let expected_digits = vec![vec![1 as u32, 2, 3, 4, 5, 5], vec![8, 9, 1, 2, 4, 5]];
+        let expected_digits = vec![vec![1, 2, 3, 4, 5, 5], vec![8, 9, 1, 2, 4, 5]];
         let actual_digits = parse_digits_grid_file("tests/parser/digits.dat").unwrap();
 
         assert_eq!(actual_digits, expected_digits);
     }
 
+    #[test]
+    fn test_parse_digits_grid_file_with_invalid_digit() {
+        let error = parse_digits_grid_file("tests/parser/chars.dat").unwrap_err();
+
+        assert_eq!(Some(1), error.line_number());
+    }
+
+    #[test]
+    fn test_parse_digits_grid_line_with_options() {
+        let skip = DigitsGridOptions { invalid_digit: InvalidDigitPolicy::Skip, ..Default::default() };
+        assert_eq!(Ok(vec![1, 2, 3]), parse_digits_grid_line_with_options("1.2#3", &skip));
+
+        let substitute = DigitsGridOptions { invalid_digit: InvalidDigitPolicy::Substitute(9), ..Default::default() };
+        assert_eq!(Ok(vec![1, 9, 2]), parse_digits_grid_line_with_options("1.2", &substitute));
+
+        let hexadecimal = DigitsGridOptions { radix: 16, ..Default::default() };
+        assert_eq!(Ok(vec![10, 15, 1]), parse_digits_grid_line_with_options("af1", &hexadecimal));
+
+        assert_eq!(
+            Err("invalid digit 'x' at column 3".to_string()),
+            parse_digits_grid_line_with_options("12x", &DigitsGridOptions::default())
+        );
+
+        let too_large = DigitsGridOptions { radix: 37, ..Default::default() };
+        assert_eq!(
+            Err("invalid radix 37, expected 2 to 36".to_string()),
+            parse_digits_grid_line_with_options("12", &too_large)
+        );
+        assert!(parse_digits_grid_str("12", &too_large).is_err());
+    }
+
     #[test]
     fn test_parse_digits_grid_line() {
-        assert_eq!(vec![1 as u32, 2, 3, 4, 5], parse_digits_grid_line("12345").unwrap());
+        assert_eq!(vec![1, 2, 3, 4, 5], parse_digits_grid_line("12345").unwrap());
     }
 
     #[test]
     fn parse_digits_grid_line_when_it_is_not() {
         assert_eq!(None, parse_digits_grid_line("This is not a digits line."));
     }
+
+    #[test]
+    fn test_parse_sparse_map_str() {
+        let cells = parse_sparse_map_str("#\n..#\n\n.#", '#');
+
+        assert_eq!(HashSet::from([(0, 0), (1, 2), (3, 1)]), cells);
+        assert!(parse_sparse_map_str("...", '#').is_empty());
+    }
+
+    #[test]
+    fn test_byte_order_mark_is_ignored() {
+        let numbers: Vec<u32> = parse_lines_as("tests/parser/bom.txt").unwrap();
+        assert_eq!(vec![64, 65], numbers);
+
+        assert_eq!(vec![vec!['a']], parse_chars_grid_str("\u{feff}a"));
+    }
+
+    #[test]
+    fn test_parse_errors_locate_offending_text() {
+        let error = parse_separated_values_file::<u32, _>("tests/parser/sections.txt", "|").unwrap_err();
+
+        assert_eq!(Some(Path::new("tests/parser/sections.txt")), error.filename());
+        assert_eq!(Some(5), error.line_number());
+        assert_eq!(Some(1), error.column());
+        assert!(error.to_string().starts_with("tests/parser/sections.txt: line 5, column 1: invalid value \"75,47,61,53\""));
+
+        let error = parse_separated_values_str::<u32>("1, 2, x3", ",").unwrap_err();
+        assert_eq!(None, error.filename());
+        assert_eq!(Some(7), error.column());
+        assert_eq!(
+            Some("  --> line 1, column 7\n  |\n1 | 1, 2, x3\n  |       ^ invalid value \"x3\": invalid digit found in string\n".to_string()),
+            error.snippet(),
+        );
+    }
+
+    #[test]
+    fn test_snippet_without_column() {
+        let result: Result<Vec<u8>, _> = parse_file_by_line("tests/parser/chars.dat", |line| line.parse());
+        let snippet = result.unwrap_err().snippet().unwrap();
+
+        assert!(snippet.starts_with("  --> tests/parser/chars.dat:1\n"));
+        assert!(snippet.ends_with("  | ^^^^^ invalid digit found in string\n"));
+    }
+
+    #[test]
+    fn test_snippet_with_zero_column() {
+        let error = ParserError::parse(1, "x", "invalid value").with_column(Some(0));
+
+        assert_eq!(Some("  --> line 1, column 0\n  |\n1 | x\n  | ^ invalid value\n".to_string()), error.snippet());
+    }
 }
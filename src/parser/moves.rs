@@ -0,0 +1,147 @@
+use std::path::Path;
+
+use crate::parser::{parse_file_by_line_with, ParserError};
+
+/// A move, as a (direction_vector, magnitude) pair
+pub type Move = ((i32, i32), i64);
+
+/// Maps letters or words like "R", "N" or "forward" to 2D direction vectors,
+/// to parse movement instructions into (direction_vector, magnitude) pairs.
+///
+/// Direction vectors are (delta_i, delta_j), like in the grids of the crate:
+/// up is (-1, 0), down (1, 0), left (0, -1), right (0, 1).
+///
+/// Names are case-sensitive.
+///
+/// ```
+/// use enontekio::parser::DirectionMap;
+///
+/// let map = DirectionMap::udlr();
+///
+/// assert_eq!(Ok(((0, 1), 5)), map.parse_move("R5"));
+/// assert_eq!(Ok(vec![((-1, 0), 2), ((0, -1), 10)]), map.parse_moves("U2,L10", ","));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirectionMap {
+    directions: Vec<(String, (i32, i32))>,
+}
+
+impl DirectionMap {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a map for U, D, L and R.
+    pub fn udlr() -> Self {
+        Self::new()
+            .with("U", (-1, 0))
+            .with("D", (1, 0))
+            .with("L", (0, -1))
+            .with("R", (0, 1))
+    }
+
+    /// Creates a map for the cardinal points N, S, W and E, north being up.
+    pub fn compass() -> Self {
+        Self::new()
+            .with("N", (-1, 0))
+            .with("S", (1, 0))
+            .with("W", (0, -1))
+            .with("E", (0, 1))
+    }
+
+    /// Creates a map for the words up, down, left and right,
+    /// forward going right and backward going left.
+    pub fn words() -> Self {
+        Self::new()
+            .with("up", (-1, 0))
+            .with("down", (1, 0))
+            .with("left", (0, -1))
+            .with("right", (0, 1))
+            .with("forward", (0, 1))
+            .with("backward", (0, -1))
+    }
+
+    /// Adds a name for a direction vector, replacing any previous mapping of that name.
+    pub fn with(mut self, name: &str, vector: (i32, i32)) -> Self {
+        self.directions.retain(|(known_name, _)| known_name != name);
+        self.directions.push((name.to_string(), vector));
+        self
+    }
+
+    /// Gets the direction vector of a name.
+    pub fn get(&self, name: &str) -> Option<(i32, i32)> {
+        self.directions.iter()
+            .find(|(known_name, _)| known_name == name)
+            .map(|&(_, vector)| vector)
+    }
+
+    /// Parses a move, as a name followed by a magnitude, like "R5" or "forward 8".
+    ///
+    /// The name and the magnitude can be separated by whitespace.
+    /// Without whitespace, the name is everything before the first digit or sign.
+    pub fn parse_move(&self, instruction: &str) -> Result<Move, String> {
+        let instruction = instruction.trim();
+
+        let (name, magnitude) = match instruction.split_once(char::is_whitespace) {
+            Some((name, magnitude)) => (name, magnitude.trim()),
+            None => {
+                let position = instruction
+                    .find(|c: char| c.is_ascii_digit() || c == '-' || c == '+')
+                    .unwrap_or(instruction.len());
+
+                instruction.split_at(position)
+            }
+        };
+
+        let vector = self.get(name)
+            .ok_or_else(|| format!("unknown direction {:?}", name))?;
+        let magnitude = magnitude.parse()
+            .map_err(|_| format!("invalid magnitude {:?}", magnitude))?;
+
+        Ok((vector, magnitude))
+    }
+
+    /// Parses several moves on the same line, like "R8,U5,L5".
+    pub fn parse_moves(&self, line: &str, separator: &str) -> Result<Vec<Move>, String> {
+        line.split(separator)
+            .map(|instruction| self.parse_move(instruction))
+            .collect()
+    }
+
+    /// Attempts to open and parse a file with one move per line.
+    pub fn parse_moves_file<P>(&self, filename: P) -> Result<Vec<Move>, ParserError> where P: AsRef<Path> {
+        parse_file_by_line_with(filename, |line| self.parse_move(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_move() {
+        assert_eq!(Ok(((0, 1), 8)), DirectionMap::words().parse_move("forward 8"));
+        assert_eq!(Ok(((-1, 0), 10)), DirectionMap::compass().parse_move("N10"));
+        assert_eq!(Ok(((0, -1), -3)), DirectionMap::udlr().parse_move(" L-3 "));
+    }
+
+    #[test]
+    fn test_parse_move_errors() {
+        let map = DirectionMap::udlr();
+
+        assert!(map.parse_move("X5").is_err());
+        assert!(map.parse_move("R").is_err());
+        assert!(map.parse_move("r5").is_err());
+    }
+
+    #[test]
+    fn test_custom_mapping() {
+        let map = DirectionMap::udlr()
+            .with("U", (1, 0))
+            .with("F", (0, 1));
+
+        assert_eq!(Some((1, 0)), map.get("U"));
+        assert_eq!(Ok(vec![((1, 0), 1), ((0, 1), 2)]), map.parse_moves("U1 F2", " "));
+    }
+}
@@ -0,0 +1,97 @@
+/// Parses a human-readable number, like "15k", "2.5M" or "1_000,000".
+///
+/// The number can end with a multiplier suffix:
+/// k or K for a thousand, M for a million, G for a billion.
+/// Underscores and commas are digit separators, and are ignored.
+///
+/// A decimal part is allowed as long as the value is an integer,
+/// so "1.5k" is 1500 but "1.5" is an error.
+///
+/// ```
+/// use enontekio::parser::parse_number_with_suffix;
+///
+/// assert_eq!(Ok(15_000), parse_number_with_suffix("15k"));
+/// assert_eq!(Ok(-2_500_000), parse_number_with_suffix("-2.5M"));
+/// assert_eq!(Ok(1_000_000), parse_number_with_suffix("1,000_000"));
+/// assert!(parse_number_with_suffix("15x").is_err());
+/// ```
+pub fn parse_number_with_suffix(input: &str) -> Result<i64, String> {
+    let invalid = || format!("invalid number {:?}", input);
+    let overflow = || format!("number {:?} is too large", input);
+
+    let number = input.trim();
+    let (number, multiplier) = match number.chars().last() {
+        Some('k' | 'K') => (&number[..number.len() - 1], 1_000i128),
+        Some('M') => (&number[..number.len() - 1], 1_000_000),
+        Some('G') => (&number[..number.len() - 1], 1_000_000_000),
+        _ => (number, 1),
+    };
+
+    let (negative, number) = match number.strip_prefix('-') {
+        Some(number) => (true, number),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+
+    let digits: String = number.chars().filter(|&c| c != '_' && c != ',').collect();
+    let (integer_part, decimal_part) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let is_valid = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if integer_part.is_empty() || !is_valid(integer_part) || !is_valid(decimal_part) {
+        return Err(invalid());
+    }
+
+    // The magnitude is computed on a wider type, so i64::MIN can be parsed
+    let mut magnitude = integer_part.parse::<i128>().ok()
+        .and_then(|integer| integer.checked_mul(multiplier))
+        .ok_or_else(overflow)?;
+
+    // Trailing zeros don't change the value, so "1.000k" is fine
+    let decimal_part = decimal_part.trim_end_matches('0');
+    if !decimal_part.is_empty() {
+        // The decimal part is fraction / 10^len, so it adds fraction * multiplier / 10^len,
+        // which must be an integer. A decimal part too long for the computation can't be.
+        let decimals = decimal_part.parse::<i128>().ok()
+            .and_then(|fraction| fraction.checked_mul(multiplier))
+            .zip(10i128.checked_pow(decimal_part.len() as u32))
+            .filter(|(scaled, scale)| scaled % scale == 0)
+            .map(|(scaled, scale)| scaled / scale)
+            .ok_or_else(|| format!("number {:?} isn't an integer", input))?;
+
+        magnitude = magnitude.checked_add(decimals).ok_or_else(overflow)?;
+    }
+
+    let value = if negative { -magnitude } else { magnitude };
+    i64::try_from(value).map_err(|_| overflow())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_with_suffix() {
+        assert_eq!(Ok(42), parse_number_with_suffix(" 42 "));
+        assert_eq!(Ok(3_000), parse_number_with_suffix("3K"));
+        assert_eq!(Ok(7_000_000_000), parse_number_with_suffix("7G"));
+        assert_eq!(Ok(1_250), parse_number_with_suffix("+1.250k"));
+        assert_eq!(Ok(1_000), parse_number_with_suffix("1.000k"));
+        assert_eq!(Ok(2_000_010), parse_number_with_suffix("2.00001M"));
+        assert_eq!(Ok(i64::MIN), parse_number_with_suffix("-9223372036854775808"));
+        assert_eq!(Ok(i64::MAX), parse_number_with_suffix("9223372036854775807"));
+    }
+
+    #[test]
+    fn test_parse_number_with_suffix_errors() {
+        assert!(parse_number_with_suffix("").is_err());
+        assert!(parse_number_with_suffix("k").is_err());
+        assert!(parse_number_with_suffix("1.5").is_err());
+        assert!(parse_number_with_suffix("1.0005k").is_err());
+        assert!(parse_number_with_suffix("1.01").is_err());
+        assert!(parse_number_with_suffix("2.00001k").is_err());
+        assert!(parse_number_with_suffix("1.5000000000000000000000000000000000000001G").is_err());
+        assert!(parse_number_with_suffix("9223372036854775808").is_err());
+        assert!(parse_number_with_suffix("1m").is_err());
+        assert!(parse_number_with_suffix("--1").is_err());
+        assert!(parse_number_with_suffix("10000000000G").is_err());
+    }
+}
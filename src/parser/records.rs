@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::parser::sections::{blank_line_separated_blocks, split_blank_line_separated_blocks};
+use crate::parser::{str_lines, ParserError};
+
+/// A record, made of key:value pairs
+pub type Record = HashMap<String, String>;
+
+/// Types built from a record of key:value pairs
+pub trait FromRecord: Sized {
+    fn from_record(record: &Record) -> Result<Self, String>;
+}
+
+/// Parses key:value pairs, separated by whitespace or new lines.
+///
+/// A key can't be repeated. The value can contain ':'.
+///
+/// ```
+/// use enontekio::parser::parse_record;
+///
+/// let record = parse_record("ecl:gry pid:860033327\nhcl:#fffffd").unwrap();
+///
+/// assert_eq!(3, record.len());
+/// assert_eq!("#fffffd", record["hcl"]);
+/// ```
+pub fn parse_record(block: &str) -> Result<Record, String> {
+    let mut record = Record::new();
+
+    for pair in block.split_whitespace() {
+        let (key, value) = pair.split_once(':')
+            .ok_or_else(|| format!("expected key:value, got {:?}", pair))?;
+
+        if record.insert(key.to_string(), value.to_string()).is_some() {
+            return Err(format!("duplicate key {:?}", key));
+        }
+    }
+
+    Ok(record)
+}
+
+/// Attempts to open and parse a file of records, each record being
+/// a block of key:value pairs, blocks separated by blank lines.
+///
+/// An error is reported as a ParserError::Parse
+/// with the number of the first line of the block.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let passports = parser::parse_records_file("tests/parser/passports.txt").unwrap();
+///
+/// assert_eq!(3, passports.len());
+/// assert_eq!("183cm", passports[0]["hgt"]);
+/// ```
+pub fn parse_records_file<P>(filename: P) -> Result<Vec<Record>, ParserError> where P: AsRef<Path> {
+    parse_records_file_as(filename, |record| Ok(record.clone()))
+}
+
+/// Parses a string of records, each record being a block
+/// of key:value pairs, blocks separated by blank lines.
+pub fn parse_records_str(input: &str) -> Result<Vec<Record>, ParserError> {
+    parse_records_blocks(split_blank_line_separated_blocks(str_lines(input))?, |record| Ok(record.clone()))
+}
+
+/// Attempts to open and parse a file of records into a type implementing FromRecord.
+pub fn parse_records_file_into<T, P>(filename: P) -> Result<Vec<T>, ParserError>
+where T: FromRecord, P: AsRef<Path> {
+    parse_records_file_as(filename, T::from_record)
+}
+
+fn parse_records_file_as<T, P, F>(filename: P, callback: F) -> Result<Vec<T>, ParserError>
+where P: AsRef<Path>, F: Fn(&Record) -> Result<T, String> {
+    let filename = filename.as_ref();
+
+    parse_records_blocks(blank_line_separated_blocks(filename)?, callback).map_err(|error| error.with_filename(filename))
+}
+
+pub(super) fn parse_records_blocks<T, F>(blocks: Vec<(usize, Vec<String>)>, callback: F) -> Result<Vec<T>, ParserError>
+where F: Fn(&Record) -> Result<T, String> {
+    blocks
+        .into_iter()
+        .map(|(line_number, lines)| {
+            let block = lines.join("\n");
+
+            parse_record(&block)
+                .and_then(|record| callback(&record))
+                .map_err(|message| ParserError::parse(line_number, &block, message))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Passport {
+        birth_year: u32,
+        country_id: Option<String>,
+    }
+
+    impl FromRecord for Passport {
+        fn from_record(record: &Record) -> Result<Self, String> {
+            let birth_year = record.get("byr").ok_or("missing byr")?;
+
+            Ok(Self {
+                birth_year: birth_year.parse().map_err(|_| "invalid byr")?,
+                country_id: record.get("cid").cloned(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_parse_records_file_into() {
+        let passports: Vec<Passport> = parse_records_file_into("tests/parser/passports.txt").unwrap();
+
+        assert_eq!(Passport { birth_year: 1937, country_id: Some("147".to_string()) }, passports[0]);
+        assert_eq!(None, passports[2].country_id);
+    }
+
+    #[test]
+    fn test_parse_records_str() {
+        let error = parse_records_str("a:1\nb:2\n\n\nc:3 c:4").unwrap_err();
+        assert_eq!(Some(5), error.line_number());
+
+        assert_eq!(2, parse_records_str("a:1\n\nb:2\n").unwrap().len());
+    }
+
+    #[test]
+    fn test_parse_record_errors() {
+        assert!(parse_record("a:1 b").is_err());
+        assert!(parse_record("a:1 a:2").is_err());
+        assert_eq!(Some(&"12:30".to_string()), parse_record("time:12:30").unwrap().get("time"));
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+use std::io::Error as IOError;
+use std::path::Path;
+
+use crate::parser::{lines_iter, str_lines, ParserError};
+
+/// Attempts to open a file and splits it into sections separated by blank lines.
+///
+/// Several consecutive blank lines count as one separator.
+///
+/// ```
+/// use enontekio::parser;
+///
+/// let sections = parser::parse_sections("tests/parser/sections.txt").unwrap();
+///
+/// assert_eq!(2, sections.len());
+/// assert_eq!(vec!["75,47,61,53", "97,61,53"], sections[1]);
+/// ```
+pub fn parse_sections<P>(filename: P) -> Result<Vec<Vec<String>>, ParserError> where P: AsRef<Path> {
+    Ok(without_line_numbers(blank_line_separated_blocks(filename)?))
+}
+
+/// Splits a string into sections separated by blank lines.
+pub fn parse_sections_str(input: &str) -> Result<Vec<Vec<String>>, ParserError> {
+    Ok(without_line_numbers(split_blank_line_separated_blocks(str_lines(input))?))
+}
+
+fn without_line_numbers(blocks: Vec<(usize, Vec<String>)>) -> Vec<Vec<String>> {
+    blocks.into_iter().map(|(_, lines)| lines).collect()
+}
+
+/// Attempts to open and parse a file made of two sections separated by blank lines,
+/// like rules then updates, each section with its own callback.
+///
+/// An error is reported as a ParserError::Parse with the number
+/// of the first line of the section, or of the last line if a section is missing.
+///
+/// ```
+/// use enontekio::parser::{self, parse_separated_values_line};
+///
+/// let (rules, updates) = parser::parse_two_sections(
+///     "tests/parser/sections.txt",
+///     |lines| lines.iter().map(|line| parse_separated_values_line::<u32>(line, "|")).collect::<Result<Vec<_>, _>>(),
+///     |lines| lines.iter().map(|line| parse_separated_values_line::<u32>(line, ",")).collect::<Result<Vec<_>, _>>(),
+/// ).unwrap();
+///
+/// assert_eq!(vec![47, 53], rules[0]);
+/// assert_eq!(vec![97, 61, 53], updates[1]);
+/// ```
+pub fn parse_two_sections<P, A, B, E, FA, FB>(filename: P, first: FA, second: FB) -> Result<(A, B), ParserError>
+where P: AsRef<Path>, E: Display, FA: FnOnce(&[String]) -> Result<A, E>, FB: FnOnce(&[String]) -> Result<B, E> {
+    let filename = filename.as_ref();
+    let blocks = blank_line_separated_blocks(filename)?;
+    let last_line_number = blocks.last().map_or(0, |(line_number, lines)| line_number + lines.len() - 1);
+
+    if blocks.len() != 2 {
+        let message = format!("expected 2 sections, got {}", blocks.len());
+        return Err(ParserError::parse(last_line_number, "", message).with_filename(filename));
+    }
+
+    let mut blocks = blocks.into_iter();
+    let (first_line_number, first_lines) = blocks.next().unwrap();
+    let (second_line_number, second_lines) = blocks.next().unwrap();
+
+    let a = first(&first_lines)
+        .map_err(|error| ParserError::parse(first_line_number, &first_lines.join("\n"), error).with_filename(filename))?;
+    let b = second(&second_lines)
+        .map_err(|error| ParserError::parse(second_line_number, &second_lines.join("\n"), error).with_filename(filename))?;
+
+    Ok((a, b))
+}
+
+/// Attempts to open a file and splits it into blocks separated by blank lines.
+///
+/// Each block comes with the number of its first line.
+/// Several consecutive blank lines count as one separator.
+pub fn blank_line_separated_blocks<P>(filename: P) -> Result<Vec<(usize, Vec<String>)>, ParserError> where P: AsRef<Path> {
+    split_blank_line_separated_blocks(lines_iter(filename)?)
+}
+
+pub(super) fn split_blank_line_separated_blocks<I>(lines: I) -> Result<Vec<(usize, Vec<String>)>, ParserError>
+where I: Iterator<Item = Result<String, IOError>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, Vec<String>)> = None;
+
+    for (index, line) in lines.enumerate() {
+        let line = line.map_err(|error| ParserError::IO { line_number: Some(index + 1), error })?;
+
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current.get_or_insert_with(|| (index + 1, Vec::new())).1.push(line);
+        }
+    }
+    blocks.extend(current);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_line_separated_blocks() {
+        let blocks = blank_line_separated_blocks("tests/parser/passports.txt").unwrap();
+        let line_numbers: Vec<_> = blocks.iter().map(|(line_number, _)| *line_number).collect();
+
+        assert_eq!(vec![1, 4, 8], line_numbers);
+        assert_eq!(4, blocks[2].1.len());
+    }
+
+    #[test]
+    fn test_parse_sections_str() {
+        let sections = parse_sections_str("a\nb\n\n\nc\n\n").unwrap();
+
+        assert_eq!(vec![vec!["a", "b"], vec!["c"]], sections);
+    }
+
+    #[test]
+    fn test_parse_two_sections_errors() {
+        let result = parse_two_sections(
+            "tests/parser/sections.txt",
+            |_| Ok::<_, String>(()),
+            |lines| if lines.len() > 1 { Err("too many updates".to_string()) } else { Ok(()) },
+        );
+        assert_eq!(Some(5), result.unwrap_err().line_number());
+
+        let result = parse_two_sections("tests/parser/ascii.txt", |_| Ok::<_, String>(()), |_| Ok(()));
+        assert_eq!(Some(4), result.unwrap_err().line_number());
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::parser::{parse_file_by_line_with, ParserError};
+
+/// Types built from the tokens of a line, like tuples or vectors
+/// of values implementing FromStr.
+pub trait FromTokens: Sized {
+    /// Builds a value from the tokens, which must all be consumed.
+    fn from_tokens<'a, I: Iterator<Item = &'a str>>(tokens: I) -> Result<Self, String>;
+}
+
+fn parse_token<T>(token: Option<&str>, position: usize, expected: usize) -> Result<T, String>
+where T: FromStr, T::Err: Display {
+    let token = token.ok_or_else(|| format!("expected {} tokens, got {}", expected, position))?;
+
+    token.parse().map_err(|error| format!("invalid token {:?} at position {}: {}", token, position + 1, error))
+}
+
+macro_rules! impl_from_tokens_for_tuple {
+    ($count:expr; $($position:tt $type:ident),+) => {
+        impl<$($type),+> FromTokens for ($($type,)+)
+        where $($type: FromStr, $type::Err: Display),+ {
+            fn from_tokens<'a, I: Iterator<Item = &'a str>>(mut tokens: I) -> Result<Self, String> {
+                let tuple = ($(parse_token::<$type>(tokens.next(), $position, $count)?,)+);
+
+                match tokens.next() {
+                    None => Ok(tuple),
+                    Some(token) => Err(format!("expected {} tokens, got extra token {:?}", $count, token)),
+                }
+            }
+        }
+    };
+}
+
+impl_from_tokens_for_tuple!(1; 0 A);
+impl_from_tokens_for_tuple!(2; 0 A, 1 B);
+impl_from_tokens_for_tuple!(3; 0 A, 1 B, 2 C);
+impl_from_tokens_for_tuple!(4; 0 A, 1 B, 2 C, 3 D);
+impl_from_tokens_for_tuple!(5; 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_from_tokens_for_tuple!(6; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+
+impl<T> FromTokens for Vec<T> where T: FromStr, T::Err: Display {
+    fn from_tokens<'a, I: Iterator<Item = &'a str>>(tokens: I) -> Result<Self, String> {
+        tokens
+            .enumerate()
+            .map(|(position, token)| parse_token(Some(token), position, position + 1))
+            .collect()
+    }
+}
+
+/// Parses the whitespace separated tokens of a line into a typed tuple or a vector.
+///
+/// ```
+/// use enontekio::parser::parse_line_as;
+///
+/// let (name, x, y) = parse_line_as::<(String, i64, i64)>("a 3 7").unwrap();
+/// assert_eq!(("a", 3, 7), (name.as_str(), x, y));
+///
+/// assert_eq!(Ok(vec![1, 2, 3]), parse_line_as::<Vec<u8>>(" 1  2 3 "));
+/// assert!(parse_line_as::<(u8, u8)>("1 2 3").is_err());
+/// ```
+pub fn parse_line_as<T: FromTokens>(line: &str) -> Result<T, String> {
+    T::from_tokens(line.split_whitespace())
+}
+
+/// Parses the tokens of a line separated by a delimiter into a typed tuple or a vector.
+///
+/// Tokens are trimmed, so "1, 2" is split as "1" and "2" with a comma delimiter.
+///
+/// ```
+/// use enontekio::parser::parse_line_as_with_delimiter;
+///
+/// assert_eq!(Ok((1, 2.5)), parse_line_as_with_delimiter::<(u32, f64)>("1, 2.5", ","));
+/// ```
+pub fn parse_line_as_with_delimiter<T: FromTokens>(line: &str, delimiter: &str) -> Result<T, String> {
+    T::from_tokens(line.split(delimiter).map(str::trim))
+}
+
+/// Attempts to open and parse a file line by line,
+/// each line split into whitespace separated tokens.
+///
+/// An error is reported as a ParserError::Parse with the line number.
+pub fn parse_file_as_tokens<T, P>(filename: P) -> Result<Vec<T>, ParserError>
+where T: FromTokens, P: AsRef<Path> {
+    parse_file_by_line_with(filename, parse_line_as::<T>)
+}
+
+/// Attempts to open and parse a file line by line,
+/// each line split into tokens separated by a delimiter.
+///
+/// An error is reported as a ParserError::Parse with the line number.
+pub fn parse_file_as_tokens_with_delimiter<T, P>(filename: P, delimiter: &str) -> Result<Vec<T>, ParserError>
+where T: FromTokens, P: AsRef<Path> {
+    parse_file_by_line_with(filename, |line| parse_line_as_with_delimiter::<T>(line, delimiter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_as_errors() {
+        assert_eq!(
+            Err("expected 3 tokens, got 2".to_string()),
+            parse_line_as::<(u8, u8, u8)>("1 2"),
+        );
+        assert_eq!(
+            Err("invalid token \"x\" at position 2: invalid digit found in string".to_string()),
+            parse_line_as::<(u8, u8)>("1 x"),
+        );
+    }
+
+    #[test]
+    fn test_parse_file_as_tokens() {
+        let numbers: Vec<(u8,)> = parse_file_as_tokens("tests/parser/ascii.txt").unwrap();
+        assert_eq!(vec![(64,), (65,), (66,), (67,)], numbers);
+
+        let error = parse_file_as_tokens_with_delimiter::<(u8, u8), _>("tests/parser/ascii.txt", ",").unwrap_err();
+        assert_eq!(Some(1), error.line_number());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::collections::get_taxicab_neighbors_in_2d_grid;
+use crate::pathfinding::astar;
+
+/// Gets the Manhattan distance between two cells of a grid,
+/// an admissible heuristic for A* when every move costs at least 1.
+pub fn manhattan_distance(a: (usize, usize), b: (usize, usize)) -> u64 {
+    (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as u64
+}
+
+/// Finds the cheapest path between two cells of a weighted grid,
+/// using Dijkstra's algorithm.
+///
+/// Moves are horizontal or vertical. Entering a cell costs its value,
+/// so the start cell value isn't counted.
+///
+/// ```
+/// use enontekio::pathfinding::dijkstra_grid;
+///
+/// let grid = vec![
+///     vec![1, 1, 6],
+///     vec![1, 3, 8],
+///     vec![2, 1, 3],
+/// ];
+///
+/// let (_, cost) = dijkstra_grid(&grid, (0, 0), (2, 2)).unwrap();
+/// assert_eq!(7, cost);
+/// ```
+pub fn dijkstra_grid(grid: &[Vec<u32>], start: (usize, usize), end: (usize, usize)) -> Option<(Vec<(usize, usize)>, u64)> {
+    astar_grid(grid, start, end, |_| 0)
+}
+
+/// Finds the cheapest path between two cells of a weighted grid,
+/// using the A* algorithm with the specified heuristic.
+///
+/// Moves are horizontal or vertical. Entering a cell costs its value,
+/// so the start cell value isn't counted.
+///
+/// The heuristic receives a cell and estimates the remaining cost to the end cell,
+/// for example `|cell| manhattan_distance(cell, end)` when every cell costs at least 1.
+pub fn astar_grid<FH>(grid: &[Vec<u32>], start: (usize, usize), end: (usize, usize), heuristic: FH) -> Option<(Vec<(usize, usize)>, u64)>
+where FH: Fn((usize, usize)) -> u64 {
+    astar(
+        start,
+        |&(i, j)| get_taxicab_neighbors_in_2d_grid(grid, i, j)
+            .into_iter()
+            .map(|(ni, nj)| ((ni, nj), grid[ni][nj] as u64)),
+        |&cell| heuristic(cell),
+        |&cell| cell == end,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> Vec<Vec<u32>> {
+        vec![
+            vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
+            vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
+            vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
+            vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
+            vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
+            vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
+            vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
+            vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
+            vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
+            vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
+        ]
+    }
+
+    #[test]
+    fn test_dijkstra_grid() {
+        let (path, cost) = dijkstra_grid(&get_grid(), (0, 0), (9, 9)).unwrap();
+
+        assert_eq!(40, cost);
+        assert_eq!(Some(&(0, 0)), path.first());
+        assert_eq!(Some(&(9, 9)), path.last());
+    }
+
+    #[test]
+    fn test_astar_grid() {
+        let end = (9, 9);
+        let (_, cost) = astar_grid(&get_grid(), (0, 0), end, |cell| manhattan_distance(cell, end)).unwrap();
+
+        assert_eq!(40, cost);
+    }
+}
@@ -0,0 +1,205 @@
+//! Shortest paths search with Dijkstra and A* algorithms.
+//!
+//! The generic functions explore any state space through a successors callback,
+//! returning for a node its neighbors and the cost to move to them.
+//! Helpers are provided for weighted grids and adjacency lists.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::collections::History;
+
+mod grid;
+
+pub use grid::{astar_grid, dijkstra_grid, manhattan_distance};
+
+/// A graph represented as an adjacency list:
+/// each node maps to its neighbors and the cost of the edge to reach them.
+pub type AdjacencyList<N, C> = HashMap<N, Vec<(N, C)>>;
+
+/// Finds the shortest path from the start node to a node satisfying the goal predicate,
+/// using Dijkstra's algorithm.
+///
+/// Costs must be nonnegative; the default value of the cost type is used as zero.
+///
+/// Returns the path, including the start and goal nodes, and its total cost.
+///
+/// ```
+/// use enontekio::pathfinding::dijkstra;
+///
+/// // From 1, we can go to n + 1 for a cost of 1, or to 2n for a cost of 1.
+/// let (path, cost) = dijkstra(1, |&n: &u32| vec![(n + 1, 1), (n * 2, 1)], |&n| n == 10).unwrap();
+/// assert_eq!(vec![1, 2, 4, 5, 10], path);
+/// assert_eq!(4, cost);
+/// ```
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, successors: FN, is_goal: FG) -> Option<(Vec<N>, C)>
+where N: Eq + Hash + Clone, C: Copy + Ord + Add<Output = C> + Default,
+      FN: FnMut(&N) -> IN, IN: IntoIterator<Item = (N, C)>, FG: Fn(&N) -> bool {
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Finds the shortest path from the start node to a node satisfying the goal predicate,
+/// using the A* algorithm.
+///
+/// The heuristic estimates the remaining cost from a node to the goal.
+/// To get the shortest path, it must never overestimate the actual cost.
+/// A heuristic always returning zero makes A* equivalent to Dijkstra.
+///
+/// Returns the path, including the start and goal nodes, and its total cost.
+pub fn astar<N, C, FN, IN, FH, FG>(start: N, successors: FN, heuristic: FH, is_goal: FG) -> Option<(Vec<N>, C)>
+where N: Eq + Hash + Clone, C: Copy + Ord + Add<Output = C> + Default,
+      FN: FnMut(&N) -> IN, IN: IntoIterator<Item = (N, C)>, FH: Fn(&N) -> C, FG: Fn(&N) -> bool {
+    search(start, successors, heuristic, is_goal, None)
+}
+
+/// Same as astar, but records in the history each expanded node with its cost,
+/// in the order they're expanded.
+pub fn astar_with_history<N, C, FN, IN, FH, FG>(start: N, successors: FN, heuristic: FH, is_goal: FG, history: &mut History<(N, C)>) -> Option<(Vec<N>, C)>
+where N: Eq + Hash + Clone, C: Copy + Ord + Add<Output = C> + Default,
+      FN: FnMut(&N) -> IN, IN: IntoIterator<Item = (N, C)>, FH: Fn(&N) -> C, FG: Fn(&N) -> bool {
+    search(start, successors, heuristic, is_goal, Some(history))
+}
+
+fn search<N, C, FN, IN, FH, FG>(start: N, mut successors: FN, heuristic: FH, is_goal: FG, mut history: Option<&mut History<(N, C)>>) -> Option<(Vec<N>, C)>
+where N: Eq + Hash + Clone, C: Copy + Ord + Add<Output = C> + Default,
+      FN: FnMut(&N) -> IN, IN: IntoIterator<Item = (N, C)>, FH: Fn(&N) -> C, FG: Fn(&N) -> bool {
+    // Nodes are stored once in a vector, the heap and maps only manipulate indices.
+    let mut nodes = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut costs = vec![C::default()];
+    let mut parents: Vec<Option<usize>> = vec![None];
+
+    let mut to_visit = BinaryHeap::new();
+    to_visit.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, index))) = to_visit.pop() {
+        if cost > costs[index] {
+            // A shorter path to this node has already been processed.
+            continue;
+        }
+
+        let node = nodes[index].clone();
+        if let Some(history) = history.as_mut() {
+            history.record((node.clone(), cost));
+        }
+
+        if is_goal(&node) {
+            return Some((build_path(&nodes, &parents, index), cost));
+        }
+
+        for (neighbor, move_cost) in successors(&node) {
+            let new_cost = cost + move_cost;
+
+            let neighbor_index = match indices.get(&neighbor) {
+                Some(&neighbor_index) => {
+                    if new_cost >= costs[neighbor_index] {
+                        continue;
+                    }
+                    costs[neighbor_index] = new_cost;
+                    parents[neighbor_index] = Some(index);
+                    neighbor_index
+                }
+                None => {
+                    let neighbor_index = nodes.len();
+                    indices.insert(neighbor.clone(), neighbor_index);
+                    nodes.push(neighbor.clone());
+                    costs.push(new_cost);
+                    parents.push(Some(index));
+                    neighbor_index
+                }
+            };
+
+            to_visit.push(Reverse((new_cost + heuristic(&neighbor), new_cost, neighbor_index)));
+        }
+    }
+
+    None
+}
+
+/// Finds the shortest path between two nodes of a graph represented as an adjacency list,
+/// using Dijkstra's algorithm.
+pub fn dijkstra_adjacency_list<N, C>(graph: &AdjacencyList<N, C>, start: &N, goal: &N) -> Option<(Vec<N>, C)>
+where N: Eq + Hash + Clone, C: Copy + Ord + Add<Output = C> + Default {
+    dijkstra(
+        start.clone(),
+        |node| graph.get(node).cloned().unwrap_or_default(),
+        |node| node == goal,
+    )
+}
+
+fn build_path<N: Clone>(nodes: &[N], parents: &[Option<usize>], end: usize) -> Vec<N> {
+    let mut path = vec![nodes[end].clone()];
+    let mut current = end;
+
+    while let Some(parent) = parents[current] {
+        path.push(nodes[parent].clone());
+        current = parent;
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_graph() -> AdjacencyList<char, u32> {
+        HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+            ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+            ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+            ('e', vec![('d', 6), ('f', 9)]),
+            ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+        ])
+    }
+
+    #[test]
+    fn test_dijkstra_adjacency_list() {
+        let (path, cost) = dijkstra_adjacency_list(&get_graph(), &'a', &'e').unwrap();
+
+        assert_eq!(vec!['a', 'c', 'f', 'e'], path);
+        assert_eq!(20, cost);
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        let mut graph = get_graph();
+        graph.insert('z', Vec::new());
+
+        assert_eq!(None, dijkstra_adjacency_list(&graph, &'a', &'z'));
+    }
+
+    #[test]
+    fn test_dijkstra_start_is_goal() {
+        assert_eq!(Some((vec!['a'], 0)), dijkstra_adjacency_list(&get_graph(), &'a', &'a'));
+    }
+
+    #[test]
+    fn test_astar_with_history() {
+        let graph = get_graph();
+        let mut history = History::new(10);
+
+        astar_with_history('a', |node| graph[node].clone(), |_| 0, |&node| node == 'e', &mut history);
+
+        assert_eq!(Some((0, &('a', 0))), history.first());
+        assert_eq!(Some(&('e', 20)), history.last().map(|(_, entry)| entry));
+    }
+
+    #[test]
+    fn test_astar_on_number_line() {
+        let goal: i32 = 17;
+        let (path, cost) = astar(
+            0,
+            |&n: &i32| vec![(n - 1, 1), (n + 1, 1), (n + 5, 1)],
+            |&n| ((goal - n).abs() + 4) / 5,
+            |&n| n == goal,
+        ).unwrap();
+
+        assert_eq!(5, cost);
+        assert_eq!(Some(&goal), path.last());
+    }
+}
@@ -0,0 +1,156 @@
+//! Cellular automata, where each cell of a grid evolves from its value
+//! and the values of its neighbors, all cells at once.
+
+use std::collections::HashSet;
+
+use crate::collections::{map_with_neighbors, Neighborhood, SparseGrid};
+use crate::math::cycle::{detect_cycle, state_after, Cycle};
+
+/// Computes the next generation of a dense grid.
+///
+/// The rule receives the current value of a cell and the values of its
+/// neighbors in the current generation. Cells on the border have fewer neighbors.
+///
+/// ```
+/// use enontekio::collections::Neighborhood;
+/// use enontekio::simulation::automaton::step;
+///
+/// // Game of life blinker
+/// let rule = |&alive: &bool, neighbors: &[&bool]| {
+///     let count = neighbors.iter().filter(|&&&neighbor| neighbor).count();
+///     count == 3 || (alive && count == 2)
+/// };
+///
+/// let grid = vec![
+///     vec![false, false, false],
+///     vec![true, true, true],
+///     vec![false, false, false],
+/// ];
+///
+/// let next = step(&grid, Neighborhood::All, rule);
+/// assert_eq!(vec![vec![false, true, false]; 3], next);
+/// assert_eq!(grid, step(&next, Neighborhood::All, rule));
+/// ```
+pub fn step<T, F>(grid: &[Vec<T>], neighborhood: Neighborhood, rule: F) -> Vec<Vec<T>>
+where F: Fn(&T, &[&T]) -> T {
+    map_with_neighbors(grid, neighborhood, |value, neighbors| rule(value, neighbors))
+}
+
+/// Computes the nth generation of a dense grid.
+pub fn run_generations<T, F>(grid: &[Vec<T>], neighborhood: Neighborhood, rule: F, n: usize) -> Vec<Vec<T>>
+where T: Clone, F: Fn(&T, &[&T]) -> T {
+    (0..n).fold(grid.to_vec(), |current, _| step(&current, neighborhood, &rule))
+}
+
+/// Detects when the generations of a dense grid start to repeat.
+///
+/// The automaton must eventually cycle, or this function won't return.
+pub fn find_cycle<T, F>(grid: &[Vec<T>], neighborhood: Neighborhood, rule: F) -> Cycle
+where T: Clone + Eq, F: Fn(&T, &[&T]) -> T {
+    detect_cycle(grid.to_vec(), |current| step(current, neighborhood, &rule))
+}
+
+/// Computes the nth generation of a dense grid, skipping the repeated
+/// generations once a cycle is found, so n can be huge.
+///
+/// The automaton must eventually cycle, or this function won't return.
+pub fn generation_after<T, F>(grid: &[Vec<T>], neighborhood: Neighborhood, rule: F, n: usize) -> Vec<Vec<T>>
+where T: Clone + Eq, F: Fn(&T, &[&T]) -> T {
+    state_after(grid.to_vec(), |current| step(current, neighborhood, &rule), n)
+}
+
+/// Computes the next generation of a sparse grid.
+///
+/// Only the cells set and their neighbors are evaluated, so the rule
+/// must keep a cell with the default value surrounded by default values
+/// to the default value. Cells evolving to the default value are unset.
+pub fn step_sparse<T, F>(grid: &SparseGrid<T>, neighborhood: Neighborhood, rule: F) -> SparseGrid<T>
+where T: Clone + PartialEq, F: Fn(&T, &[&T]) -> T {
+    let directions = neighborhood.direction_vectors();
+
+    let candidates: HashSet<(i64, i64)> = grid.iter()
+        .flat_map(|(coords, _)| {
+            let mut cells = grid.neighbors(coords, &directions);
+            cells.push(coords);
+            cells
+        })
+        .collect();
+
+    let mut next = SparseGrid::new(grid.default_value().clone());
+    for coords in candidates {
+        let value = rule(grid.get(coords), &grid.neighbor_values(coords, &directions));
+
+        if value != *next.default_value() {
+            next.set(coords, value);
+        }
+    }
+
+    next
+}
+
+/// Computes the nth generation of a sparse grid.
+pub fn run_sparse_generations<T, F>(grid: &SparseGrid<T>, neighborhood: Neighborhood, rule: F, n: usize) -> SparseGrid<T>
+where T: Clone + PartialEq, F: Fn(&T, &[&T]) -> T {
+    (0..n).fold(grid.clone(), |current, _| step_sparse(&current, neighborhood, &rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(alive: &bool, neighbors: &[&bool]) -> bool {
+        let count = neighbors.iter().filter(|&&&neighbor| neighbor).count();
+
+        count == 3 || (*alive && count == 2)
+    }
+
+    fn glider() -> SparseGrid<bool> {
+        let mut grid = SparseGrid::new(false);
+        for coords in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)] {
+            grid.set(coords, true);
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_sparse_glider_moves() {
+        let grid = run_sparse_generations(&glider(), Neighborhood::All, life, 4);
+
+        let expected = glider().iter()
+            .map(|((i, j), _)| (i + 1, j + 1))
+            .collect::<HashSet<_>>();
+        let actual = grid.iter()
+            .map(|(coords, _)| coords)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_dense_and_sparse_agree() {
+        // A glider in a box large enough to not hit the borders
+        let mut dense = vec![vec![false; 8]; 8];
+        for ((i, j), _) in glider().iter() {
+            dense[i as usize][j as usize] = true;
+        }
+
+        let dense = run_generations(&dense, Neighborhood::All, life, 8);
+        let sparse = run_sparse_generations(&glider(), Neighborhood::All, life, 8);
+
+        assert_eq!(SparseGrid::from_dense(&dense, false).len(), sparse.len());
+        assert!(sparse.iter().all(|((i, j), _)| dense[i as usize][j as usize]));
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let blinker = vec![
+            vec![false, true, false],
+            vec![false, true, false],
+            vec![false, true, false],
+        ];
+
+        assert_eq!(Cycle { start: 0, length: 2 }, find_cycle(&blinker, Neighborhood::All, life));
+        assert_eq!(blinker, generation_after(&blinker, Neighborhood::All, life, 1_000_000));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod automaton;
+pub mod points;
+pub mod recipes;
+pub mod spinlock;
+pub mod tubes;
+pub mod virus;
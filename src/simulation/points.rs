@@ -0,0 +1,163 @@
+//! Moving points converging into a message, like stars aligning.
+
+use crate::strings::ocr::decode_letters;
+
+/// The message formed by the points when they're the closest together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointsMessage {
+    /// The number of seconds elapsed before the message appears
+    pub time: u64,
+
+    /// The message, with # for points and . for empty cells, one line per row
+    pub rendered: String,
+
+    /// The letters read in the message, if they're drawn in a known font
+    pub letters: Option<String>,
+}
+
+/// Advances points at constant velocities until the height of their bounding box
+/// is minimal, then renders them and reads the letters they draw.
+///
+/// Points and velocities are (x, y) pairs, y growing downward.
+/// Returns None if there are no points, or if the velocities don't match the points.
+///
+/// ```
+/// use enontekio::simulation::points::points_message;
+///
+/// let points = vec![(0, -2), (0, 4)];
+/// let velocities = vec![(0, 1), (0, -1)];
+///
+/// let message = points_message(&points, &velocities).unwrap();
+/// assert_eq!(3, message.time);
+/// assert_eq!("#", message.rendered);
+/// assert_eq!(None, message.letters);
+/// ```
+pub fn points_message(points: &[(i64, i64)], velocities: &[(i64, i64)]) -> Option<PointsMessage> {
+    if points.is_empty() || points.len() != velocities.len() {
+        return None;
+    }
+
+    let mut current = points.to_vec();
+    let mut height = bounding_box_height(&current);
+    let mut time = 0;
+
+    loop {
+        let next: Vec<_> = current.iter()
+            .zip(velocities)
+            .map(|(&(x, y), &(dx, dy))| (x + dx, y + dy))
+            .collect();
+        let next_height = bounding_box_height(&next);
+
+        if next_height >= height {
+            break;
+        }
+
+        current = next;
+        height = next_height;
+        time += 1;
+    }
+
+    let rendered = render_points(&current);
+    let letters = decode_letters(&rendered);
+
+    Some(PointsMessage {
+        time,
+        rendered,
+        letters,
+    })
+}
+
+fn bounding_box_height(points: &[(i64, i64)]) -> i64 {
+    let min = points.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let max = points.iter().map(|&(_, y)| y).max().unwrap_or(0);
+
+    max - min
+}
+
+/// Renders points in their bounding box, with # for points and . for empty cells.
+pub fn render_points(points: &[(i64, i64)]) -> String {
+    if points.is_empty() {
+        return String::new();
+    }
+
+    let min_x = points.iter().map(|&(x, _)| x).min().unwrap_or(0);
+    let max_x = points.iter().map(|&(x, _)| x).max().unwrap_or(0);
+    let min_y = points.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let max_y = points.iter().map(|&(_, y)| y).max().unwrap_or(0);
+
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut grid = vec![vec!['.'; width]; height];
+
+    for &(x, y) in points {
+        grid[(y - min_y) as usize][(x - min_x) as usize] = '#';
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points_message() {
+        // An L shape: three points of a vertical bar and one to the right of its bottom,
+        // each starting 3 seconds away from its final position, then crossing each other.
+        let targets = [(0, 0), (0, 1), (0, 2), (1, 2)];
+        let velocities = vec![(1, 5), (-1, 0), (1, -5), (-1, -5)];
+        let points: Vec<_> = targets.iter()
+            .zip(velocities.iter())
+            .map(|(&(x, y), &(dx, dy))| (x - 3 * dx, y - 3 * dy))
+            .collect();
+
+        let message = points_message(&points, &velocities).unwrap();
+
+        assert_eq!(3, message.time);
+        assert_eq!("#.\n#.\n##", message.rendered);
+        assert_eq!(None, message.letters);
+    }
+
+    #[test]
+    fn test_points_message_letters() {
+        let drawing = [
+            "#....#..######",
+            "#....#.......#",
+            "#....#.......#",
+            "#....#......#.",
+            "######.....#..",
+            "#....#....#...",
+            "#....#...#....",
+            "#....#..#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ];
+        let targets: Vec<(i64, i64)> = drawing.iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.char_indices().filter(|&(_, c)| c == '#').map(move |(x, _)| (x as i64, y as i64)))
+            .collect();
+
+        // Points alternately come from above and below, reaching their targets after 4 seconds
+        let velocities: Vec<(i64, i64)> = (0..targets.len())
+            .map(|index| if index % 2 == 0 { (0, 5) } else { (0, -5) })
+            .collect();
+        let points: Vec<_> = targets.iter()
+            .zip(&velocities)
+            .map(|(&(x, y), &(dx, dy))| (x - 4 * dx, y - 4 * dy))
+            .collect();
+
+        let message = points_message(&points, &velocities).unwrap();
+
+        assert_eq!(4, message.time);
+        assert_eq!(Some("HZ".to_string()), message.letters);
+    }
+
+    #[test]
+    fn test_points_message_with_invalid_input() {
+        assert_eq!(None, points_message(&[], &[]));
+        assert_eq!(None, points_message(&[(0, 0)], &[]));
+    }
+}
@@ -0,0 +1,155 @@
+//! Recipes scoreboard, where two elves create new recipes
+//! from the digits of the sum of their current recipes scores.
+
+/// The scoreboard, starting with the recipes 3 and 7.
+pub struct Scoreboard {
+    scores: Vec<u8>,
+    elves: [usize; 2],
+}
+
+impl Default for Scoreboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scoreboard {
+    pub fn new() -> Self {
+        Self {
+            scores: vec![3, 7],
+            elves: [0, 1],
+        }
+    }
+
+    pub fn scores(&self) -> &[u8] {
+        &self.scores
+    }
+
+    /// Creates the new recipes, one or two, then moves the elves.
+    ///
+    /// Returns the number of recipes created.
+    pub fn step(&mut self) -> usize {
+        let sum = self.scores[self.elves[0]] + self.scores[self.elves[1]];
+
+        let created = if sum >= 10 {
+            self.scores.push(sum / 10);
+            self.scores.push(sum % 10);
+            2
+        } else {
+            self.scores.push(sum);
+            1
+        };
+
+        for elf in self.elves.iter_mut() {
+            *elf = (*elf + 1 + self.scores[*elf] as usize) % self.scores.len();
+        }
+
+        created
+    }
+}
+
+/// Gets the scores of the first <target_count> recipes.
+///
+/// ```
+/// use enontekio::simulation::recipes::recipe_scores;
+///
+/// assert_eq!(vec![3, 7, 1, 0, 1, 0, 1, 2], recipe_scores(8));
+/// ```
+pub fn recipe_scores(target_count: usize) -> Vec<u8> {
+    let mut scoreboard = Scoreboard::new();
+
+    while scoreboard.scores.len() < target_count {
+        scoreboard.step();
+    }
+
+    scoreboard.scores.truncate(target_count);
+    scoreboard.scores
+}
+
+/// Gets the scores of the ten recipes after the first <count> recipes, as a string.
+pub fn ten_scores_after(count: usize) -> String {
+    recipe_scores(count + 10)[count..]
+        .iter()
+        .map(|score| char::from(b'0' + score))
+        .collect()
+}
+
+/// Gets the number of recipes on the scoreboard before the pattern of scores first appears.
+///
+/// Only the tail of the scoreboard is compared after each step, and as a step
+/// can create two recipes, the pattern can end on the last or the previous one.
+///
+/// A pattern may never appear, so the search stops after <max_recipes> recipes
+/// and gives None if the pattern isn't found within them.
+/// Scores are digits: a pattern with a score above 9 never appears, so it gives None too.
+///
+/// ```
+/// use enontekio::simulation::recipes::find_score_sequence;
+///
+/// assert_eq!(Some(9), find_score_sequence(&[5, 1, 5, 8, 9], 100));
+/// assert_eq!(None, find_score_sequence(&[5, 1, 5, 8, 9], 10));
+/// assert_eq!(None, find_score_sequence(&[1, 10], 100));
+/// ```
+pub fn find_score_sequence(pattern: &[u8], max_recipes: usize) -> Option<usize> {
+    if pattern.iter().any(|&score| score > 9) {
+        return None;
+    }
+
+    let mut scoreboard = Scoreboard::new();
+    if let Some(position) = find_in(&scoreboard.scores[..max_recipes.min(2)], pattern) {
+        return Some(position);
+    }
+
+    let n = pattern.len();
+
+    while scoreboard.scores.len() < max_recipes {
+        let created = scoreboard.step();
+        let len = scoreboard.scores.len().min(max_recipes);
+
+        for end in (scoreboard.scores.len() + 1 - created)..=len {
+            if end >= n && scoreboard.scores[end - n..end] == *pattern {
+                return Some(end - n);
+            }
+        }
+    }
+
+    None
+}
+
+fn find_in(scores: &[u8], pattern: &[u8]) -> Option<usize> {
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    scores.windows(pattern.len()).position(|window| window == pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ten_scores_after() {
+        assert_eq!("5158916779", ten_scores_after(9));
+        assert_eq!("0124515891", ten_scores_after(5));
+        assert_eq!("9251071085", ten_scores_after(18));
+        assert_eq!("5941429882", ten_scores_after(2018));
+    }
+
+    #[test]
+    fn test_find_score_sequence() {
+        assert_eq!(Some(9), find_score_sequence(&[5, 1, 5, 8, 9], 10_000));
+        assert_eq!(Some(5), find_score_sequence(&[0, 1, 2, 4, 5], 10_000));
+        assert_eq!(Some(18), find_score_sequence(&[9, 2, 5, 1, 0], 10_000));
+        assert_eq!(Some(2018), find_score_sequence(&[5, 9, 4, 1, 4], 10_000));
+        assert_eq!(Some(0), find_score_sequence(&[3, 7], 10_000));
+        assert_eq!(None, find_score_sequence(&[42], 10_000));
+    }
+
+    #[test]
+    fn test_find_score_sequence_stops_after_max_recipes() {
+        assert_eq!(Some(2018), find_score_sequence(&[5, 9, 4, 1, 4], 2023));
+        assert_eq!(None, find_score_sequence(&[5, 9, 4, 1, 4], 2022));
+        assert_eq!(None, find_score_sequence(&[3, 7], 1));
+    }
+}
@@ -0,0 +1,103 @@
+//! Spinlock, inserting values in a circular buffer: it steps forward
+//! a fixed number of times, inserts the next value after the current
+//! position, then the inserted value becomes the current position.
+
+/// A circular buffer after the insertions, with the current position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spinlock {
+    pub buffer: Vec<usize>,
+    pub position: usize,
+}
+
+impl Spinlock {
+    /// Gets the value following the current position.
+    pub fn value_after_current(&self) -> usize {
+        self.buffer[(self.position + 1) % self.buffer.len()]
+    }
+
+    /// Gets the value following the specified value, if it's in the buffer.
+    pub fn value_after(&self, value: usize) -> Option<usize> {
+        let index = self.buffer.iter().position(|&other| other == value)?;
+
+        Some(self.buffer[(index + 1) % self.buffer.len()])
+    }
+}
+
+/// Runs the spinlock with the full buffer, starting with 0,
+/// inserting the values 1 to insertions.
+///
+/// Each insertion shifts the buffer, so this is quadratic.
+///
+/// ```
+/// use enontekio::simulation::spinlock::spinlock;
+///
+/// let spinlock = spinlock(3, 2017);
+/// assert_eq!(638, spinlock.value_after_current());
+/// ```
+pub fn spinlock(step: usize, insertions: usize) -> Spinlock {
+    let mut buffer = Vec::with_capacity(insertions + 1);
+    buffer.push(0);
+    let mut position = 0;
+
+    for value in 1..=insertions {
+        position = (position + step) % buffer.len() + 1;
+        buffer.insert(position, value);
+    }
+
+    Spinlock { buffer, position }
+}
+
+/// Gets the value following 0 after the insertions, without the buffer.
+///
+/// As values are inserted after the current position, 0 always stays at
+/// index 0, so only the insertions at index 1 matter: tracking the position
+/// and the buffer length is enough, in linear time and constant memory.
+///
+/// Returns None if there is no insertion.
+///
+/// ```
+/// use enontekio::simulation::spinlock::value_after_zero;
+///
+/// assert_eq!(Some(9), value_after_zero(3, 9));
+/// ```
+pub fn value_after_zero(step: usize, insertions: usize) -> Option<usize> {
+    let mut value_after_zero = None;
+    let mut position = 0;
+
+    for value in 1..=insertions {
+        // The buffer contains the values 0 to value - 1
+        position = (position + step) % value + 1;
+
+        if position == 1 {
+            value_after_zero = Some(value);
+        }
+    }
+
+    value_after_zero
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinlock_buffer() {
+        let spinlock = spinlock(3, 9);
+
+        assert_eq!(vec![0, 9, 5, 7, 2, 4, 3, 8, 6, 1], spinlock.buffer);
+        assert_eq!(1, spinlock.position);
+        assert_eq!(Some(2), spinlock.value_after(7));
+        assert_eq!(Some(0), spinlock.value_after(1));
+    }
+
+    #[test]
+    fn test_value_after_zero_matches_full_buffer() {
+        for step in [1, 3, 7, 348] {
+            for insertions in [0, 1, 2, 10, 500] {
+                let expected = spinlock(step, insertions).value_after(0).filter(|_| insertions > 0);
+
+                assert_eq!(expected, value_after_zero(step, insertions));
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Walker following a path drawn in an ASCII diagram, like:
+//!
+//! ```text
+//!      |
+//!      |  +--+
+//!      A  |  C
+//!  F---|----E|--+
+//!      |  |  |  D
+//!      +B-+  +--+
+//! ```
+//!
+//! The walker goes straight as long as it can, crossing other lines,
+//! and turns when it can't, until it reaches the end of the path.
+
+use std::collections::HashSet;
+
+/// Options to follow a path
+#[derive(Debug, Clone, Copy)]
+pub struct FollowPathOptions {
+    /// The characters the path is made of
+    pub passable: fn(char) -> bool,
+
+    /// The initial direction, as (delta_i, delta_j), down by default
+    pub direction: (i32, i32),
+}
+
+impl Default for FollowPathOptions {
+    fn default() -> Self {
+        Self {
+            passable: |c| matches!(c, '|' | '-' | '+') || c.is_alphabetic(),
+            direction: (1, 0),
+        }
+    }
+}
+
+/// The result of a walk along a path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathWalk {
+    /// The letters met along the path, in order
+    pub letters: String,
+
+    /// The number of cells visited, including the start
+    pub steps: usize,
+}
+
+/// Follows a path of |, -, + and letters, going down from the start.
+///
+/// ```
+/// use enontekio::simulation::tubes::follow_path;
+///
+/// let diagram: Vec<Vec<char>> = [
+///     "  |   ",
+///     "  A-+ ",
+///     "    B ",
+/// ].iter().map(|line| line.chars().collect()).collect();
+///
+/// let walk = follow_path(&diagram, (0, 2));
+/// assert_eq!("AB", walk.letters);
+/// assert_eq!(5, walk.steps);
+/// ```
+pub fn follow_path(grid: &[Vec<char>], start: (usize, usize)) -> PathWalk {
+    follow_path_with_options(grid, start, &FollowPathOptions::default())
+}
+
+/// Follows a path, as described by the options.
+///
+/// Rows can have different lengths, cells out of the grid aren't passable.
+///
+/// On a closed path, the walk stops when the walker is back on a cell
+/// it already left in the same direction, that cell being counted again.
+pub fn follow_path_with_options(grid: &[Vec<char>], start: (usize, usize), options: &FollowPathOptions) -> PathWalk {
+    let passable_cell = |(i, j): (usize, usize), (delta_i, delta_j): (i32, i32)| -> Option<(usize, usize)> {
+        let ni = i.checked_add_signed(delta_i as isize)?;
+        let nj = j.checked_add_signed(delta_j as isize)?;
+        let c = *grid.get(ni)?.get(nj)?;
+
+        (options.passable)(c).then_some((ni, nj))
+    };
+
+    let mut walk = PathWalk { letters: String::new(), steps: 0 };
+    let is_start_passable = grid.get(start.0)
+        .and_then(|row| row.get(start.1))
+        .is_some_and(|&c| (options.passable)(c));
+    if !is_start_passable {
+        return walk;
+    }
+
+    let mut moves = HashSet::new();
+    let mut position = start;
+    let mut direction = options.direction;
+    loop {
+        let c = grid[position.0][position.1];
+        if c.is_alphabetic() {
+            walk.letters.push(c);
+        }
+        walk.steps += 1;
+
+        // Goes straight if possible, else turns left or right
+        let (delta_i, delta_j) = direction;
+        let next = [direction, (delta_j, -delta_i), (-delta_j, delta_i)]
+            .into_iter()
+            .find_map(|direction| passable_cell(position, direction).map(|cell| (cell, direction)));
+
+        match next {
+            None => return walk,
+            Some((_, next_direction)) if !moves.insert((position, next_direction)) => return walk,
+            Some((cell, next_direction)) => {
+                position = cell;
+                direction = next_direction;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagram(lines: &[&str]) -> Vec<Vec<char>> {
+        lines.iter().map(|line| line.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_follow_path() {
+        let grid = diagram(&[
+            "     |          ",
+            "     |  +--+    ",
+            "     A  |  C    ",
+            " F---|----E|--+ ",
+            "     |  |  |  D ",
+            "     +B-+  +--+ ",
+        ]);
+
+        let walk = follow_path(&grid, (0, 5));
+        assert_eq!("ABCDEF", walk.letters);
+        assert_eq!(38, walk.steps);
+    }
+
+    #[test]
+    fn test_follow_path_with_options() {
+        let grid = diagram(&["..#", "#.#", "#.."]);
+        let options = FollowPathOptions {
+            passable: |c| c == '.',
+            direction: (0, 1),
+        };
+
+        let walk = follow_path_with_options(&grid, (0, 0), &options);
+        assert_eq!("", walk.letters);
+        assert_eq!(5, walk.steps);
+
+        assert_eq!(0, follow_path_with_options(&grid, (1, 0), &options).steps);
+    }
+
+    #[test]
+    fn test_follow_closed_path() {
+        let grid = diagram(&["+A+", "| |", "+-+"]);
+
+        let walk = follow_path(&grid, (0, 0));
+        assert_eq!("A", walk.letters);
+        assert_eq!(9, walk.steps);
+    }
+}
@@ -0,0 +1,123 @@
+//! Virus carrier walking an infinite grid: at each burst, it turns
+//! according to the state of its current node, changes that state,
+//! then moves forward.
+
+use crate::collections::SparseGrid;
+
+/// The state of a node of the grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum State {
+    #[default]
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+impl State {
+    /// Parses '.' as clean, 'W' as weakened, '#' as infected and 'F' as flagged.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(State::Clean),
+            'W' => Some(State::Weakened),
+            '#' => Some(State::Infected),
+            'F' => Some(State::Flagged),
+            _ => None,
+        }
+    }
+}
+
+/// How the carrier turns and changes the state of nodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleSet {
+    /// Clean nodes are infected, turning left,
+    /// infected nodes are cleaned, turning right.
+    TwoStates,
+
+    /// Clean nodes are weakened, turning left, then infected, without turning,
+    /// then flagged, turning right, then cleaned, reversing direction.
+    FourStates,
+}
+
+impl RuleSet {
+    /// Gets the next state of a node, and the new direction of the carrier.
+    fn apply(&self, state: State, (delta_i, delta_j): (i64, i64)) -> (State, (i64, i64)) {
+        let left = (-delta_j, delta_i);
+        let right = (delta_j, -delta_i);
+        let reverse = (-delta_i, -delta_j);
+
+        match (self, state) {
+            (RuleSet::TwoStates, State::Infected) => (State::Clean, right),
+            (RuleSet::TwoStates, _) => (State::Infected, left),
+
+            (RuleSet::FourStates, State::Clean) => (State::Weakened, left),
+            (RuleSet::FourStates, State::Weakened) => (State::Infected, (delta_i, delta_j)),
+            (RuleSet::FourStates, State::Infected) => (State::Flagged, right),
+            (RuleSet::FourStates, State::Flagged) => (State::Clean, reverse),
+        }
+    }
+}
+
+/// Runs the bursts of the carrier, starting at the specified node, facing up.
+///
+/// Clean nodes are removed from the grid, so it only stores the other states.
+///
+/// Returns the number of bursts causing a node to become infected.
+///
+/// ```
+/// use enontekio::collections::SparseGrid;
+/// use enontekio::simulation::virus::{simulate, RuleSet, State};
+///
+/// let mut grid = SparseGrid::new(State::Clean);
+/// grid.set((0, 2), State::Infected);
+/// grid.set((1, 0), State::Infected);
+///
+/// assert_eq!(5587, simulate(&mut grid, (1, 1), 10_000, RuleSet::TwoStates));
+/// ```
+pub fn simulate(grid: &mut SparseGrid<State>, start: (i64, i64), bursts: usize, rule_set: RuleSet) -> usize {
+    let mut position = start;
+    let mut direction = (-1, 0);
+    let mut infections = 0;
+
+    for _ in 0..bursts {
+        let (state, next_direction) = rule_set.apply(*grid.get(position), direction);
+
+        if state == State::Infected {
+            infections += 1;
+        }
+        if state == State::Clean {
+            grid.remove(position);
+        } else {
+            grid.set(position, state);
+        }
+
+        direction = next_direction;
+        position = (position.0 + direction.0, position.1 + direction.1);
+    }
+
+    infections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> SparseGrid<State> {
+        let dense: Vec<Vec<State>> = ["..#", "#..", "..."].iter()
+            .map(|line| line.chars().map(|c| State::from_char(c).unwrap()).collect())
+            .collect();
+
+        SparseGrid::from_dense(&dense, State::Clean)
+    }
+
+    #[test]
+    fn test_two_states() {
+        assert_eq!(5, simulate(&mut grid(), (1, 1), 7, RuleSet::TwoStates));
+        assert_eq!(41, simulate(&mut grid(), (1, 1), 70, RuleSet::TwoStates));
+    }
+
+    #[test]
+    fn test_four_states() {
+        assert_eq!(26, simulate(&mut grid(), (1, 1), 100, RuleSet::FourStates));
+    }
+}
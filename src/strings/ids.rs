@@ -0,0 +1,106 @@
+//! Comparison of identifiers, like boxes IDs in an inventory.
+
+use std::collections::HashMap;
+
+/// Computes the checksum of a list of IDs: the number of IDs containing
+/// a letter exactly twice, multiplied by the number of IDs containing
+/// a letter exactly three times.
+///
+/// ```
+/// use enontekio::strings::ids::checksum;
+///
+/// let ids = ["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"];
+/// assert_eq!(12, checksum(&ids));
+/// ```
+pub fn checksum<S: AsRef<str>>(ids: &[S]) -> usize {
+    let mut twice = 0;
+    let mut thrice = 0;
+
+    for id in ids {
+        let mut counts = HashMap::new();
+        for c in id.as_ref().chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+
+        if counts.values().any(|&count| count == 2) {
+            twice += 1;
+        }
+
+        if counts.values().any(|&count| count == 3) {
+            thrice += 1;
+        }
+    }
+
+    twice * thrice
+}
+
+/// Finds two IDs differing by exactly one character at the same position,
+/// and returns their common characters.
+///
+/// Instead of comparing every pair, each ID is stored once per position
+/// with the character at that position removed: two IDs differing by
+/// one character produce the same key. Keys borrow the parts of the ID
+/// before and after the removed character, so nothing is allocated
+/// per position, and hashing them is O(n·L²) for n IDs of length L,
+/// far fewer comparisons than every pair.
+///
+/// ```
+/// use enontekio::strings::ids::find_ids_differing_by_one;
+///
+/// let ids = ["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz"];
+/// assert_eq!(Some("fgij".to_string()), find_ids_differing_by_one(&ids));
+/// ```
+pub fn find_ids_differing_by_one<S: AsRef<str>>(ids: &[S]) -> Option<String> {
+    // Maps (position, part before, part after the character at that position) to the removed character.
+    // Identical IDs have the same removed character and mustn't be reported.
+    let mut seen: HashMap<(usize, &str, &str), char> = HashMap::new();
+
+    for id in ids {
+        let id = id.as_ref();
+
+        for (position, (offset, removed)) in id.char_indices().enumerate() {
+            let key = (position, &id[..offset], &id[offset + removed.len_utf8()..]);
+
+            match seen.get(&key) {
+                Some(&other) if other != removed => return Some(format!("{}{}", key.1, key.2)),
+                Some(_) => {}
+                None => {
+                    seen.insert(key, removed);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        let ids = vec!["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"];
+
+        assert_eq!(12, checksum(&ids));
+        assert_eq!(0, checksum::<&str>(&[]));
+    }
+
+    #[test]
+    fn test_find_ids_differing_by_one() {
+        let ids = vec!["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz"];
+
+        assert_eq!(Some("fgij".to_string()), find_ids_differing_by_one(&ids));
+    }
+
+    #[test]
+    fn test_find_ids_differing_by_one_ignores_identical_ids() {
+        assert_eq!(None, find_ids_differing_by_one(&["abc", "abc", "xyz"]));
+        assert_eq!(None, find_ids_differing_by_one(&["abc", "xbz"]));
+    }
+
+    #[test]
+    fn test_find_ids_differing_by_one_with_multibyte_chars() {
+        assert_eq!(Some("mlö".to_string()), find_ids_differing_by_one(&["malö", "mulö"]));
+    }
+}
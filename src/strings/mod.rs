@@ -0,0 +1,5 @@
+pub mod ids;
+pub mod ocr;
+pub mod password_rules;
+pub mod polymer;
+pub mod rules;
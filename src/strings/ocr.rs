@@ -0,0 +1,140 @@
+//! Recognition of the block letters drawn by puzzles, with # for lit cells.
+//!
+//! Two fonts are known: letters 6 rows high and 4 columns wide,
+//! and letters 10 rows high and 6 columns wide.
+
+/// Letters 6 rows high, one string per row
+const SMALL_FONT: [(char, [&str; 6]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters 10 rows high, one string per row
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Decodes block letters drawn with # for lit cells, one line per row.
+///
+/// Letters are separated by columns without any lit cell.
+/// Returns None if the height matches no font, or if a letter isn't known.
+///
+/// ```
+/// use enontekio::strings::ocr::decode_letters;
+///
+/// let drawing = [
+///     "#..#.####",
+///     "#..#....#",
+///     "####...#.",
+///     "#..#..#..",
+///     "#..#.#...",
+///     "#..#.####",
+/// ];
+///
+/// assert_eq!(Some("HZ".to_string()), decode_letters(&drawing.join("\n")));
+/// ```
+pub fn decode_letters(drawing: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = drawing.lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+
+    let width = rows.iter().map(|row| row.len()).max()?;
+    let is_lit = |i: usize, j: usize| rows[i].get(j).copied().unwrap_or(false);
+    let is_empty_column = |j: usize| (0..rows.len()).all(|i| !is_lit(i, j));
+
+    let mut letters = String::new();
+    let mut j = 0;
+    while j < width {
+        if is_empty_column(j) {
+            j += 1;
+            continue;
+        }
+
+        let start = j;
+        while j < width && !is_empty_column(j) {
+            j += 1;
+        }
+
+        let glyph: Vec<String> = (0..rows.len())
+            .map(|i| (start..j).map(|j| if is_lit(i, j) { '#' } else { '.' }).collect())
+            .collect();
+        letters.push(recognize(&glyph)?);
+    }
+
+    Some(letters)
+}
+
+fn recognize(glyph: &[String]) -> Option<char> {
+    match glyph.len() {
+        6 => find_letter(&SMALL_FONT, glyph),
+        10 => find_letter(&LARGE_FONT, glyph),
+        _ => None,
+    }
+}
+
+fn find_letter<const N: usize>(font: &[(char, [&str; N])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, rows)| rows.iter().zip(glyph).all(|(row, glyph_row)| row == glyph_row))
+        .map(|&(letter, _)| letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw<const N: usize>(font: &[(char, [&str; N])], word: &str) -> String {
+        (0..N)
+            .map(|i| {
+                word.chars()
+                    .map(|letter| font.iter().find(|(c, _)| *c == letter).unwrap().1[i])
+                    .collect::<Vec<_>>()
+                    .join("..")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_decode_every_letter() {
+        let small: String = SMALL_FONT.iter().map(|(letter, _)| letter).collect();
+        let large: String = LARGE_FONT.iter().map(|(letter, _)| letter).collect();
+
+        assert_eq!(Some(small.clone()), decode_letters(&draw(&SMALL_FONT, &small)));
+        assert_eq!(Some(large.clone()), decode_letters(&draw(&LARGE_FONT, &large)));
+    }
+
+    #[test]
+    fn test_decode_unknown_drawings() {
+        assert_eq!(None, decode_letters(""));
+        assert_eq!(None, decode_letters("#"));
+        assert_eq!(Some(String::new()), decode_letters("...\n..."));
+    }
+}
@@ -0,0 +1,201 @@
+//! Password policies on lowercase letters passwords,
+//! and search of the next password following a policy.
+
+use crate::strings::rules::Rule;
+
+/// A password policy: letters the password can't contain, and rules it must follow.
+#[derive(Default)]
+pub struct PasswordRules<'a> {
+    forbidden_letters: Vec<char>,
+    rules: Vec<Rule<'a>>,
+}
+
+impl<'a> PasswordRules<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the corporate policy: passwords must include an increasing straight
+    /// of three letters, two different pairs of letters, and no i, o or l.
+    pub fn corporate() -> Self {
+        Self::new()
+            .forbid_letters("iol")
+            .with_rule(has_increasing_straight(3))
+            .with_rule(has_different_pairs(2))
+    }
+
+    /// Forbids the letters of the specified string.
+    pub fn forbid_letters(mut self, letters: &str) -> Self {
+        self.forbidden_letters.extend(letters.chars());
+        self
+    }
+
+    /// Adds a rule the password must follow.
+    pub fn with_rule<F>(mut self, rule: F) -> Self where F: Fn(&str) -> bool + 'a {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Checks if a password follows the policy.
+    pub fn is_valid(&self, password: &str) -> bool {
+        !password.chars().any(|c| self.is_forbidden(c))
+            && self.rules.iter().all(|rule| rule(password))
+    }
+
+    fn is_forbidden(&self, c: char) -> bool {
+        self.forbidden_letters.contains(&c)
+    }
+}
+
+/// Gets a predicate checking if a string contains an increasing straight
+/// of n consecutive letters, like "abc" or "xyz".
+pub fn has_increasing_straight(n: usize) -> impl Fn(&str) -> bool {
+    move |s| {
+        let bytes = s.as_bytes();
+
+        bytes.windows(n.max(1)).any(|w| w.windows(2).all(|pair| pair[1] == pair[0] + 1))
+    }
+}
+
+/// Gets a predicate checking if a string contains n different pairs of letters
+/// not overlapping, like "aa" and "zz" in "aabzz".
+pub fn has_different_pairs(n: usize) -> impl Fn(&str) -> bool {
+    move |s| {
+        let bytes = s.as_bytes();
+        let mut pairs = Vec::new();
+
+        let mut i = 0;
+        while i + 1 < bytes.len() {
+            if bytes[i] == bytes[i + 1] {
+                if !pairs.contains(&bytes[i]) {
+                    pairs.push(bytes[i]);
+                }
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+
+        pairs.len() >= n
+    }
+}
+
+/// Finds the next password following the rules, by incrementing it like a number
+/// written in base 26 with the letters a to z.
+///
+/// Forbidden letters are skipped: when the password contains one, the search jumps
+/// directly to the next letter and resets the following ones, instead of trying
+/// every password containing it.
+///
+/// Returns None when the password can't be incremented without growing, like "zzz",
+/// or when it contains characters other than lowercase letters.
+///
+/// ```
+/// use enontekio::strings::password_rules::{next_valid, PasswordRules};
+///
+/// assert_eq!(Some("abcdffaa".to_string()), next_valid("abcdefgh", &PasswordRules::corporate()));
+/// ```
+pub fn next_valid(password: &str, rules: &PasswordRules) -> Option<String> {
+    if !password.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+
+    let mut letters: Vec<u8> = password.bytes().collect();
+    let last = letters.len().checked_sub(1)?;
+    let position = letters.iter()
+        .position(|&c| rules.is_forbidden(c as char))
+        .unwrap_or(last);
+
+    if !increment_at(&mut letters, position, rules) {
+        return None;
+    }
+
+    loop {
+        let candidate = String::from_utf8(letters.clone()).ok()?;
+        if rules.is_valid(&candidate) {
+            return Some(candidate);
+        }
+
+        if !increment_at(&mut letters, last, rules) {
+            return None;
+        }
+    }
+}
+
+/// Increments the letter at the specified position, carrying to the left
+/// and skipping forbidden letters. Letters after the position are reset
+/// to the smallest allowed letter.
+///
+/// Returns false on overflow.
+fn increment_at(letters: &mut [u8], position: usize, rules: &PasswordRules) -> bool {
+    let smallest = match (b'a'..=b'z').find(|&c| !rules.is_forbidden(c as char)) {
+        Some(c) => c,
+        None => return false,
+    };
+
+    for letter in letters.iter_mut().skip(position + 1) {
+        *letter = smallest;
+    }
+
+    let mut i = position;
+    loop {
+        let next = (letters[i] + 1..=b'z').find(|&c| !rules.is_forbidden(c as char));
+
+        match next {
+            Some(c) => {
+                letters[i] = c;
+                return true;
+            }
+            None => {
+                letters[i] = smallest;
+                if i == 0 {
+                    return false;
+                }
+                i -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_rules() {
+        assert!(has_increasing_straight(3)("hijklmmn"));
+        assert!(!has_increasing_straight(3)("abbceffg"));
+
+        assert!(has_different_pairs(2)("abbceffg"));
+        assert!(!has_different_pairs(2)("abbcegjk"));
+        assert!(!has_different_pairs(2)("aaa"));
+        assert!(!has_different_pairs(2)("aabaa"));
+    }
+
+    #[test]
+    fn test_corporate_policy() {
+        let rules = PasswordRules::corporate();
+
+        assert!(!rules.is_valid("hijklmmn"));
+        assert!(rules.is_valid("abcdffaa"));
+        assert!(rules.is_valid("ghjaabcc"));
+    }
+
+    #[test]
+    fn test_next_valid() {
+        let rules = PasswordRules::corporate();
+
+        assert_eq!(Some("abcdffaa".to_string()), next_valid("abcdefgh", &rules));
+        assert_eq!(Some("ghjaabcc".to_string()), next_valid("ghijklmn", &rules));
+    }
+
+    #[test]
+    fn test_next_valid_without_rules() {
+        let rules = PasswordRules::new();
+
+        assert_eq!(Some("xz".to_string()), next_valid("xy", &rules));
+        assert_eq!(Some("ya".to_string()), next_valid("xz", &rules));
+        assert_eq!(None, next_valid("zz", &rules));
+        assert_eq!(None, next_valid("", &rules));
+    }
+}
@@ -0,0 +1,82 @@
+//! Polymer reduction, where adjacent units of the same type
+//! and opposite polarity annihilate, like "aA" or "Bb".
+
+/// Checks if two units react: same letter, different case.
+fn react(a: char, b: char) -> bool {
+    a != b && a.eq_ignore_ascii_case(&b)
+}
+
+/// Fully reacts a polymer, returning the remaining units.
+///
+/// A single pass with a stack is enough: each unit either annihilates
+/// with the last remaining one, or is pushed on top of them.
+///
+/// ```
+/// use enontekio::strings::polymer::react_polymer;
+///
+/// assert_eq!("dabCBAcaDA", react_polymer("dabAcCaCBAcCcaDA"));
+/// ```
+pub fn react_polymer(polymer: &str) -> String {
+    react_units(polymer.chars())
+}
+
+fn react_units<I>(units: I) -> String where I: Iterator<Item = char> {
+    let mut stack: Vec<char> = Vec::new();
+
+    for unit in units {
+        match stack.last() {
+            Some(&last) if react(last, unit) => {
+                stack.pop();
+            }
+            _ => stack.push(unit),
+        }
+    }
+
+    stack.into_iter().collect()
+}
+
+/// Gets the length of the shortest polymer obtainable by removing
+/// all the units of one type, regardless of polarity, then fully reacting it.
+///
+/// As removing units can't prevent other reactions, the polymer is reacted
+/// once first, so each attempt works on a shorter input.
+///
+/// ```
+/// use enontekio::strings::polymer::shortest_after_removing_unit;
+///
+/// assert_eq!(4, shortest_after_removing_unit("dabAcCaCBAcCcaDA"));
+/// ```
+pub fn shortest_after_removing_unit(polymer: &str) -> usize {
+    let reacted = react_polymer(polymer);
+
+    let mut unit_types: Vec<char> = reacted.chars().map(|c| c.to_ascii_lowercase()).collect();
+    unit_types.sort_unstable();
+    unit_types.dedup();
+
+    unit_types.into_iter()
+        .map(|removed| {
+            react_units(reacted.chars().filter(|c| c.to_ascii_lowercase() != removed)).len()
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_react_polymer() {
+        assert_eq!("", react_polymer("aA"));
+        assert_eq!("", react_polymer("abBA"));
+        assert_eq!("abAB", react_polymer("abAB"));
+        assert_eq!("aabAAB", react_polymer("aabAAB"));
+        assert_eq!("dabCBAcaDA", react_polymer("dabAcCaCBAcCcaDA"));
+    }
+
+    #[test]
+    fn test_shortest_after_removing_unit() {
+        assert_eq!(4, shortest_after_removing_unit("dabAcCaCBAcCcaDA"));
+        assert_eq!(0, shortest_after_removing_unit(""));
+    }
+}
@@ -0,0 +1,126 @@
+//! Reusable predicates to check if a string follows some rules,
+//! like the "nice strings" ones.
+//!
+//! Predicates can be combined with `and` or `all_of`:
+//!
+//! ```
+//! use enontekio::strings::rules::*;
+//!
+//! let forbidden = ["ab", "cd", "pq", "xy"];
+//! let is_nice = and(and(contains_n_vowels(3), has_double_letter), forbids(&forbidden));
+//!
+//! assert!(is_nice("ugknbfddgicrmopn"));
+//! assert!(!is_nice("haegwjzuvuyypxyu"));
+//! ```
+
+/// A boxed predicate, to store rules of different types together.
+pub type Rule<'a> = Box<dyn Fn(&str) -> bool + 'a>;
+
+/// Gets a predicate checking if a string contains at least n vowels.
+///
+/// Vowels are a, e, i, o and u. The same vowel can be counted several times.
+pub fn contains_n_vowels(n: usize) -> impl Fn(&str) -> bool {
+    move |s| s.chars().filter(|c| "aeiou".contains(*c)).count() >= n
+}
+
+/// Checks if a string contains a letter appearing twice in a row, like "xx".
+pub fn has_double_letter(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+
+    chars.windows(2).any(|w| w[0] == w[1])
+}
+
+/// Checks if a string contains a pair of letters appearing at least twice
+/// without overlapping, like "xyxy" or "aabcdefgaa", but not "aaa".
+pub fn has_pair_appearing_twice(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+
+    (0..chars.len().saturating_sub(1)).any(|i| {
+        (i + 2..chars.len().saturating_sub(1)).any(|j| chars[i] == chars[j] && chars[i + 1] == chars[j + 1])
+    })
+}
+
+/// Checks if a string contains a letter repeating with exactly one letter between them,
+/// like "xyx" or "aaa".
+pub fn has_aba_pattern(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+
+    chars.windows(3).any(|w| w[0] == w[2])
+}
+
+/// Checks if a string contains a pair of two different characters followed by the reverse
+/// of that pair, like "abba" or "xyyx", but not "aaaa".
+pub fn has_abba_pattern(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+
+    chars.windows(4).any(|w| w[0] != w[1] && w[0] == w[3] && w[1] == w[2])
+}
+
+/// Gets a predicate checking if a string contains none of the specified substrings.
+pub fn forbids<'a>(substrings: &'a [&'a str]) -> impl Fn(&str) -> bool + 'a {
+    move |s| !substrings.iter().any(|substring| s.contains(substring))
+}
+
+/// Combines two predicates into a predicate satisfied when both are.
+pub fn and<A, B>(a: A, b: B) -> impl Fn(&str) -> bool
+where A: Fn(&str) -> bool, B: Fn(&str) -> bool {
+    move |s| a(s) && b(s)
+}
+
+/// Combines any number of predicates into a predicate satisfied when all of them are.
+pub fn all_of<'a>(rules: Vec<Rule<'a>>) -> impl Fn(&str) -> bool + 'a {
+    move |s| rules.iter().all(|rule| rule(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_n_vowels() {
+        assert!(contains_n_vowels(3)("aei"));
+        assert!(contains_n_vowels(3)("aaa"));
+        assert!(!contains_n_vowels(3)("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_has_double_letter() {
+        assert!(has_double_letter("abcdde"));
+        assert!(!has_double_letter("jchzalrnumimnmhp"));
+    }
+
+    #[test]
+    fn test_has_pair_appearing_twice() {
+        assert!(has_pair_appearing_twice("xyxy"));
+        assert!(has_pair_appearing_twice("aabcdefgaa"));
+        assert!(!has_pair_appearing_twice("aaa"));
+        assert!(!has_pair_appearing_twice("ieodomkazucvgmuy"));
+    }
+
+    #[test]
+    fn test_has_aba_pattern() {
+        assert!(has_aba_pattern("xyx"));
+        assert!(has_aba_pattern("aaa"));
+        assert!(!has_aba_pattern("uurcxstgmygtbstg"));
+    }
+
+    #[test]
+    fn test_has_abba_pattern() {
+        assert!(has_abba_pattern("abba"));
+        assert!(has_abba_pattern("ioxxoj"));
+        assert!(!has_abba_pattern("aaaa"));
+    }
+
+    #[test]
+    fn test_all_of() {
+        let is_nice = all_of(vec![
+            Box::new(has_pair_appearing_twice),
+            Box::new(has_aba_pattern),
+        ]);
+
+        assert!(is_nice("qjhvhtzxzqqjkmpb"));
+        assert!(is_nice("xxyxx"));
+        assert!(!is_nice("uurcxstgmygtbstg"));
+        assert!(!is_nice("ieodomkazucvgmuy"));
+    }
+}
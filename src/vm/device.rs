@@ -0,0 +1,488 @@
+//! The wrist device: 16 opcodes operating on registers, with
+//! an optional register bound to the instruction pointer.
+//!
+//! ```
+//! use enontekio::vm::device::Device;
+//!
+//! let program = "#ip 0
+//! seti 5 0 1
+//! seti 6 0 2
+//! addi 0 1 0
+//! addr 1 2 3
+//! setr 1 0 0
+//! seti 8 0 4
+//! seti 9 0 5";
+//!
+//! let mut device = Device::parse(program, 6).unwrap();
+//! device.run();
+//! assert_eq!(&[6, 5, 6, 0, 0, 9], device.registers());
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::collections::History;
+use crate::parser::ParserError;
+
+/// The 16 opcodes of the device.
+///
+/// The suffix describes how A and B are used: r for a register, i for an immediate value.
+/// The result is always stored in the register C.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Addr, Addi,
+    Mulr, Muli,
+    Banr, Bani,
+    Borr, Bori,
+    Setr, Seti,
+    Gtir, Gtri, Gtrr,
+    Eqir, Eqri, Eqrr,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 16] = [
+        Opcode::Addr, Opcode::Addi,
+        Opcode::Mulr, Opcode::Muli,
+        Opcode::Banr, Opcode::Bani,
+        Opcode::Borr, Opcode::Bori,
+        Opcode::Setr, Opcode::Seti,
+        Opcode::Gtir, Opcode::Gtri, Opcode::Gtrr,
+        Opcode::Eqir, Opcode::Eqri, Opcode::Eqrr,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Opcode::Addr => "addr",
+            Opcode::Addi => "addi",
+            Opcode::Mulr => "mulr",
+            Opcode::Muli => "muli",
+            Opcode::Banr => "banr",
+            Opcode::Bani => "bani",
+            Opcode::Borr => "borr",
+            Opcode::Bori => "bori",
+            Opcode::Setr => "setr",
+            Opcode::Seti => "seti",
+            Opcode::Gtir => "gtir",
+            Opcode::Gtri => "gtri",
+            Opcode::Gtrr => "gtrr",
+            Opcode::Eqir => "eqir",
+            Opcode::Eqri => "eqri",
+            Opcode::Eqrr => "eqrr",
+        }
+    }
+
+    /// Checks if the value A is a register index, rather than an immediate value.
+    pub fn reads_register_a(&self) -> bool {
+        !matches!(self, Opcode::Seti | Opcode::Gtir | Opcode::Eqir)
+    }
+
+    /// Checks if the value B is a register index, rather than an immediate value.
+    ///
+    /// For setr and seti, B is ignored.
+    pub fn reads_register_b(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Addr | Opcode::Mulr | Opcode::Banr | Opcode::Borr
+                | Opcode::Gtir | Opcode::Gtrr | Opcode::Eqir | Opcode::Eqrr
+        )
+    }
+
+    /// Executes the opcode on the registers.
+    ///
+    /// Panics if a register index is out of bounds.
+    pub fn execute(&self, registers: &mut [u64], a: u64, b: u64, c: u64) {
+        let r = |index: u64| registers[index as usize];
+
+        let value = match self {
+            Opcode::Addr => r(a).wrapping_add(r(b)),
+            Opcode::Addi => r(a).wrapping_add(b),
+            Opcode::Mulr => r(a).wrapping_mul(r(b)),
+            Opcode::Muli => r(a).wrapping_mul(b),
+            Opcode::Banr => r(a) & r(b),
+            Opcode::Bani => r(a) & b,
+            Opcode::Borr => r(a) | r(b),
+            Opcode::Bori => r(a) | b,
+            Opcode::Setr => r(a),
+            Opcode::Seti => a,
+            Opcode::Gtir => (a > r(b)) as u64,
+            Opcode::Gtri => (r(a) > b) as u64,
+            Opcode::Gtrr => (r(a) > r(b)) as u64,
+            Opcode::Eqir => (a == r(b)) as u64,
+            Opcode::Eqri => (r(a) == b) as u64,
+            Opcode::Eqrr => (r(a) == r(b)) as u64,
+        };
+
+        registers[c as usize] = value;
+    }
+
+    /// Gets the opcodes transforming the registers before into the registers after,
+    /// for the specified A, B and C values.
+    pub fn matching(before: &[u64], after: &[u64], a: u64, b: u64, c: u64) -> Vec<Opcode> {
+        Opcode::ALL.iter()
+            .filter(|opcode| {
+                let mut registers = before.to_vec();
+                let valid_registers = [a, b, c].iter().all(|&index| (index as usize) < registers.len());
+
+                valid_registers && {
+                    opcode.execute(&mut registers, a, b, c);
+                    registers == after
+                }
+            })
+            .copied()
+            .collect()
+    }
+}
+
+impl FromStr for Opcode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Opcode::ALL.iter()
+            .find(|opcode| opcode.name() == s)
+            .copied()
+            .ok_or_else(|| format!("unknown opcode {}", s))
+    }
+}
+
+impl Display for Opcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An instruction: an opcode and its A, B and C values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Instruction {
+    /// Gets the register indexes used by the instruction, C included.
+    pub fn registers(&self) -> Vec<u64> {
+        let mut registers = Vec::with_capacity(3);
+
+        if self.opcode.reads_register_a() {
+            registers.push(self.a);
+        }
+        if self.opcode.reads_register_b() {
+            registers.push(self.b);
+        }
+        registers.push(self.c);
+
+        registers
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    /// Parses an instruction like "addi 1 2 3".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        if tokens.len() != 4 {
+            return Err(format!("expected an opcode and 3 values, got {} tokens", tokens.len()));
+        }
+
+        let value = |token: &str| token.parse::<u64>().map_err(|error| format!("{}: {}", token, error));
+
+        Ok(Self {
+            opcode: tokens[0].parse()?,
+            a: value(tokens[1])?,
+            b: value(tokens[2])?,
+            c: value(tokens[3])?,
+        })
+    }
+}
+
+/// What to do after a hook has been called
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookAction {
+    /// Executes the next instruction
+    Continue,
+
+    /// Stops the execution before the next instruction
+    Stop,
+}
+
+/// A device running a program
+#[derive(Debug, Clone)]
+pub struct Device {
+    registers: Vec<u64>,
+    program: Vec<Instruction>,
+    ip: usize,
+    ip_register: Option<usize>,
+}
+
+impl Device {
+    /// Creates a device with the specified number of registers, all set to 0.
+    ///
+    /// When ip_register is set, that register is bound to the instruction pointer:
+    /// it's written before each instruction, and read back after it.
+    pub fn new(program: Vec<Instruction>, register_count: usize, ip_register: Option<usize>) -> Self {
+        Self {
+            registers: vec![0; register_count],
+            program,
+            ip: 0,
+            ip_register,
+        }
+    }
+
+    /// Parses a program, one instruction per line,
+    /// optionally preceded by a "#ip n" declaration binding a register.
+    ///
+    /// A register index out of bounds is reported as a parse error.
+    pub fn parse(source: &str, register_count: usize) -> Result<Self, ParserError> {
+        let mut ip_register = None;
+        let mut program = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(register) = line.strip_prefix("#ip ") {
+                let register = register.trim().parse::<usize>()
+                    .map_err(|error| ParserError::parse(line_number, line, error))?;
+
+                if register >= register_count {
+                    return Err(ParserError::parse(line_number, line, "instruction pointer register out of bounds"));
+                }
+
+                ip_register = Some(register);
+                continue;
+            }
+
+            let instruction = line.parse::<Instruction>()
+                .map_err(|error| ParserError::parse(line_number, line, error))?;
+
+            if let Some(register) = instruction.registers().into_iter().find(|&register| register >= register_count as u64) {
+                let message = format!("register {} out of bounds", register);
+                return Err(ParserError::parse(line_number, line, message));
+            }
+
+            program.push(instruction);
+        }
+
+        Ok(Self::new(program, register_count, ip_register))
+    }
+
+    pub fn registers(&self) -> &[u64] {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut [u64] {
+        &mut self.registers
+    }
+
+    /// Gets the instruction pointer.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /// Checks if the instruction pointer is outside the program.
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    /// Executes the instruction at the instruction pointer.
+    ///
+    /// Returns false if the device is halted.
+    pub fn step(&mut self) -> bool {
+        if self.is_halted() {
+            return false;
+        }
+
+        if let Some(register) = self.ip_register {
+            self.registers[register] = self.ip as u64;
+        }
+
+        let instruction = self.program[self.ip];
+        instruction.opcode.execute(&mut self.registers, instruction.a, instruction.b, instruction.c);
+
+        let next_ip = match self.ip_register {
+            Some(register) => self.registers[register].wrapping_add(1),
+            None => self.ip as u64 + 1,
+        };
+        self.ip = usize::try_from(next_ip).unwrap_or(usize::MAX);
+
+        true
+    }
+
+    /// Runs the program until the device halts.
+    ///
+    /// Returns the number of executed instructions.
+    pub fn run(&mut self) -> usize {
+        self.run_with_hook(|_| HookAction::Continue)
+    }
+
+    /// Runs the program until the device halts or the hook stops it.
+    ///
+    /// The hook is called before each instruction, and can inspect the device.
+    /// Returns the number of executed instructions.
+    pub fn run_with_hook<F>(&mut self, mut hook: F) -> usize where F: FnMut(&Device) -> HookAction {
+        let mut steps = 0;
+
+        while !self.is_halted() && hook(self) == HookAction::Continue {
+            self.step();
+            steps += 1;
+        }
+
+        steps
+    }
+
+    /// Runs the program until the device halts or the hook stops it,
+    /// recording before each executed instruction the instruction pointer and the registers.
+    ///
+    /// Returns the number of executed instructions.
+    pub fn run_with_history<F>(&mut self, history: &mut History<(usize, Vec<u64>)>, mut hook: F) -> usize
+    where F: FnMut(&Device) -> HookAction {
+        self.run_with_hook(|device| {
+            let action = hook(device);
+            if action == HookAction::Continue {
+                history.record((device.ip(), device.registers().to_vec()));
+            }
+
+            action
+        })
+    }
+
+    /// Analyzes a background process computing the sum of the divisors of a number.
+    ///
+    /// Such programs first compute the number, then jump back to the entry point
+    /// of a slow double loop. The program is run until the instruction pointer
+    /// reaches that entry point: the largest register is then the number,
+    /// and its sum of divisors is computed directly.
+    ///
+    /// Returns None if the program halts before reaching the entry point.
+    pub fn run_sum_of_divisors_process(&mut self, entry_point: usize) -> Option<u64> {
+        let mut steps = 0;
+        self.run_with_hook(|device| {
+            let reached = steps > 0 && device.ip() == entry_point;
+            steps += 1;
+
+            if reached {
+                HookAction::Stop
+            } else {
+                HookAction::Continue
+            }
+        });
+
+        if self.is_halted() {
+            return None;
+        }
+
+        self.registers.iter().max().map(|&n| sum_of_divisors(n))
+    }
+}
+
+/// Computes the sum of the divisors of n, including 1 and n.
+pub fn sum_of_divisors(n: u64) -> u64 {
+    let mut sum = 0;
+    let mut i = 1;
+
+    while i <= n / i {
+        if n.is_multiple_of(i) {
+            sum += i;
+            if i != n / i {
+                sum += n / i;
+            }
+        }
+        i += 1;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opcodes_matching() {
+        let matching = Opcode::matching(&[3, 2, 1, 1], &[3, 2, 2, 1], 2, 1, 2);
+
+        assert_eq!(vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti], matching);
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        let instruction: Instruction = "gtrr 1 2 3".parse().unwrap();
+
+        assert_eq!(Opcode::Gtrr, instruction.opcode);
+        assert_eq!((1, 2, 3), (instruction.a, instruction.b, instruction.c));
+        assert!("nope 1 2 3".parse::<Instruction>().is_err());
+        assert!("addi 1 2".parse::<Instruction>().is_err());
+    }
+
+    #[test]
+    fn test_parse_program_reports_line() {
+        let error = Device::parse("#ip 0\nseti 5 0 1\nadd 1 2 3", 6).unwrap_err();
+
+        assert_eq!(Some(3), error.line_number());
+    }
+
+    #[test]
+    fn test_parse_program_rejects_registers_out_of_bounds() {
+        assert!(Device::parse("addr 0 6 1", 6).is_err());
+        assert!(Device::parse("seti 9 0 6", 6).is_err());
+        assert!(Device::parse("gtir 9 0 1", 6).is_ok());
+        assert!(Device::parse("setr 1 9 2", 6).is_ok());
+    }
+
+    #[test]
+    fn test_run_with_bound_ip() {
+        let program = "#ip 0\nseti 5 0 1\nseti 6 0 2\naddi 0 1 0\naddr 1 2 3\nsetr 1 0 0\nseti 8 0 4\nseti 9 0 5";
+        let mut device = Device::parse(program, 6).unwrap();
+
+        assert_eq!(5, device.run());
+        assert_eq!(&[6, 5, 6, 0, 0, 9], device.registers());
+    }
+
+    #[test]
+    fn test_run_with_history() {
+        let program = "seti 1 0 0\naddi 0 1 0\naddi 0 1 0\nmuli 0 10 0";
+        let mut device = Device::parse(program, 1).unwrap();
+        let mut history = History::new(2);
+
+        device.run_with_history(&mut history, |_| HookAction::Continue);
+
+        assert_eq!(&[30], device.registers());
+        assert_eq!(4, history.steps());
+        assert_eq!(Some((3, &(3, vec![3]))), history.last());
+
+        let mut device = Device::parse(program, 1).unwrap();
+        let mut history = History::new(2);
+
+        assert_eq!(2, device.run_with_history(&mut history, |device| {
+            if device.ip() == 2 { HookAction::Stop } else { HookAction::Continue }
+        }));
+        assert_eq!(2, history.steps());
+        assert_eq!(Some((1, &(1, vec![1]))), history.last());
+    }
+
+    #[test]
+    fn test_sum_of_divisors() {
+        assert_eq!(1, sum_of_divisors(1));
+        assert_eq!(1 + 2 + 4 + 8 + 16, sum_of_divisors(16));
+        assert_eq!(1 + 2 + 3 + 6, sum_of_divisors(6));
+    }
+
+    #[test]
+    fn test_run_sum_of_divisors_process() {
+        // Jumps to 3 to set r1 to 10, then back to 1, where a naive divisors loop would start.
+        let program = "#ip 0\nseti 2 0 0\nseti 0 0 0\nseti 99 0 0\nseti 10 0 1\nseti 0 0 0";
+        let mut device = Device::parse(program, 6).unwrap();
+
+        assert_eq!(Some(1 + 2 + 5 + 10), device.run_sum_of_divisors_process(1));
+    }
+}
@@ -0,0 +1,3 @@
+//! Virtual machines executing the assembly-like programs of puzzles.
+
+pub mod device;